@@ -5,6 +5,8 @@ pub mod abi_checking;
 mod const_generics;
 pub mod extra_checks;
 pub mod get_static_equivalent;
+#[cfg(feature = "serde_json")]
+mod layout_json;
 pub mod stable_abi_trait;
 
 pub use self::{
@@ -14,5 +16,10 @@ pub use self::{
     stable_abi_trait::{AbiConsts, PrefixStableAbi, StableAbi},
 };
 
+#[cfg(feature = "serde_json")]
+pub use self::layout_json::{
+    compare_layout_json, JsonAbiInstability, JsonAbiInstabilityKind, LayoutJson,
+};
+
 #[doc(no_inline)]
 pub use self::extra_checks::{ExtraChecks, TypeChecker};
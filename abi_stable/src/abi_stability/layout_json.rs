@@ -0,0 +1,831 @@
+//! Serializing type layouts as json,
+//! and checking the compatibility of layouts deserialized from json.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+use crate::{
+    abi_stability::abi_checking::ExpectedFound,
+    sabi_types::VersionNumber,
+    std_types::{RErr, ROk, RResult, RVec, UTypeId},
+    type_layout::{TLData, TLField, TypeLayout},
+};
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests;
+
+/// The layout of a type, and of all the types it references,
+/// in a format that can be serialized and deserialized.
+///
+/// This is constructed with [`LayoutJson::from_type_layout`],
+/// and compared with [`compare_layout_json`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutJson {
+    /// The layouts of all the types, the first one is the layout this was constructed from.
+    ///
+    /// Types reference each other by their index in this list.
+    types: Vec<TypeJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct TypeJson {
+    name: String,
+    full_type: String,
+    package: String,
+    package_version: String,
+    is_nonzero: bool,
+    repr_attr: String,
+    lifetime_count: usize,
+    const_params: Vec<String>,
+    size: usize,
+    alignment: usize,
+    phantom_fields: Vec<FieldJson>,
+    data: DataJson,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum DataJson {
+    Primitive {
+        primitive: String,
+    },
+    Opaque,
+    Struct {
+        fields: Vec<FieldJson>,
+    },
+    Union {
+        fields: Vec<FieldJson>,
+    },
+    Enum {
+        is_exhaustive: bool,
+        variant_names: Vec<String>,
+        variant_field_counts: Vec<u8>,
+        discriminant_repr: String,
+        discriminants: Vec<String>,
+        fields: Vec<FieldJson>,
+    },
+    PrefixType {
+        prefix_field_count: u8,
+        conditional_prefix_fields: u64,
+        accessible_fields: u64,
+        fields: Vec<FieldJson>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct FieldJson {
+    name: String,
+    lifetime_indices: String,
+    /// The index of the type of the field in `LayoutJson::types`.
+    layout: usize,
+    functions: Vec<FunctionJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct FunctionJson {
+    is_unsafe: bool,
+    lifetime_indices: String,
+    /// The parameters, followed by the return type.
+    params_ret: Vec<FieldJson>,
+}
+
+impl DataJson {
+    const fn kind_name(&self) -> &'static str {
+        match self {
+            DataJson::Primitive { .. } => "Primitive",
+            DataJson::Opaque => "Opaque",
+            DataJson::Struct { .. } => "Struct",
+            DataJson::Union { .. } => "Union",
+            DataJson::Enum { .. } => "Enum",
+            DataJson::PrefixType { .. } => "PrefixType",
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl LayoutJson {
+    /// Gets the layout of a type, and of all the types it references.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use abi_stable::{abi_stability::LayoutJson, std_types::RString, StableAbi};
+    ///
+    /// let layout = LayoutJson::from_type_layout(<RString as StableAbi>::LAYOUT);
+    ///
+    /// let json = serde_json::to_string(&layout).unwrap();
+    ///
+    /// assert_eq!(serde_json::from_str::<LayoutJson>(&json).unwrap(), layout);
+    ///
+    /// ```
+    pub fn from_type_layout(layout: &'static TypeLayout) -> Self {
+        let mut builder = LayoutJsonBuilder {
+            types: Vec::new(),
+            indices: HashMap::new(),
+        };
+        builder.add_type(layout);
+        Self {
+            types: builder
+                .types
+                .into_iter()
+                .map(|ty| ty.expect("all types are initialized after add_type returns"))
+                .collect(),
+        }
+    }
+}
+
+struct LayoutJsonBuilder {
+    types: Vec<Option<TypeJson>>,
+    indices: HashMap<UTypeId, usize>,
+}
+
+impl LayoutJsonBuilder {
+    fn add_type(&mut self, layout: &'static TypeLayout) -> usize {
+        let type_id = layout.get_utypeid();
+        if let Some(&index) = self.indices.get(&type_id) {
+            return index;
+        }
+
+        // Reserving the index before adding the referenced types,
+        // so that types that reference themselves don't recurse infinitely.
+        let index = self.types.len();
+        self.types.push(None);
+        self.indices.insert(type_id, index);
+
+        let (package, version) = layout.package_and_version();
+        let generics = layout.generics();
+
+        let data = match layout.data() {
+            TLData::Primitive(primitive) => DataJson::Primitive {
+                primitive: format!("{:?}", primitive),
+            },
+            TLData::Opaque => DataJson::Opaque,
+            TLData::Struct { fields } => DataJson::Struct {
+                fields: self.add_fields(fields.iter()),
+            },
+            TLData::Union { fields } => DataJson::Union {
+                fields: self.add_fields(fields.iter()),
+            },
+            TLData::Enum(enum_) => DataJson::Enum {
+                is_exhaustive: enum_.exhaustiveness.is_exhaustive(),
+                variant_names: enum_.variant_names_iter().map(String::from).collect(),
+                variant_field_counts: enum_.field_count.to_vec(),
+                discriminant_repr: format!("{:?}", enum_.discriminants.discriminant_repr()),
+                discriminants: enum_.discriminants.discriminant_strings(),
+                fields: self.add_fields(enum_.fields.iter()),
+            },
+            TLData::PrefixType(prefix) => DataJson::PrefixType {
+                prefix_field_count: prefix.first_suffix_field,
+                conditional_prefix_fields: prefix.conditional_prefix_fields.bits(),
+                accessible_fields: prefix.accessible_fields.bits(),
+                fields: self.add_fields(prefix.fields.iter()),
+            },
+        };
+
+        self.types[index] = Some(TypeJson {
+            name: layout.name().into(),
+            full_type: layout.full_type().to_string(),
+            package: package.to_string(),
+            package_version: version.version.to_string(),
+            is_nonzero: layout.is_nonzero(),
+            repr_attr: format!("{:?}", layout.repr_attr()),
+            lifetime_count: generics.lifetime_count(),
+            const_params: generics
+                .const_params()
+                .iter()
+                .map(|x| format!("{:?}", x))
+                .collect(),
+            size: layout.size(),
+            alignment: layout.alignment(),
+            phantom_fields: self.add_fields(layout.phantom_fields().iter()),
+            data,
+        });
+
+        index
+    }
+
+    fn add_fields<I>(&mut self, fields: I) -> Vec<FieldJson>
+    where
+        I: IntoIterator<Item = TLField>,
+    {
+        fields
+            .into_iter()
+            .map(|field| FieldJson {
+                name: field.name().into(),
+                lifetime_indices: format!("{:?}", field.lifetime_indices()),
+                layout: self.add_type(field.layout()),
+                functions: field
+                    .function_range()
+                    .iter()
+                    .map(|func| FunctionJson {
+                        is_unsafe: func.qualifiers().is_unsafe(),
+                        lifetime_indices: format!("{:?}", func.paramret_lifetime_indices),
+                        params_ret: self.add_fields(func.get_params_ret_iter()),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// An incompatibility between two type layouts deserialized from json,
+/// found by [`compare_layout_json`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonAbiInstability {
+    /// The path to the incompatible type,
+    /// starting with the name of the compared type,
+    /// followed by the names of the fields used to reach the incompatible type.
+    pub path: String,
+    /// What is incompatible.
+    pub kind: JsonAbiInstabilityKind,
+}
+
+/// What is incompatible in a [`JsonAbiInstability`],
+/// these mirror the variants of the errors returned by
+/// [`check_layout_compatibility`](crate::abi_stability::check_layout_compatibility).
+///
+/// Strings are the formatted values that the checks compared.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonAbiInstabilityKind {
+    /// The json for one of the layouts couldn't be parsed.
+    InvalidJson {
+        /// Either `"old"` or `"new"`.
+        which: &'static str,
+        error: String,
+    },
+    Name(ExpectedFound<String>),
+    Package(ExpectedFound<String>),
+    PackageVersionParseError(String),
+    PackageVersion(ExpectedFound<String>),
+    NonZeroness(ExpectedFound<bool>),
+    ReprAttr(ExpectedFound<String>),
+    GenericParamCount(ExpectedFound<String>),
+    MismatchedConstParam(ExpectedFound<String>),
+    Size(ExpectedFound<usize>),
+    Alignment(ExpectedFound<usize>),
+    TLDataDiscriminant(ExpectedFound<&'static str>),
+    MismatchedPrimitive(ExpectedFound<String>),
+    FieldCountMismatch(ExpectedFound<usize>),
+    UnexpectedField(ExpectedFound<String>),
+    FieldLifetimeMismatch(ExpectedFound<String>),
+    FnLifetimeMismatch(ExpectedFound<String>),
+    FnQualifierMismatch(ExpectedFound<bool>),
+    MismatchedExhaustiveness(ExpectedFound<bool>),
+    TooManyVariants(ExpectedFound<usize>),
+    UnexpectedVariant(ExpectedFound<String>),
+    EnumDiscriminant(ExpectedFound<String>),
+    MismatchedPrefixSize(ExpectedFound<u8>),
+    MismatchedPrefixConditionality(ExpectedFound<u64>),
+}
+
+impl Display for JsonAbiInstability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use self::JsonAbiInstabilityKind as K;
+
+        fn ef<T: Display>(
+            f: &mut fmt::Formatter<'_>,
+            what: &str,
+            ef: &ExpectedFound<T>,
+        ) -> fmt::Result {
+            write!(
+                f,
+                "{}:\n    expected: {}\n    found: {}",
+                what, ef.expected, ef.found
+            )
+        }
+
+        if let K::InvalidJson { which, error } = &self.kind {
+            return write!(f, "Could not parse the {} layout:\n    {}", which, error);
+        }
+
+        writeln!(f, "In `{}`:", self.path)?;
+
+        match &self.kind {
+            K::InvalidJson { .. } => Ok(()),
+            K::Name(x) => ef(f, "Mismatched type name", x),
+            K::Package(x) => ef(f, "Mismatched package", x),
+            K::PackageVersionParseError(x) => {
+                write!(f, "Could not parse the package version: {:?}", x)
+            }
+            K::PackageVersion(x) => ef(f, "Incompatible package version", x),
+            K::NonZeroness(x) => ef(f, "Mismatched non-zeroness", x),
+            K::ReprAttr(x) => ef(f, "Mismatched representation attribute", x),
+            K::GenericParamCount(x) => ef(f, "Mismatched amount of generic parameters", x),
+            K::MismatchedConstParam(x) => ef(f, "Mismatched const parameter", x),
+            K::Size(x) => ef(f, "Mismatched size", x),
+            K::Alignment(x) => ef(f, "Mismatched alignment", x),
+            K::TLDataDiscriminant(x) => ef(f, "Mismatched kind of type", x),
+            K::MismatchedPrimitive(x) => ef(f, "Mismatched primitive", x),
+            K::FieldCountMismatch(x) => ef(f, "Mismatched amount of fields", x),
+            K::UnexpectedField(x) => ef(f, "Unexpected field", x),
+            K::FieldLifetimeMismatch(x) => ef(f, "Mismatched field lifetimes", x),
+            K::FnLifetimeMismatch(x) => ef(f, "Mismatched function pointer lifetimes", x),
+            K::FnQualifierMismatch(x) => ef(f, "Mismatched function pointer unsafety", x),
+            K::MismatchedExhaustiveness(x) => ef(f, "Mismatched enum exhaustiveness", x),
+            K::TooManyVariants(x) => ef(f, "Too many variants", x),
+            K::UnexpectedVariant(x) => ef(f, "Unexpected variant", x),
+            K::EnumDiscriminant(x) => ef(f, "Mismatched enum discriminant", x),
+            K::MismatchedPrefixSize(x) => ef(f, "Mismatched prefix size", x),
+            K::MismatchedPrefixConditionality(x) => {
+                ef(f, "Mismatched prefix conditionality (as bits)", x)
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonAbiInstability {}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Checks that the `new` type layout is compatible with the `old` one,
+/// where both are the json serialization of a [`LayoutJson`]
+/// (as outputted by the `sabi_extract layout` command).
+///
+/// This is for checking that a new version of a library is ABI compatible
+/// with an old version without loading either library (eg: in CI).
+///
+/// This does the same checks as
+/// [`check_layout_compatibility`](crate::abi_stability::check_layout_compatibility)
+/// on the types reachable from the root type,
+/// with these exceptions:
+///
+/// - [`ExtraChecks`](crate::abi_stability::ExtraChecks) and [tags](crate::type_layout::Tag)
+///   are not checked, since they can't be serialized.
+///
+/// - Const parameters are compared by their `Debug` output.
+///
+/// - Prefix types and nonexhaustive enums are only checked against the other version,
+///   it's not checked that they're compatible with the other uses of the same type.
+///
+/// # Example
+///
+/// ```rust
+/// use abi_stable::{
+///     abi_stability::{compare_layout_json, LayoutJson},
+///     StableAbi,
+/// };
+///
+/// mod v0 {
+///     #[repr(C)]
+///     #[derive(abi_stable::StableAbi)]
+///     pub struct Point {
+///         pub x: u32,
+///         pub y: u32,
+///     }
+/// }
+///
+/// mod v1 {
+///     #[repr(C)]
+///     #[derive(abi_stable::StableAbi)]
+///     pub struct Point {
+///         pub x: u64,
+///         pub y: u64,
+///     }
+/// }
+///
+/// let to_json = |layout| serde_json::to_string(&LayoutJson::from_type_layout(layout)).unwrap();
+///
+/// let old = to_json(<v0::Point as StableAbi>::LAYOUT);
+/// let new = to_json(<v1::Point as StableAbi>::LAYOUT);
+///
+/// assert!(compare_layout_json(&old, &old).is_ok());
+///
+/// let errors = compare_layout_json(&old, &new).unwrap_err();
+/// assert!(errors.iter().any(|e| e.path == "Point.x"));
+///
+/// ```
+pub fn compare_layout_json(old: &str, new: &str) -> RResult<(), RVec<JsonAbiInstability>> {
+    let parse = |which: &'static str, json: &str| {
+        serde_json::from_str::<LayoutJson>(json)
+            .map_err(|e| e.to_string())
+            .and_then(|layout| layout.validate())
+            .map_err(|error| {
+                rvec![JsonAbiInstability {
+                    path: String::new(),
+                    kind: JsonAbiInstabilityKind::InvalidJson { which, error },
+                }]
+            })
+    };
+
+    let old = match parse("old", old) {
+        Ok(x) => x,
+        Err(e) => return RErr(e),
+    };
+    let new = match parse("new", new) {
+        Ok(x) => x,
+        Err(e) => return RErr(e),
+    };
+
+    let mut checker = JsonChecker {
+        old: &old.types,
+        new: &new.types,
+        visited: HashSet::new(),
+        path: vec![old.types[0].name.clone()],
+        errors: RVec::new(),
+    };
+    checker.check_type(0, 0);
+
+    if checker.errors.is_empty() {
+        ROk(())
+    } else {
+        RErr(checker.errors)
+    }
+}
+
+impl LayoutJson {
+    /// Checks that there is a root type and that every type index is in bounds.
+    fn validate(self) -> Result<Self, String> {
+        fn check_fields(fields: &[FieldJson], len: usize) -> Result<(), String> {
+            for field in fields {
+                if field.layout >= len {
+                    return Err(format!(
+                        "the `{}` field refers to type {}, but there are only {} types",
+                        field.name, field.layout, len,
+                    ));
+                }
+                for func in &field.functions {
+                    check_fields(&func.params_ret, len)?;
+                }
+            }
+            Ok(())
+        }
+
+        let len = self.types.len();
+        if len == 0 {
+            return Err("there are no types".into());
+        }
+        for ty in &self.types {
+            check_fields(&ty.phantom_fields, len)?;
+            match &ty.data {
+                DataJson::Primitive { .. } | DataJson::Opaque => {}
+                DataJson::Struct { fields }
+                | DataJson::Union { fields }
+                | DataJson::Enum { fields, .. }
+                | DataJson::PrefixType { fields, .. } => check_fields(fields, len)?,
+            }
+        }
+        Ok(self)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FieldContext {
+    Fields,
+    Subfields,
+    PhantomFields,
+}
+
+struct JsonChecker<'a> {
+    old: &'a [TypeJson],
+    new: &'a [TypeJson],
+    /// The pairs of (old, new) type indices that were already checked.
+    visited: HashSet<(usize, usize)>,
+    path: Vec<String>,
+    errors: RVec<JsonAbiInstability>,
+}
+
+impl<'a> JsonChecker<'a> {
+    fn push_err<T>(
+        &mut self,
+        expected: T,
+        found: T,
+        kind: fn(ExpectedFound<T>) -> JsonAbiInstabilityKind,
+    ) {
+        self.errors.push(JsonAbiInstability {
+            path: self.path.join("."),
+            kind: kind(ExpectedFound { expected, found }),
+        });
+    }
+
+    fn check_type(&mut self, t_index: usize, o_index: usize) {
+        // Recursive types, or types that were already checked.
+        if !self.visited.insert((t_index, o_index)) {
+            return;
+        }
+
+        let (old, new) = (self.old, self.new);
+        self.check_type_inner(&old[t_index], &new[o_index]);
+    }
+
+    fn check_type_inner(&mut self, this: &'a TypeJson, other: &'a TypeJson) {
+        use self::JsonAbiInstabilityKind as K;
+
+        if this.name != other.name {
+            self.push_err(this.full_type.clone(), other.full_type.clone(), K::Name);
+            return;
+        }
+        if this.package != other.package {
+            self.push_err(this.package.clone(), other.package.clone(), K::Package);
+            return;
+        }
+
+        if this.is_nonzero != other.is_nonzero {
+            self.push_err(this.is_nonzero, other.is_nonzero, K::NonZeroness);
+        }
+
+        if this.repr_attr != other.repr_attr {
+            self.push_err(this.repr_attr.clone(), other.repr_attr.clone(), K::ReprAttr);
+        }
+
+        match (
+            parse_version(&this.package_version),
+            parse_version(&other.package_version),
+        ) {
+            (Ok(l), Ok(r)) => {
+                if !l.is_loosely_compatible(r) {
+                    self.push_err(
+                        this.package_version.clone(),
+                        other.package_version.clone(),
+                        K::PackageVersion,
+                    );
+                }
+            }
+            (Err(version), _) | (_, Err(version)) => {
+                let path = self.path.join(".");
+                self.errors.push(JsonAbiInstability {
+                    path,
+                    kind: K::PackageVersionParseError(version.into()),
+                });
+                return;
+            }
+        }
+
+        if this.lifetime_count != other.lifetime_count
+            || this.const_params.len() != other.const_params.len()
+        {
+            self.push_err(
+                this.full_type.clone(),
+                other.full_type.clone(),
+                K::GenericParamCount,
+            );
+        }
+        for (l, r) in this.const_params.iter().zip(&other.const_params) {
+            if l != r {
+                self.push_err(l.clone(), r.clone(), K::MismatchedConstParam);
+            }
+        }
+
+        self.check_fields(
+            this,
+            other,
+            FieldContext::PhantomFields,
+            &this.phantom_fields,
+            &other.phantom_fields,
+        );
+
+        let is_prefix = matches!(this.data, DataJson::PrefixType { .. });
+        match (this.size.cmp(&other.size), is_prefix) {
+            (Ordering::Greater, _) | (Ordering::Less, false) => {
+                self.push_err(this.size, other.size, K::Size);
+            }
+            (Ordering::Equal, _) | (Ordering::Less, true) => {}
+        }
+        if this.alignment != other.alignment {
+            self.push_err(this.alignment, other.alignment, K::Alignment);
+        }
+
+        let (t_kind, o_kind) = (this.data.kind_name(), other.data.kind_name());
+        if t_kind != o_kind {
+            self.push_err(t_kind, o_kind, K::TLDataDiscriminant);
+        }
+
+        match (&this.data, &other.data) {
+            (DataJson::Opaque, _) => {}
+
+            (
+                DataJson::Primitive { primitive: t_prim },
+                DataJson::Primitive { primitive: o_prim },
+            ) if t_prim != o_prim => {
+                self.push_err(t_prim.clone(), o_prim.clone(), K::MismatchedPrimitive);
+            }
+
+            (DataJson::Struct { fields: t_fields }, DataJson::Struct { fields: o_fields })
+            | (DataJson::Union { fields: t_fields }, DataJson::Union { fields: o_fields }) => {
+                self.check_fields(this, other, FieldContext::Fields, t_fields, o_fields);
+            }
+
+            (
+                DataJson::Enum {
+                    is_exhaustive: t_exhaustive,
+                    variant_names: t_names,
+                    variant_field_counts: t_fcount,
+                    discriminant_repr: t_discr_repr,
+                    discriminants: t_discrs,
+                    fields: t_fields,
+                },
+                DataJson::Enum {
+                    is_exhaustive: o_exhaustive,
+                    variant_names: o_names,
+                    variant_field_counts: o_fcount,
+                    discriminant_repr: o_discr_repr,
+                    discriminants: o_discrs,
+                    fields: o_fields,
+                },
+            ) => {
+                if t_exhaustive != o_exhaustive {
+                    self.push_err(*t_exhaustive, *o_exhaustive, K::MismatchedExhaustiveness);
+                }
+
+                if *t_exhaustive && t_fcount.len() != o_fcount.len()
+                    || !*t_exhaustive && t_fcount.len() > o_fcount.len()
+                {
+                    self.push_err(t_fcount.len(), o_fcount.len(), K::TooManyVariants);
+                }
+
+                if t_discr_repr != o_discr_repr {
+                    let repr = |x: &String| format!("Int({})", x);
+                    self.push_err(repr(t_discr_repr), repr(o_discr_repr), K::ReprAttr);
+                } else {
+                    for (t_discr, o_discr) in t_discrs.iter().zip(o_discrs) {
+                        if t_discr != o_discr {
+                            self.push_err(t_discr.clone(), o_discr.clone(), K::EnumDiscriminant);
+                        }
+                    }
+                }
+
+                let mut total_field_count = 0;
+                for (i, (t_count, o_count)) in t_fcount.iter().zip(o_fcount).enumerate() {
+                    total_field_count += usize::from(*t_count);
+
+                    if t_count != o_count {
+                        self.push_err(
+                            usize::from(*t_count),
+                            usize::from(*o_count),
+                            K::FieldCountMismatch,
+                        );
+                    }
+
+                    let t_name = t_names.get(i).map_or("<this unavailable>", |x| &**x);
+                    let o_name = o_names.get(i).map_or("<other unavailable>", |x| &**x);
+                    if t_name != o_name {
+                        self.push_err(t_name.into(), o_name.into(), K::UnexpectedVariant);
+                    }
+                }
+
+                let min_field_count = t_fields.len().min(o_fields.len());
+                if total_field_count != min_field_count {
+                    self.push_err(total_field_count, min_field_count, K::FieldCountMismatch);
+                }
+
+                self.check_fields(this, other, FieldContext::Fields, t_fields, o_fields);
+            }
+
+            (
+                DataJson::PrefixType {
+                    prefix_field_count: t_prefix_count,
+                    conditional_prefix_fields: t_cond,
+                    fields: t_fields,
+                    ..
+                },
+                DataJson::PrefixType {
+                    prefix_field_count: o_prefix_count,
+                    conditional_prefix_fields: o_cond,
+                    fields: o_fields,
+                    ..
+                },
+            ) => {
+                if t_prefix_count != o_prefix_count {
+                    self.push_err(*t_prefix_count, *o_prefix_count, K::MismatchedPrefixSize);
+                }
+                if t_cond != o_cond {
+                    self.push_err(*t_cond, *o_cond, K::MismatchedPrefixConditionality);
+                }
+                self.check_fields(this, other, FieldContext::Fields, t_fields, o_fields);
+            }
+
+            // Equal primitives, or a mismatched kind of type that was reported above.
+            _ => {}
+        }
+    }
+
+    fn check_fields(
+        &mut self,
+        this: &'a TypeJson,
+        other: &'a TypeJson,
+        ctx: FieldContext,
+        t_fields: &'a [FieldJson],
+        o_fields: &'a [FieldJson],
+    ) {
+        use self::JsonAbiInstabilityKind as K;
+
+        if t_fields.is_empty() && o_fields.is_empty() {
+            return;
+        }
+
+        let is_prefix = match &this.data {
+            DataJson::PrefixType { .. } => true,
+            DataJson::Enum { is_exhaustive, .. } => !is_exhaustive,
+            _ => false,
+        };
+        match (t_fields.len().cmp(&o_fields.len()), is_prefix) {
+            (Ordering::Greater, _) | (Ordering::Less, false) => {
+                self.push_err(t_fields.len(), o_fields.len(), K::FieldCountMismatch);
+            }
+            (Ordering::Equal, _) | (Ordering::Less, true) => {}
+        }
+
+        let acc_fields = match (&this.data, &other.data) {
+            (
+                DataJson::PrefixType {
+                    accessible_fields: l,
+                    ..
+                },
+                DataJson::PrefixType {
+                    accessible_fields: r,
+                    ..
+                },
+            ) => Some((*l, *r)),
+            _ => None,
+        };
+
+        for (field_i, (this_f, other_f)) in t_fields.iter().zip(o_fields).enumerate() {
+            if this_f.name != other_f.name {
+                self.push_err(
+                    this_f.name.clone(),
+                    other_f.name.clone(),
+                    K::UnexpectedField,
+                );
+                continue;
+            }
+
+            let is_accessible = match (ctx, acc_fields) {
+                (FieldContext::Fields, Some((l, r))) => {
+                    let bit = 1u64.checked_shl(field_i as u32).unwrap_or(0);
+                    (l & bit) != 0 && (r & bit) != 0
+                }
+                _ => true,
+            };
+
+            self.path.push(this_f.name.clone());
+
+            if is_accessible {
+                if this_f.lifetime_indices != other_f.lifetime_indices {
+                    self.push_err(
+                        this_f.lifetime_indices.clone(),
+                        other_f.lifetime_indices.clone(),
+                        K::FieldLifetimeMismatch,
+                    );
+                }
+
+                for (t_func, o_func) in this_f.functions.iter().zip(&other_f.functions) {
+                    if t_func.lifetime_indices != o_func.lifetime_indices {
+                        self.push_err(
+                            t_func.lifetime_indices.clone(),
+                            o_func.lifetime_indices.clone(),
+                            K::FnLifetimeMismatch,
+                        );
+                    }
+                    if t_func.is_unsafe != o_func.is_unsafe {
+                        self.push_err(t_func.is_unsafe, o_func.is_unsafe, K::FnQualifierMismatch);
+                    }
+                    self.check_fields(
+                        this,
+                        other,
+                        FieldContext::Subfields,
+                        &t_func.params_ret,
+                        &o_func.params_ret,
+                    );
+                }
+
+                self.check_type(this_f.layout, other_f.layout);
+            } else {
+                let t_field_layout = &self.old[this_f.layout];
+                let o_field_layout = &self.new[other_f.layout];
+                if t_field_layout.size != o_field_layout.size {
+                    self.push_err(t_field_layout.size, o_field_layout.size, K::Size);
+                }
+                if t_field_layout.alignment != o_field_layout.alignment {
+                    self.push_err(
+                        t_field_layout.alignment,
+                        o_field_layout.alignment,
+                        K::Alignment,
+                    );
+                }
+            }
+
+            self.path.pop();
+        }
+    }
+}
+
+/// Parses a `major.minor.patch` version string.
+fn parse_version(version: &str) -> Result<VersionNumber, &str> {
+    let mut iter = version.splitn(3, '.').map(str::parse::<u32>);
+    match (iter.next(), iter.next(), iter.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) => Ok(VersionNumber {
+            major,
+            minor,
+            patch,
+        }),
+        _ => Err(version),
+    }
+}
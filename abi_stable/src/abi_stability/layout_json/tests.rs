@@ -0,0 +1,218 @@
+#![allow(dead_code)]
+
+use super::*;
+
+use crate::{
+    std_types::{ROk, RStr},
+    StableAbi,
+};
+
+mod v0 {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Point {
+        pub x: u32,
+        pub y: u32,
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix))]
+    pub struct Module {
+        #[sabi(last_prefix_field)]
+        pub count: extern "C" fn(u32) -> u32,
+        pub name: RStr<'static>,
+    }
+
+    #[repr(u8)]
+    #[derive(StableAbi)]
+    pub enum Shape {
+        Point(Point),
+        Empty,
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Recursive {
+        pub value: u32,
+        pub next: Option<&'static Recursive>,
+    }
+}
+
+mod v1_compatible {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix))]
+    pub struct Module {
+        #[sabi(last_prefix_field)]
+        pub count: extern "C" fn(u32) -> u32,
+        pub name: RStr<'static>,
+        pub extra: u64,
+    }
+}
+
+mod v1_incompatible {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Point {
+        pub x: u64,
+        pub y: u64,
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix))]
+    pub struct Module {
+        #[sabi(last_prefix_field)]
+        pub count: extern "C" fn(u64) -> u32,
+        pub extra: u64,
+    }
+
+    #[repr(u8)]
+    #[derive(StableAbi)]
+    pub enum Shape {
+        Empty,
+        Point(super::v0::Point),
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Recursive {
+        pub value: u64,
+        pub next: Option<&'static Recursive>,
+    }
+}
+
+fn layout_json<T: StableAbi>() -> String {
+    serde_json::to_string(&LayoutJson::from_type_layout(T::LAYOUT)).unwrap()
+}
+
+fn assert_has_error(
+    errs: &[JsonAbiInstability],
+    path: &str,
+    pred: impl Fn(&JsonAbiInstabilityKind) -> bool,
+) {
+    assert!(
+        errs.iter()
+            .any(|e| e.path.starts_with(path) && pred(&e.kind)),
+        "{:#?}",
+        errs,
+    );
+}
+
+#[test]
+fn roundtrip() {
+    let layout = LayoutJson::from_type_layout(<v0::Recursive as StableAbi>::LAYOUT);
+    let json = serde_json::to_string(&layout).unwrap();
+    assert_eq!(serde_json::from_str::<LayoutJson>(&json).unwrap(), layout);
+}
+
+#[test]
+fn compare_compatible() {
+    for old in [
+        layout_json::<v0::Point>(),
+        layout_json::<v0::Shape>(),
+        layout_json::<v0::Recursive>(),
+        layout_json::<v0::Module_Ref>(),
+    ] {
+        assert_eq!(compare_layout_json(&old, &old), ROk(()));
+    }
+
+    let old = layout_json::<v0::Module_Ref>();
+    let new = layout_json::<v1_compatible::Module_Ref>();
+    assert_eq!(compare_layout_json(&old, &new), ROk(()));
+}
+
+#[test]
+fn compare_incompatible() {
+    {
+        let old = layout_json::<v0::Point>();
+        let new = layout_json::<v1_incompatible::Point>();
+        let errs = compare_layout_json(&old, &new).unwrap_err();
+        assert_has_error(&errs, "Point", |k| {
+            *k == JsonAbiInstabilityKind::Size(ExpectedFound {
+                expected: 8,
+                found: 16,
+            })
+        });
+        assert_has_error(
+            &errs,
+            "Point.x",
+            |k| matches!(k, JsonAbiInstabilityKind::Name(ef) if ef.expected == "u32"),
+        );
+    }
+    {
+        let old = layout_json::<v0::Module_Ref>();
+        let new = layout_json::<v1_incompatible::Module_Ref>();
+        let errs = compare_layout_json(&old, &new).unwrap_err();
+        assert_has_error(
+            &errs,
+            "PrefixRef.0.count",
+            |k| matches!(k, JsonAbiInstabilityKind::Name(ef) if ef.expected == "u32"),
+        );
+        assert_has_error(&errs, "PrefixRef.0", |k| {
+            matches!(
+                k,
+                JsonAbiInstabilityKind::UnexpectedField(ef) if ef.expected == "name",
+            )
+        });
+    }
+    {
+        let old = layout_json::<v0::Shape>();
+        let new = layout_json::<v1_incompatible::Shape>();
+        let errs = compare_layout_json(&old, &new).unwrap_err();
+        assert_has_error(&errs, "Shape", |k| {
+            matches!(
+                k,
+                JsonAbiInstabilityKind::UnexpectedVariant(ef) if ef.expected == "Point",
+            )
+        });
+    }
+    {
+        let old = layout_json::<v0::Recursive>();
+        let new = layout_json::<v1_incompatible::Recursive>();
+        let errs = compare_layout_json(&old, &new).unwrap_err();
+        assert_has_error(
+            &errs,
+            "Recursive.value",
+            |k| matches!(k, JsonAbiInstabilityKind::Name(ef) if ef.found == "u64"),
+        );
+    }
+}
+
+#[test]
+fn invalid_json() {
+    let valid = layout_json::<v0::Point>();
+
+    let errs = compare_layout_json("{", &valid).unwrap_err();
+    assert!(
+        matches!(
+            &errs[..],
+            [JsonAbiInstability {
+                kind: JsonAbiInstabilityKind::InvalidJson { which: "old", .. },
+                ..
+            }],
+        ),
+        "{:#?}",
+        errs,
+    );
+
+    let errs = compare_layout_json(&valid, r#"{"types":[]}"#).unwrap_err();
+    assert!(
+        matches!(
+            &errs[..],
+            [JsonAbiInstability {
+                kind: JsonAbiInstabilityKind::InvalidJson { which: "new", .. },
+                ..
+            }],
+        ),
+        "{:#?}",
+        errs,
+    );
+}
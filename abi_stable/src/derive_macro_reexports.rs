@@ -24,7 +24,8 @@ pub use crate::{
     pointer_trait::{AsMutPtr, AsPtr, GetPointerKind, PK_Reference},
     prefix_type::{
         panic_on_missing_field_ty, FieldAccessibility, FieldConditionality, IsAccessible,
-        IsConditional, PTStructLayout, PrefixRef, PrefixRefTrait, PrefixTypeTrait, WithMetadata_,
+        IsConditional, MissingFieldError, PTStructLayout, PrefixRef, PrefixRefTrait,
+        PrefixTypeTrait, WithMetadata_,
     },
     reflection::ModReflMode,
     sabi_trait::vtable::{GetRObjectVTable, RObjectVtable, RObjectVtable_Ref},
@@ -32,6 +32,8 @@ generated by the [`sabi_trait`] attribute.
 
 -[`from_value`](#from_value-method)
 
+-[`from_small_value`](#from_small_value-method)
+
 -[`from_const`](#from_const-method)
 
 -[`from_sabi`](#from_sabi-method)
@@ -167,6 +169,52 @@ assert_eq!(object.add_into(7), 110);
 ```
 
 
+## `from_small_value` method
+
+```text
+impl<'lt, Inline, …> Trait_TO<'lt, RSmallBox<(), Inline>, …> {
+    pub fn from_small_value<_Self, Downcasting>(
+        pointer: _Self,
+        can_it_downcast: Downcasting
+    ) -> Self
+```
+
+Constructs `<trait>_TO` from a type that implements `<trait>`,
+wrapping that value in an [`RSmallBox`],
+which stores the value inline (without allocating) if it fits in `Inline`,
+otherwise storing it in the heap.
+
+The `can_it_downcast` parameter describes whether the trait object can be
+converted back into the original type or not.<br>
+Its possible values are [`TD_CanDowncast`] and [`TD_Opaque`].
+
+[Method docs for `Action_TO::from_small_value`
+](../../sabi_trait/doc_examples/struct.Action_TO.html#method.from_small_value)
+
+**Example**:
+```rust
+use abi_stable::{
+    sabi_trait::doc_examples::Action_TO, sabi_types::RSmallBox,
+    type_level::downcasting::TD_CanDowncast,
+};
+
+// The type annotation is purely for the reader.
+let mut object: Action_TO<'static, RSmallBox<(), [usize; 2]>> =
+    Action_TO::from_small_value(100_usize, TD_CanDowncast);
+
+assert_eq!(object.get(), 100);
+
+assert_eq!(object.add_mut(3), 103);
+assert_eq!(object.get(), 103);
+
+// `usize` fits in `[usize; 2]`, so it was stored inline.
+let unerased = object.obj.downcast_into::<usize>().unwrap();
+assert!(RSmallBox::is_inline(&unerased));
+assert_eq!(RSmallBox::into_inner(unerased), 103);
+
+```
+
+
 ## `from_const` method
 
 ```text
@@ -378,6 +426,8 @@ where
 
 [`RBox`]: ../../std_types/struct.RBox.html
 
+[`RSmallBox`]: ../../sabi_types/struct.RSmallBox.html
+
 [`Action_TO`]: ../../sabi_trait/doc_examples/struct.Action_TO.html
 
 [`TD_CanDowncast`]: ../../type_level/downcasting/struct.TD_CanDowncast.html
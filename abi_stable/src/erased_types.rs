@@ -20,6 +20,8 @@ mod enabled_traits_macro;
 
 pub(crate) mod c_functions;
 
+mod counting_write;
+
 /// Types that implement `InterfaceType`, used in examples.
 pub mod interfaces;
 
@@ -40,6 +42,7 @@ pub(crate) mod traits;
 pub use crate::DynTrait;
 
 pub use self::{
+    counting_write::CountingWrite,
     dyn_trait::UneraseError,
     traits::{
         DeserializeDyn, InterfaceType, IteratorItem, IteratorItemOrDefault, SerializeProxyType,
@@ -0,0 +1,165 @@
+//! Contains the `CountingWrite` type.
+
+use std::fmt::{self, Write as fmtWrite};
+
+/// A `std::fmt::Write` wrapper that tracks how many bytes were written through it.
+///
+/// This is mostly useful with `DynTrait`s that implement `std::fmt::Write`,
+/// to limit how much output a plugin produces.
+///
+/// Only the bytes of successful writes are counted.
+///
+/// # Example
+///
+/// ```rust
+/// use abi_stable::{
+///     erased_types::{interfaces::FmtWriteInterface, CountingWrite},
+///     DynTrait, RMut,
+/// };
+///
+/// use std::fmt::Write;
+///
+/// let mut buffer = String::new();
+///
+/// let wrapped: DynTrait<'static, RMut<'_, ()>, FmtWriteInterface> =
+///     DynTrait::from_ptr(&mut buffer).interface(FmtWriteInterface);
+///
+/// let mut writer = CountingWrite::new(wrapped);
+///
+/// writer.write_str("Hello, ").unwrap();
+/// write!(writer, "{}!", "world").unwrap();
+/// assert_eq!(writer.bytes_written(), 13);
+///
+/// drop(writer);
+/// assert_eq!(buffer, "Hello, world!");
+///
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CountingWrite<W> {
+    writer: W,
+    bytes_written: usize,
+}
+
+impl<W> CountingWrite<W> {
+    /// Constructs a `CountingWrite` that writes into `writer`,
+    /// with a count of zero bytes written.
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            bytes_written: 0,
+        }
+    }
+
+    /// Gets the amount of bytes that were successfully written.
+    pub const fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    /// Resets the count of bytes written to zero.
+    pub fn reset_count(&mut self) {
+        self.bytes_written = 0;
+    }
+
+    /// Gets a reference to the wrapped writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the wrapped writer.
+    ///
+    /// Writes done directly through the returned reference are not counted.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwraps this into the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> fmtWrite for CountingWrite<W>
+where
+    W: fmtWrite,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_str(s)?;
+        self.bytes_written += s.len();
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.writer.write_char(c)?;
+        self.bytes_written += c.len_utf8();
+        Ok(())
+    }
+}
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    use crate::{erased_types::interfaces::FmtWriteInterface, std_types::RBox, DynTrait};
+
+    /// A writer that fails after `capacity` bytes were written.
+    struct Bounded {
+        buffer: String,
+        capacity: usize,
+    }
+
+    impl fmtWrite for Bounded {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.buffer.len() + s.len() > self.capacity {
+                return Err(fmt::Error);
+            }
+            self.buffer.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn counts_dyn_trait_writes() {
+        let wrapped: DynTrait<'static, RBox<()>, FmtWriteInterface> =
+            DynTrait::from_value(String::new()).interface(FmtWriteInterface);
+
+        let mut writer = CountingWrite::new(wrapped);
+        assert_eq!(writer.bytes_written(), 0);
+
+        writer.write_str("foo").unwrap();
+        assert_eq!(writer.bytes_written(), 3);
+
+        writer.write_char('ñ').unwrap();
+        assert_eq!(writer.bytes_written(), 5);
+
+        write!(writer, "{}-{:?}", 100, "bar").unwrap();
+        assert_eq!(writer.bytes_written(), 14);
+
+        writer.write_str("").unwrap();
+        assert_eq!(writer.bytes_written(), 14);
+
+        let wrapped = writer.into_inner();
+        let string = unsafe { wrapped.unchecked_downcast_as::<String>() };
+        assert_eq!(string, "fooñ100-\"bar\"");
+        assert_eq!(string.len(), 14);
+    }
+
+    #[test]
+    fn failed_writes_are_not_counted() {
+        let mut writer = CountingWrite::new(Bounded {
+            buffer: String::new(),
+            capacity: 8,
+        });
+
+        writer.write_str("hello").unwrap();
+        writer.write_str("world").unwrap_err();
+        assert_eq!(writer.bytes_written(), 5);
+
+        writer.write_str("abc").unwrap();
+        writer.write_char('d').unwrap_err();
+        assert_eq!(writer.bytes_written(), 8);
+        assert_eq!(writer.get_ref().buffer, "helloabc");
+
+        writer.reset_count();
+        assert_eq!(writer.bytes_written(), 0);
+    }
+}
@@ -3,7 +3,7 @@
 use std::{
     fmt::{self, Write as fmtWrite},
     io,
-    mem::ManuallyDrop,
+    mem::{self, ManuallyDrop},
     ptr,
     rc::Rc,
 };
@@ -19,7 +19,7 @@ use crate::{
     },
     prefix_type::PrefixRef,
     sabi_types::{MovePtr, RMut, RRef},
-    std_types::{RBox, RIoError, RStr, RVec},
+    std_types::{RBox, RCmpOrdering, RIoError, RNone, ROption, RSome, RStr, RVec},
     type_level::{
         downcasting::{TD_CanDowncast, TD_Opaque},
         impl_enum::{Implemented, Unimplemented},
@@ -209,6 +209,9 @@ mod priv_ {
     /// DynTraits wrapping different pointer types can be compared with each other,
     /// it simply uses the values' implementation of PartialEq.
     ///
+    /// DynTraits with different interfaces can also be compared with `==` and `<`,
+    /// so long as both interfaces implement `PartialEq`/`PartialOrd`.
+    ///
     /// ```
     /// use abi_stable::{
     ///     erased_types::interfaces::PartialEqInterface,
@@ -286,6 +289,36 @@ mod priv_ {
     ///
     /// ```
     ///
+    /// A `DynTrait` with an owning pointer (eg: `RBox<()>`) owns the erased iterator,
+    /// so it can be returned from functions (eg: plugin methods) and consumed
+    /// like any other owned iterator, with no extra wrapper type.
+    ///
+    /// ```
+    /// use abi_stable::{
+    ///     erased_types::interfaces::IteratorInterface,
+    ///     std_types::{RBox, RString},
+    ///     DynTrait,
+    /// };
+    ///
+    /// type StringIter = DynTrait<'static, RBox<()>, IteratorInterface<RString>>;
+    ///
+    /// fn words(text: &str) -> StringIter {
+    ///     let words = text
+    ///         .split_whitespace()
+    ///         .map(RString::from)
+    ///         .collect::<Vec<RString>>();
+    ///
+    ///     DynTrait::from_value(words.into_iter()).interface(IteratorInterface::NEW)
+    /// }
+    ///
+    /// let mut list = Vec::new();
+    /// for word in words("hello world") {
+    ///     list.push(word);
+    /// }
+    /// assert_eq!(list, vec![RString::from("hello"), RString::from("world")]);
+    ///
+    /// ```
+    ///
     ///
     /// # Making pointers compatible with DynTrait
     ///
@@ -474,6 +507,34 @@ mod priv_ {
             let object = RBox::new(object);
             DynTrait::from_ptr(object)
         }
+
+        /// Constructs the `DynTrait<_>` from the default value of `T`,
+        /// without requiring an existing `DynTrait` to call [`default`](#method.default) on.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDefEqInterface,
+        ///     std_types::{RBox, RVec},
+        ///     DynTrait,
+        /// };
+        ///
+        /// let object: DynTrait<'static, RBox<()>, DebugDefEqInterface> =
+        ///     DynTrait::from_default::<RVec<u8>>();
+        ///
+        /// assert_eq!(object.downcast_as::<RVec<u8>>().unwrap(), &RVec::<u8>::new());
+        /// assert_eq!(object, object.default());
+        ///
+        /// ```
+        pub fn from_default<T>() -> Self
+        where
+            T: Default + 'static,
+            I: InterfaceType<Default = Implemented<trait_marker::Default>>,
+            VTable_Ref<'static, RBox<()>, I>: MakeVTable<'static, T, RBox<T>, TD_CanDowncast>,
+        {
+            Self::from_value(T::default())
+        }
     }
 
     impl<P, I> DynTrait<'static, P, I>
@@ -656,6 +717,63 @@ mod priv_ {
         }
     }
 
+    #[cfg(feature = "serde_json")]
+    use crate::std_types::RArc;
+
+    #[cfg(feature = "serde_json")]
+    impl<I> DynTrait<'static, RArc<()>, I> {
+        /// Deserializes a `T` from the `json` string,
+        /// and constructs an `RArc`-backed `DynTrait` from it.
+        ///
+        /// This is a shorthand for deserializing `T` with `serde_json`,
+        /// wrapping it in an `RArc`, and passing that to `DynTrait::from_ptr`,
+        /// intended for implementing the function that a
+        /// [`DeserializeDyn`] impl delegates to.
+        ///
+        /// # Errors
+        ///
+        /// This returns an error if `T` can't be deserialized from `json`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     std_types::{RArc, RResult},
+        ///     DynTrait, StableAbi,
+        /// };
+        ///
+        /// #[repr(C)]
+        /// #[derive(StableAbi)]
+        /// #[sabi(impl_InterfaceType(Clone, Debug, Display))]
+        /// struct CloneDisplayInterface;
+        ///
+        /// let res: RResult<DynTrait<'static, RArc<()>, CloneDisplayInterface>, _> =
+        ///     DynTrait::deserialize_shared_from_str::<u32>(" 100 ".into());
+        /// let to = res.unwrap();
+        ///
+        /// assert_eq!(to.downcast_as::<u32>().ok(), Some(&100));
+        ///
+        /// let shared = to.clone();
+        /// assert_eq!(format!("{}", shared), "100");
+        ///
+        /// let res = DynTrait::<'static, RArc<()>, CloneDisplayInterface>::
+        ///     deserialize_shared_from_str::<u32>("-1".into());
+        /// assert!(res.is_err());
+        ///
+        /// ```
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "serde_json")))]
+        pub fn deserialize_shared_from_str<'de, T>(json: RStr<'de>) -> RResult<Self, RBoxError>
+        where
+            T: Deserialize<'de> + 'static,
+            VTable_Ref<'static, RArc<()>, I>: MakeVTable<'static, T, RArc<T>, TD_CanDowncast>,
+        {
+            match serde_json::from_str::<T>(json.as_str()) {
+                Ok(x) => ROk(DynTrait::from_ptr(RArc::new(x))),
+                Err(e) => RErr(RBoxError::new(e)),
+            }
+        }
+    }
+
     impl<'borr, P, I, EV> DynTrait<'borr, P, I, EV>
     where
         P: AsPtr<PtrTarget = ()>,
@@ -788,6 +906,55 @@ mod priv_ {
         }
     }
 
+    impl<I> DynTrait<'static, RRef<'static, ()>, I> {
+        /// Constructs a downcastable `DynTrait` from `value`,
+        /// leaking it to get a `'static` reference to it.
+        ///
+        /// This is for `'static` values that can only be constructed at runtime,
+        /// values that can be constructed at compile-time can be
+        /// put in a `static` with [`from_const`](Self::from_const) instead.
+        ///
+        /// Since the value is leaked, it's never dropped.
+        ///
+        /// # Example
+        ///
+        /// Lazily initializing a `static` with a value constructed at runtime.
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::{interfaces::DebugDisplayInterface, DynTrait},
+        ///     sabi_types::{LateStaticRef, RRef},
+        ///     std_types::RString,
+        /// };
+        ///
+        /// type StaticDyn = DynTrait<'static, RRef<'static, ()>, DebugDisplayInterface>;
+        ///
+        /// static GREETING: LateStaticRef<&StaticDyn> = LateStaticRef::new();
+        ///
+        /// fn greeting() -> &'static StaticDyn {
+        ///     GREETING.init(|| {
+        ///         let name = std::env::var("USER_NAME").unwrap_or_else(|_| "world".into());
+        ///         let value = RString::from(format!("hello {}", name));
+        ///         Box::leak(Box::new(DynTrait::from_static_owned(value)))
+        ///     })
+        /// }
+        ///
+        /// assert!(greeting().to_string().starts_with("hello "));
+        /// assert!(std::ptr::eq(greeting(), greeting()));
+        /// assert!(greeting().downcast_as::<RString>().is_ok());
+        ///
+        /// ```
+        pub fn from_static_owned<T>(value: T) -> Self
+        where
+            T: 'static,
+            VTable_Ref<'static, RRef<'static, ()>, I>:
+                MakeVTable<'static, T, &'static T, TD_CanDowncast>,
+        {
+            let value: &'static T = Box::leak(Box::new(value));
+            DynTrait::from_const(value, TD_CanDowncast, ())
+        }
+    }
+
     impl<'borr, P, I, EV> DynTrait<'borr, P, I, EV>
     where
         P: GetPointerKind,
@@ -831,6 +998,70 @@ mod priv_ {
         }
     }
 
+    impl<P, I, EV> DynTrait<'static, P, I, EV>
+    where
+        P: AsPtr,
+        I: InterfaceType<Ord = Implemented<trait_marker::Ord>>,
+    {
+        /// Compares this with `other` using the `Ord` impl of the wrapped type,
+        /// returning `RNone` if they wrap values of different types.
+        ///
+        /// This differs from the `Ord` impl of `DynTrait`,
+        /// which orders `DynTrait`s of different types by the address of their vtables,
+        /// allowing callers to detect that a collection contains values of different types.
+        ///
+        /// Which types are considered the same is determined by
+        /// [`sabi_is_same_type`](Self::sabi_is_same_type).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     std_types::{RBox, RCmpOrdering, RNone, RSome, RString, RVec},
+        ///     DynTrait, StableAbi,
+        /// };
+        ///
+        /// #[repr(C)]
+        /// #[derive(StableAbi)]
+        /// #[sabi(impl_InterfaceType(Debug, Ord))]
+        /// struct OrdInterface;
+        ///
+        /// type OrdBox = DynTrait<'static, RBox<()>, OrdInterface>;
+        ///
+        /// let mut list: RVec<OrdBox> = [8u32, 3, 5]
+        ///     .iter()
+        ///     .map(|&x| DynTrait::from_value(x))
+        ///     .collect();
+        ///
+        /// assert_eq!(list[0].sabi_cmp(&list[1]), RSome(RCmpOrdering::Greater));
+        ///
+        /// // All the elements are `u32`s, so they can be sorted meaningfully.
+        /// list.sort_by(|l, r| l.sabi_cmp(r).unwrap().into());
+        ///
+        /// let sorted = list
+        ///     .iter()
+        ///     .map(|x| *x.downcast_as::<u32>().unwrap())
+        ///     .collect::<Vec<u32>>();
+        /// assert_eq!(sorted, [3, 5, 8]);
+        ///
+        /// let string: OrdBox = DynTrait::from_value(RString::from("hello"));
+        /// assert_eq!(list[0].sabi_cmp(&string), RNone);
+        ///
+        /// ```
+        pub fn sabi_cmp(&self, other: &Self) -> ROption<RCmpOrdering> {
+            if !self.sabi_is_same_type(other) {
+                return RNone;
+            }
+
+            unsafe {
+                RSome(self.sabi_vtable().cmp()(
+                    self.sabi_erased_ref(),
+                    other.sabi_erased_ref(),
+                ))
+            }
+        }
+    }
+
     impl<'borr, P, I, EV> DynTrait<'borr, P, I, PrefixRef<EV>>
     where
         P: GetPointerKind,
@@ -865,6 +1096,181 @@ mod priv_ {
             &self.extra_value
         }
 
+        /// Transforms the extra value stored in this `DynTrait` with `f`,
+        /// keeping the same wrapped object.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{erased_types::TD_Opaque, std_types::RBox, DynTrait};
+        ///
+        /// #[derive(Debug, PartialEq)]
+        /// struct PluginId {
+        ///     id: u32,
+        /// }
+        ///
+        /// let to: DynTrait<'static, RBox<()>, (), u32> =
+        ///     DynTrait::with_extra_value::<_, TD_Opaque>(RBox::new(55u8), 3);
+        ///
+        /// let to = to.map_extra_value(|id| PluginId { id });
+        ///
+        /// assert_eq!(to.sabi_extra_value(), &PluginId { id: 3 });
+        /// assert_eq!(unsafe { to.unchecked_downcast_as::<u8>() }, &55);
+        ///
+        /// ```
+        pub fn map_extra_value<EV2, F>(self, f: F) -> DynTrait<'borr, P, I, EV2>
+        where
+            F: FnOnce(EV) -> EV2,
+        {
+            let this = ManuallyDrop::new(self);
+            unsafe {
+                // Keeping the object in a DynTrait,so that it's dropped if `f` panics.
+                let object = DynTrait {
+                    object: ptr::read(&this.object),
+                    vtable: this.vtable,
+                    extra_value: (),
+                    _marker: NonOwningPhantom::NEW,
+                    _marker2: UnsafeIgnoredType::DEFAULT,
+                };
+                let extra_value = f(ptr::read(&this.extra_value));
+                let object = ManuallyDrop::new(object);
+                DynTrait {
+                    object: ptr::read(&object.object),
+                    vtable: object.vtable,
+                    extra_value,
+                    _marker: NonOwningPhantom::NEW,
+                    _marker2: UnsafeIgnoredType::DEFAULT,
+                }
+            }
+        }
+
+        /// Gets the name of the type of the wrapped object,
+        /// as returned by `std::any::type_name`.
+        ///
+        /// The name is only intended for diagnostics,
+        /// it is not guaranteed to be unique, or stable across compiler versions.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     std_types::{RBox, RString},
+        ///     DynTrait,
+        /// };
+        ///
+        /// let to: DynTrait<'static, RBox<()>, ()> = DynTrait::from_value(RString::new());
+        ///
+        /// assert_eq!(to.sabi_type_name(), std::any::type_name::<RString>());
+        ///
+        /// ```
+        pub fn sabi_type_name(&self) -> RStr<'static> {
+            self.sabi_vtable().type_info().type_name.get()
+        }
+
+        /// Queries whether dropping this `DynTrait` does anything.
+        ///
+        /// This returns false when the pointer is a reference
+        /// (eg: `RRef<'_, ()>`/`RMut<'_, ()>`, as returned by `reborrow`/`reborrow_mut`),
+        /// and the extra value doesn't need to be dropped.
+        /// In that case, the `DynTrait` can be leaked instead of dropped.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     std_types::{RBox, RString},
+        ///     DynTrait,
+        /// };
+        ///
+        /// let mut to: DynTrait<'static, RBox<()>, ()> = DynTrait::from_value(RString::new());
+        ///
+        /// assert!(to.sabi_needs_drop());
+        /// assert!(!to.reborrow().sabi_needs_drop());
+        /// assert!(!to.reborrow_mut().sabi_needs_drop());
+        ///
+        /// ```
+        pub fn sabi_needs_drop(&self) -> bool {
+            <P as GetPointerKind>::KIND == PointerKind::SmartPointer || mem::needs_drop::<EV>()
+        }
+
+        /// Compares this `DynTrait` with another one for equality,
+        /// supporting `DynTrait`s constructed with the `from_borrowing_*` constructors.
+        ///
+        /// This returns false if the objects are not of the same type.
+        ///
+        /// The `PartialEq` impl for `DynTrait` requires both objects to be `'static`,
+        /// because the type identity check can't distinguish lifetime parameters,
+        /// eg: it can't tell apart a `Foo<'a>` and a `Foo<'b>`.
+        ///
+        /// Notes:
+        ///
+        /// - `DynTrait`s constructed using `DynTrait::from_borrowing_*`
+        ///   are only considered to wrap the same type if they were constructed
+        ///   with the same pointer and interface types,
+        ///   in the same dynamic library/executable.
+        ///
+        /// # Safety
+        ///
+        /// If both `DynTrait`s wrap the same type (ignoring lifetime parameters),
+        /// then either:
+        ///
+        /// - They must wrap the same type, including lifetime parameters.
+        ///
+        /// - The wrapped type must be covariant over all its lifetime parameters
+        ///   (eg: `&'a str`, `Vec<&'a str>`, but not `Cell<&'a str>`).
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     erased_types::interfaces::PartialEqInterface,
+        ///     std_types::RBox,
+        ///     DynTrait,
+        /// };
+        ///
+        /// let text = String::from("hello world");
+        ///
+        /// let make = |s| -> DynTrait<'_, RBox<()>, PartialEqInterface> {
+        ///     DynTrait::from_borrowing_value(s).interface(PartialEqInterface)
+        /// };
+        ///
+        /// let hello = make(&text[..5]);
+        /// let other_hello = make(&text[..5]);
+        /// let world = make(&text[6..]);
+        ///
+        /// // safety: `&str` is covariant over its lifetime
+        /// unsafe {
+        ///     assert!(hello.sabi_partial_eq(&other_hello));
+        ///     assert!(!hello.sabi_partial_eq(&world));
+        /// }
+        ///
+        /// ```
+        pub unsafe fn sabi_partial_eq<P2, I2, EV2>(
+            &self,
+            other: &DynTrait<'borr, P2, I2, EV2>,
+        ) -> bool
+        where
+            P: AsPtr,
+            P2: AsPtr,
+            I: InterfaceType<PartialEq = Implemented<trait_marker::PartialEq>>,
+            I2: InterfaceType<PartialEq = Implemented<trait_marker::PartialEq>>,
+        {
+            let is_same_type = self.sabi_vtable_address() == other.sabi_vtable_address()
+                || self
+                    .sabi_vtable()
+                    .type_info()
+                    .is_compatible(other.sabi_vtable().type_info());
+
+            // unsafe: must check that the vtable is the same, otherwise return a sensible value.
+            if !is_same_type {
+                return false;
+            }
+
+            unsafe {
+                self.sabi_vtable().partial_eq()(self.sabi_erased_ref(), other.sabi_erased_ref())
+            }
+        }
+
         #[inline]
         pub(super) const fn sabi_vtable(&self) -> VTable_Ref<'borr, P, I> {
             self.vtable
@@ -1064,6 +1470,33 @@ mod priv_ {
             }
         }
 
+        /// Queries whether `T` is the concrete type this `DynTrait<_>` was constructed with.
+        ///
+        /// This does the same check as the `downcast_*` methods,
+        /// so this returns false in the same conditions where they would return an error.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{std_types::RBox, DynTrait};
+        ///
+        /// let to: DynTrait<'static, RBox<()>, ()> = DynTrait::from_value(3u8);
+        ///
+        /// assert!(to.sabi_is::<u8>());
+        /// assert!(!to.sabi_is::<u16>());
+        ///
+        /// let borrowing: DynTrait<'static, RBox<()>, ()> = DynTrait::from_borrowing_value(3u8);
+        ///
+        /// assert!(!borrowing.sabi_is::<u8>());
+        ///
+        /// ```
+        pub fn sabi_is<T>(&self) -> bool
+        where
+            T: 'static,
+        {
+            self.sabi_check_same_destructor::<T>().is_ok()
+        }
+
         /// Unwraps the `DynTrait<_>` into a pointer of
         /// the concrete type that it was constructed with.
         ///
@@ -1222,6 +1655,62 @@ mod priv_ {
             unsafe { Ok(self.sabi_object_as_mut()) }
         }
 
+        /// Passes a `MovePtr` to the concrete type that this `DynTrait<_>`
+        /// was constructed with to `f`,
+        /// moving the value out without going through `P::TransmutedPtr`.
+        ///
+        /// The allocation of the pointer is deallocated after `f` returns,
+        /// which allows moving the value into a caller-provided location.
+        ///
+        /// `T` is required to not borrow anything.
+        ///
+        /// # Errors
+        ///
+        /// This will return an error in any of these conditions:
+        ///
+        /// - It is called in a dynamic library/binary outside
+        /// the one from which this `DynTrait<_>` was constructed.
+        ///
+        /// - The DynTrait was constructed using a `from_borrowing_*` method
+        ///
+        /// - `T` is not the concrete type this `DynTrait<_>` was constructed with.
+        ///
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     sabi_types::MovePtr,
+        ///     std_types::{RBox, RString},
+        ///     DynTrait,
+        /// };
+        ///
+        /// fn to() -> DynTrait<'static, RBox<()>, ()> {
+        ///     DynTrait::from_value(RString::from("hello"))
+        /// }
+        ///
+        /// let len = to().downcast_with_move_ptr(|x: MovePtr<'_, RString>| x.len());
+        /// assert_eq!(len.ok(), Some(5));
+        ///
+        /// let string = to().downcast_with_move_ptr(|x: MovePtr<'_, RString>| {
+        ///     MovePtr::into_inner(x)
+        /// });
+        /// assert_eq!(string.ok(), Some(RString::from("hello")));
+        ///
+        /// assert!(to().downcast_with_move_ptr(|_: MovePtr<'_, u8>| ()).is_err());
+        ///
+        /// ```
+        pub fn downcast_with_move_ptr<T, F, R>(self, f: F) -> Result<R, UneraseError<Self>>
+        where
+            T: 'static,
+            P: OwnedPointer<PtrTarget = ()>,
+            F: FnOnce(MovePtr<'_, T>) -> R,
+        {
+            check_unerased!(self, self.sabi_check_same_destructor::<T>());
+            // SAFETY: `sabi_check_same_destructor` ensures that the erased value is a `T`.
+            Ok(self.sabi_with_value(|x| unsafe { f(MovePtr::transmute::<T>(x)) }))
+        }
+
         /// Unwraps the `DynTrait<_>` into a pointer to T,
         /// without checking whether `T` is the type that the DynTrait was constructed with.
         ///
@@ -1374,6 +1863,13 @@ mod priv_ {
         ///
         /// - DynTrait::default
         ///
+        /// Cloning the reborrowed DynTrait copies the reference,
+        /// to get an independent copy of the value, clone the DynTrait that was reborrowed
+        /// (the vtable only knows how to clone the pointer the DynTrait was constructed with).
+        ///
+        /// Reborrowing a reference-counted DynTrait (eg: one constructed from an `RArc`)
+        /// does not change its reference count.
+        ///
         /// This is only callable if `DynTrait` is either `Send + Sync` or `!Send + !Sync`.
         ///
         /// # Example
@@ -1567,6 +2063,7 @@ mod priv_ {
         {
             unsafe { self.sabi_vtable().serialize()(self.sabi_erased_ref()).into_result() }
         }
+
         /// Deserializes a `DynTrait<'borr, _>` from a proxy type, by using
         /// `<I as DeserializeDyn<'borr, Self>>::deserialize_dyn`.
         pub fn deserialize_from_proxy<'de>(proxy: I::Proxy) -> Result<Self, RBoxError>
@@ -1576,6 +2073,59 @@ mod priv_ {
         {
             I::deserialize_dyn(proxy)
         }
+
+        /// Deserializes a `DynTrait<'borr, _>` from a proxy type, by using
+        /// `<I as DeserializeDyn<'borr, Self>>::deserialize_dyn`,
+        /// returning a copy of the proxy alongside the error if it fails.
+        ///
+        /// Because `deserialize_dyn` takes the proxy by value,
+        /// this clones the proxy before deserializing it.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use abi_stable::{
+        ///     erased_types::{DeserializeDyn, DynTrait},
+        ///     std_types::{RBox, RBoxError, RString},
+        ///     StableAbi,
+        /// };
+        ///
+        /// #[repr(C)]
+        /// #[derive(StableAbi)]
+        /// #[sabi(impl_InterfaceType(Deserialize, Debug))]
+        /// struct NumInterface;
+        ///
+        /// impl<'a> DeserializeDyn<'a, DynTrait<'static, RBox<()>, NumInterface>> for NumInterface {
+        ///     type Proxy = RString;
+        ///
+        ///     fn deserialize_dyn(
+        ///         s: RString,
+        ///     ) -> Result<DynTrait<'static, RBox<()>, NumInterface>, RBoxError> {
+        ///         s.parse::<u32>()
+        ///             .map(DynTrait::from_value)
+        ///             .map_err(RBoxError::new)
+        ///     }
+        /// }
+        ///
+        /// type Num = DynTrait<'static, RBox<()>, NumInterface>;
+        ///
+        /// let num = Num::try_deserialize_from_proxy("100".into()).unwrap();
+        /// assert_eq!(num.downcast_as::<u32>().unwrap(), &100);
+        ///
+        /// let (proxy, _err) = Num::try_deserialize_from_proxy("hello".into()).unwrap_err();
+        /// assert_eq!(proxy, "hello");
+        ///
+        /// ```
+        pub fn try_deserialize_from_proxy<'de>(
+            proxy: I::Proxy,
+        ) -> Result<Self, (I::Proxy, RBoxError)>
+        where
+            P: 'borr + AsPtr,
+            I: DeserializeDyn<'de, Self>,
+            I::Proxy: Clone,
+        {
+            I::deserialize_dyn(proxy.clone()).map_err(|e| (proxy, e))
+        }
     }
 
     impl<'lt, I, EV: Clone> DynTrait<'lt, crate::std_types::RArc<()>, I, EV> {
@@ -1612,11 +2162,32 @@ pub use self::priv_::DynTrait;
 //////////////////////
 
 mod clone_impl {
+    use crate::pointer_trait::{PK_Reference, PK_SmartPointer};
+
     pub trait CloneImpl<PtrKind> {
         fn clone_impl(&self) -> Self;
     }
+
+    /// The pointer kinds of `DynTrait`s that can be cloned.
+    ///
+    /// This is not implemented for `PK_MutReference`,
+    /// so that cloning a `DynTrait<'_, RMut<'_, ()>, _>` errors
+    /// with a `PK_MutReference: CloneablePointerKind` bound.
+    #[cfg_attr(
+        feature = "rust_1_78",
+        diagnostic::on_unimplemented(
+            message = "`DynTrait`s with the `{Self}` pointer kind can't be cloned",
+            label = "this `DynTrait` can't be cloned",
+            note = "`DynTrait`s with an `RMut` pointer can't be cloned, \
+                    since that would create multiple mutable references to the same value",
+        )
+    )]
+    pub trait CloneablePointerKind {}
+
+    impl CloneablePointerKind for PK_SmartPointer {}
+    impl CloneablePointerKind for PK_Reference {}
 }
-use self::clone_impl::CloneImpl;
+use self::clone_impl::{CloneImpl, CloneablePointerKind};
 
 /// This impl is for smart pointers.
 impl<'borr, P, I, EV> CloneImpl<PK_SmartPointer> for DynTrait<'borr, P, I, EV>
@@ -1649,24 +2220,46 @@ where
 /// Clone is implemented for references and smart pointers,
 /// using `GetPointerKind` to decide whether `P` is a smart pointer or a reference.
 ///
-/// DynTrait does not implement Clone if P ==`RMut<'_, ()>` :
+/// DynTrait does not implement Clone if P ==`RMut<'_, ()>`,
+/// since that would create multiple mutable references to the same value.
+/// Attempting to clone one of those (eg: a DynTrait returned by `reborrow_mut`)
+/// causes an error saying that the
+/// `PK_MutReference: CloneablePointerKind` bound is not satisfied
+/// (with the "rust_1_78" feature,
+/// requiring `Clone` in a generic function errors with
+/// "`DynTrait`s with the `PK_MutReference` pointer kind can't be cloned"):
 ///
-/// ```compile_fail
+/// ```compile_fail,E0599
 /// # use abi_stable::{
 /// #     DynTrait,
 /// #     erased_types::interfaces::CloneInterface,
 /// # };
 ///
-/// let mut object = DynTrait::from_value(()).interface(());
+/// let mut object = DynTrait::from_value(()).interface(CloneInterface);
 /// let borrow = object.reborrow_mut();
 /// let _ = borrow.clone();
 ///
 /// ```
 ///
+/// Shared reborrows (returned by `reborrow`) can be cloned:
+///
+/// ```
+/// # use abi_stable::{
+/// #     DynTrait,
+/// #     erased_types::interfaces::CloneInterface,
+/// # };
+///
+/// let object = DynTrait::from_value(()).interface(CloneInterface);
+/// let borrow = object.reborrow();
+/// let _ = borrow.clone();
+///
+/// ```
+///
 impl<'borr, P, I, EV> Clone for DynTrait<'borr, P, I, EV>
 where
     P: AsPtr,
     I: InterfaceType,
+    <P as GetPointerKind>::Kind: CloneablePointerKind,
     Self: CloneImpl<<P as GetPointerKind>::Kind>,
 {
     fn clone(&self) -> Self {
@@ -1762,13 +2355,15 @@ where
 {
 }
 
-impl<P, P2, I, EV, EV2> PartialEq<DynTrait<'static, P2, I, EV2>> for DynTrait<'static, P, I, EV>
+impl<P, P2, I, I2, EV, EV2> PartialEq<DynTrait<'static, P2, I2, EV2>>
+    for DynTrait<'static, P, I, EV>
 where
     P: AsPtr,
     P2: AsPtr,
     I: InterfaceType<PartialEq = Implemented<trait_marker::PartialEq>>,
+    I2: InterfaceType<PartialEq = Implemented<trait_marker::PartialEq>>,
 {
-    fn eq(&self, other: &DynTrait<'static, P2, I, EV2>) -> bool {
+    fn eq(&self, other: &DynTrait<'static, P2, I2, EV2>) -> bool {
         // unsafe: must check that the vtable is the same, otherwise return a sensible value.
         if !self.sabi_is_same_type(other) {
             return false;
@@ -1794,14 +2389,16 @@ where
     }
 }
 
-impl<P, P2, I, EV, EV2> PartialOrd<DynTrait<'static, P2, I, EV2>> for DynTrait<'static, P, I, EV>
+impl<P, P2, I, I2, EV, EV2> PartialOrd<DynTrait<'static, P2, I2, EV2>>
+    for DynTrait<'static, P, I, EV>
 where
     P: AsPtr,
     P2: AsPtr,
     I: InterfaceType<PartialOrd = Implemented<trait_marker::PartialOrd>>,
-    Self: PartialEq<DynTrait<'static, P2, I, EV2>>,
+    I2: InterfaceType<PartialOrd = Implemented<trait_marker::PartialOrd>>,
+    Self: PartialEq<DynTrait<'static, P2, I2, EV2>>,
 {
-    fn partial_cmp(&self, other: &DynTrait<'static, P2, I, EV2>) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &DynTrait<'static, P2, I2, EV2>) -> Option<Ordering> {
         // unsafe: must check that the vtable is the same, otherwise return a sensible value.
         if !self.sabi_is_same_type(other) {
             return Some(self.sabi_vtable_address().cmp(&other.sabi_vtable_address()));
@@ -2106,6 +2703,38 @@ where
     }
 }
 
+impl<'borr, P, I, EV> DynTrait<'borr, P, I, EV>
+where
+    P: AsMutPtr,
+    I: InterfaceType<IoWrite = Implemented<trait_marker::IoWrite>>,
+{
+    /// Gets this `DynTrait` as a `&mut dyn io::Write`,
+    /// for passing it to functions that take `io::Write` trait objects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{erased_types::interfaces::IoWriteInterface, DynTrait};
+    ///
+    /// use std::io::{self, Write};
+    ///
+    /// fn greet(out: &mut dyn Write) -> io::Result<()> {
+    ///     out.write_all(b"hello")
+    /// }
+    ///
+    /// let mut buffer = Vec::<u8>::new();
+    /// {
+    ///     let mut wrapped = DynTrait::from_ptr(&mut buffer).interface(IoWriteInterface);
+    ///     greet(wrapped.as_io_write()).unwrap();
+    /// }
+    /// assert_eq!(buffer, b"hello");
+    ///
+    /// ```
+    pub fn as_io_write(&mut self) -> &mut (dyn io::Write + '_) {
+        self
+    }
+}
+
 /////////////
 
 impl<'borr, P, I, EV> io::Read for DynTrait<'borr, P, I, EV>
@@ -2130,6 +2759,43 @@ where
     }
 }
 
+impl<'borr, P, I, EV> DynTrait<'borr, P, I, EV>
+where
+    P: AsMutPtr,
+    I: InterfaceType<IoRead = Implemented<trait_marker::IoRead>>,
+{
+    /// Gets this `DynTrait` as a `&mut dyn io::Read`,
+    /// for passing it to functions that take `io::Read` trait objects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{std_types::RBox, DynTrait, InterfaceType, StableAbi};
+    ///
+    /// use std::io::{self, Read};
+    ///
+    /// #[repr(C)]
+    /// #[derive(StableAbi)]
+    /// #[sabi(impl_InterfaceType(IoRead))]
+    /// struct ReadInterface;
+    ///
+    /// fn read_all(input: &mut dyn Read) -> io::Result<Vec<u8>> {
+    ///     let mut out = Vec::new();
+    ///     input.read_to_end(&mut out)?;
+    ///     Ok(out)
+    /// }
+    ///
+    /// let mut wrapped: DynTrait<'_, RBox<()>, ReadInterface> =
+    ///     DynTrait::from_value(io::Cursor::new(vec![3, 5, 8]));
+    ///
+    /// assert_eq!(read_all(wrapped.as_io_read()).unwrap(), [3, 5, 8]);
+    ///
+    /// ```
+    pub fn as_io_read(&mut self) -> &mut (dyn io::Read + '_) {
+        self
+    }
+}
+
 /////////////
 
 impl<'borr, P, I, EV> io::BufRead for DynTrait<'borr, P, I, EV>
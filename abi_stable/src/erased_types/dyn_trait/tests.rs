@@ -15,7 +15,7 @@ use serde_json;
 
 #[allow(unused_imports)]
 use crate::{
-    erased_types::{DynTrait, InterfaceType, IteratorItem},
+    erased_types::{interfaces::PartialEqInterface, DynTrait, InterfaceType, IteratorItem},
     std_types::{RArc, RBox, RBoxError, RCow, RNone, ROption, RSome, RStr, RString},
     test_utils::{GetImpls, GetImplsHelper},
     traits::IntoReprC,
@@ -143,6 +143,57 @@ fn debug_interface_test() {
 
 /////////////////////////////////
 
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Serialize))]
+struct SerializeInterface;
+
+impl<'s> SerializeProxyType<'s> for SerializeInterface {
+    type Proxy = RString;
+}
+
+type DebugSerializeInterface =
+    crate::erased_types::interfaces::CombineInterfaces<DebugInterface, SerializeInterface>;
+
+#[derive(Debug)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl<'s> SerializeType<'s> for Point {
+    type Interface = DebugSerializeInterface;
+
+    fn serialize_impl(&'s self) -> Result<RString, RBoxError> {
+        Ok(format!("({}, {})", self.x, self.y).into())
+    }
+}
+
+#[test]
+fn combined_interface_test() {
+    type GI = GetImpls<DynTraitBox<DebugSerializeInterface>>;
+    assert!(GI::IMPLS_SEND);
+    assert!(GI::IMPLS_SYNC);
+    assert!(GI::IMPLS_DEBUG);
+    assert!(GI::IMPLS_SERIALIZE);
+    assert!(!GI::IMPLS_UNPIN);
+    assert!(!GI::IMPLS_CLONE);
+    assert!(!GI::IMPLS_DISPLAY);
+    assert!(!GI::IMPLS_EQ);
+    assert!(!GI::IMPLS_DESERIALIZE);
+    assert!(!GI::IMPLS_ITERATOR);
+
+    let point = Point { x: 3, y: 5 };
+    let debug = format!("{:?}", point);
+
+    let wrapped: DynTraitBox<DebugSerializeInterface> = DynTrait::from_value(point);
+    assert_eq!(format!("{:?}", wrapped), debug);
+    assert_eq!(wrapped.serialize_into_proxy().unwrap(), "(3, 5)");
+    assert_eq!(serde_json::to_string(&wrapped).unwrap(), r#""(3, 5)""#);
+}
+
+/////////////////////////////////
+
 fn new_foo() -> Foo<String> {
     Foo {
         l: 1000,
@@ -155,6 +206,51 @@ fn new_wrapped() -> VirtualFoo<'static> {
     DynTrait::from_value(new_foo())
 }
 
+#[test]
+fn from_static_owned_test() {
+    use crate::sabi_types::{LateStaticRef, RRef};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type StaticDyn = DynTrait<'static, RRef<'static, ()>, DebugInterface>;
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug)]
+    struct Counted(RVec<u32>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    static LIST: LateStaticRef<&StaticDyn> = LateStaticRef::new();
+
+    let init = || -> &'static StaticDyn {
+        LIST.init(|| {
+            let list = (1..=4).map(|x| x * 10).collect::<RVec<u32>>();
+            Box::leak(Box::new(DynTrait::from_static_owned(Counted(list))))
+        })
+    };
+
+    let first = init();
+    let second = init();
+    assert!(std::ptr::eq(first, second));
+
+    assert_eq!(format!("{:?}", first), "Counted([10, 20, 30, 40])");
+    assert_eq!(
+        first.downcast_as::<Counted>().unwrap().0.as_slice(),
+        &[10, 20, 30, 40]
+    );
+    assert!(first.downcast_as::<RVec<u32>>().is_err());
+
+    // the value is leaked, so dropping the DynTrait doesn't drop it
+    let temp: StaticDyn = DynTrait::from_static_owned(Counted(RVec::new()));
+    drop(temp);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+}
+
 #[test]
 fn clone_test() {
     let wrapped_expected = Foo::<String>::default().piped(DynTrait::<_, FooInterface>::from_value);
@@ -175,6 +271,56 @@ fn clone_test() {
     }
 }
 
+#[test]
+fn clone_reborrow_vs_owned() {
+    let wrapped = new_wrapped();
+    let address = wrapped.sabi_object_address();
+
+    // cloning a reborrow copies the reference
+    let reborrow = wrapped.reborrow();
+    assert_eq!(reborrow.clone().sabi_object_address(), address);
+
+    // cloning the owned DynTrait clones the value into a new allocation
+    let mut owned = wrapped.clone();
+    assert_ne!(owned.sabi_object_address(), address);
+    assert_eq!(owned, wrapped);
+
+    owned
+        .downcast_as_mut::<Foo<String>>()
+        .unwrap()
+        .name
+        .push_str("_2");
+    assert_ne!(owned, wrapped);
+    assert_eq!(wrapped.downcast_as::<Foo<String>>().unwrap(), &new_foo());
+}
+
+#[test]
+fn reborrow_rarc_test() {
+    fn display_string(to: DynTrait<'_, RRef<'_, ()>, FooInterface>) -> String {
+        to.to_string()
+    }
+
+    let arc = RArc::new(new_foo());
+    let wrapped: DynTrait<'static, RArc<()>, FooInterface> = DynTrait::from_ptr(arc.clone());
+    assert_eq!(RArc::strong_count(&arc), 2);
+
+    // reborrowing doesn't touch the reference count
+    let reborrow = wrapped.reborrow();
+    assert_eq!(RArc::strong_count(&arc), 2);
+    assert_eq!(
+        reborrow.sabi_object_address(),
+        wrapped.sabi_object_address()
+    );
+
+    assert_eq!(display_string(reborrow.clone()), new_foo().to_string());
+    assert_eq!(display_string(wrapped.reborrow()), new_foo().to_string());
+    assert_eq!(RArc::strong_count(&arc), 2);
+
+    drop(reborrow);
+    drop(wrapped);
+    assert_eq!(RArc::strong_count(&arc), 1);
+}
+
 #[test]
 fn default_test() {
     let concrete = Foo::<String>::default();
@@ -200,6 +346,24 @@ fn default_test() {
     }
 }
 
+#[test]
+fn from_default_test() {
+    use crate::{erased_types::interfaces::DebugDefEqInterface, std_types::RVec};
+
+    type Erased = DynTraitBox<DebugDefEqInterface>;
+
+    let wrapped = Erased::from_default::<RVec<u8>>();
+    assert_eq!(
+        wrapped.downcast_as::<RVec<u8>>().unwrap(),
+        &RVec::<u8>::new()
+    );
+    assert_eq!(wrapped, Erased::from_value(RVec::<u8>::new()));
+    assert_ne!(wrapped, Erased::from_value(RVec::from(vec![3u8])));
+
+    let wrapped = DynTraitBox::<FooInterface>::from_default::<Foo<String>>();
+    assert_eq!(wrapped, new_wrapped().default());
+}
+
 #[test]
 fn fmt_test() {
     let concrete = new_foo();
@@ -258,6 +422,20 @@ fn deserialize_test() {
     assert_eq!(wrapped2.downcast_as::<Foo<String>>().unwrap(), &concrete);
 }
 
+#[test]
+fn try_deserialize_test() {
+    let concrete = serde_json::from_str::<Foo<String>>(JSON_0).unwrap();
+
+    let wrapped = VirtualFoo::try_deserialize_from_proxy(JSON_0.into()).unwrap();
+    assert_eq!(wrapped.downcast_as::<Foo<String>>().unwrap(), &concrete);
+
+    for invalid in [r#"{"l":0,"r":"#, "[1, 2, 3]", "", r#"{"l":"hello"}"#] {
+        let (proxy, err) = VirtualFoo::try_deserialize_from_proxy(invalid.into()).unwrap_err();
+        assert_eq!(proxy, invalid);
+        assert!(!err.to_string().is_empty(), "{:?}", err);
+    }
+}
+
 // Unfortunately: miri doesn't like calling `extern fn(*const ErasedType)` that
 // were transmuted from `extern fn(*const ErasedType<T>)`
 #[test]
@@ -385,6 +563,87 @@ fn cmp_test() {
     }
 }
 
+#[test]
+fn cmp_different_interfaces() {
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Ord))]
+    struct OrdInterface;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Debug, Ord))]
+    struct OrdDebugInterface;
+
+    let ord_3: DynTraitBox<OrdInterface> = DynTrait::from_value(3u32);
+    let ord_5: DynTraitBox<OrdInterface> = DynTrait::from_value(5u32);
+    let debug_3: DynTraitBox<OrdDebugInterface> = DynTrait::from_value(3u32);
+    let debug_5 = DynTrait::from_ptr(RArc::new(5u32)).interface(OrdDebugInterface);
+
+    assert!(ord_3 == debug_3);
+    assert!(ord_3 != debug_5);
+    assert!(debug_5 == ord_5);
+
+    assert_eq!(ord_3.partial_cmp(&debug_3), Some(Ordering::Equal));
+    assert_eq!(ord_3.partial_cmp(&debug_5), Some(Ordering::Less));
+    assert_eq!(debug_5.partial_cmp(&ord_3), Some(Ordering::Greater));
+    assert!(ord_3 < debug_5);
+    assert!(debug_5 > ord_3);
+
+    // Wrapping different types, compared by the address of their vtables.
+    let debug_str: DynTraitBox<OrdDebugInterface> = DynTrait::from_value("3");
+    assert!(ord_3 != debug_str);
+    assert_ne!(ord_3.partial_cmp(&debug_str), Some(Ordering::Equal));
+}
+
+#[test]
+fn sabi_cmp_test() {
+    use crate::std_types::{RCmpOrdering, RVec};
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Debug, Ord))]
+    struct OrdInterface;
+
+    type OrdBox = DynTraitBox<OrdInterface>;
+
+    fn sort_homogeneous(list: &mut [OrdBox]) -> Result<(), (usize, usize)> {
+        for i in 1..list.len() {
+            if list[0].sabi_cmp(&list[i]).is_none() {
+                return Err((0, i));
+            }
+        }
+        list.sort_by(|l, r| l.sabi_cmp(r).unwrap().into());
+        Ok(())
+    }
+
+    let three: OrdBox = DynTrait::from_value(3u32);
+    let five: OrdBox = DynTrait::from_value(5u32);
+    let other_three: OrdBox = DynTrait::from_value(3u32);
+
+    assert_eq!(three.sabi_cmp(&five), RSome(RCmpOrdering::Less));
+    assert_eq!(five.sabi_cmp(&three), RSome(RCmpOrdering::Greater));
+    assert_eq!(three.sabi_cmp(&other_three), RSome(RCmpOrdering::Equal));
+
+    let mut list: RVec<OrdBox> = [13u32, 2, 8, 5, 21, 1, 3]
+        .iter()
+        .map(|&x| DynTrait::from_value(x))
+        .collect();
+    sort_homogeneous(&mut list).unwrap();
+    let sorted = list
+        .iter()
+        .map(|x| *x.downcast_as::<u32>().unwrap())
+        .collect::<Vec<u32>>();
+    assert_eq!(sorted, [1, 2, 3, 5, 8, 13, 21]);
+
+    let string: OrdBox = DynTrait::from_value(RString::from("3"));
+    assert_eq!(three.sabi_cmp(&string), RNone);
+    assert_eq!(string.sabi_cmp(&three), RNone);
+
+    list.insert(3, string);
+    assert_eq!(sort_homogeneous(&mut list), Err((0, 3)));
+}
+
 #[test]
 fn hash_test() {
     fn hash_value<H: Hash>(v: &H) -> u64 {
@@ -448,6 +707,155 @@ fn to_any_test() {
     }
 }
 
+#[test]
+fn sabi_is_test() {
+    let mut wrapped = DynTrait::from_value(new_foo()).interface(FooInterface);
+
+    assert!(wrapped.sabi_is::<Foo<String>>());
+    assert!(!wrapped.sabi_is::<Foo<RString>>());
+    assert!(wrapped.clone().sabi_is::<Foo<String>>());
+    assert!(wrapped.reborrow().sabi_is::<Foo<String>>());
+    assert!(!wrapped.reborrow_mut().sabi_is::<Foo<RString>>());
+
+    let list: [DynTraitBox<()>; 3] = [
+        DynTrait::from_value(3u8),
+        DynTrait::from_value(RString::from("hello")),
+        DynTrait::from_value(5u8),
+    ];
+    let bytes = list
+        .iter()
+        .filter(|x| x.sabi_is::<u8>())
+        .map(|x| *x.downcast_as::<u8>().unwrap())
+        .collect::<Vec<u8>>();
+    assert_eq!(bytes, [3, 5]);
+    assert!(list[1].sabi_is::<RString>());
+    assert!(!list[1].sabi_is::<String>());
+
+    let borrowing = DynTrait::from_borrowing_value(0u8).interface(());
+    assert!(!borrowing.sabi_is::<u8>());
+}
+
+#[test]
+fn downcast_with_move_ptr_test() {
+    use crate::sabi_types::MovePtr;
+
+    let wrapped = DynTrait::from_value(new_foo()).interface(FooInterface);
+
+    let wrapped = wrapped
+        .downcast_with_move_ptr(|_: MovePtr<'_, Foo<RString>>| unreachable!())
+        .unwrap_err()
+        .into_inner();
+
+    let mut name_buffer = String::new();
+    let sum = wrapped
+        .downcast_with_move_ptr(|x: MovePtr<'_, Foo<String>>| {
+            let Foo { l, r, name } = MovePtr::into_inner(x);
+            name_buffer = name;
+            l + r
+        })
+        .unwrap();
+    assert_eq!(sum, 1100);
+    assert_eq!(name_buffer, "hello_world");
+
+    let borrowing = DynTrait::from_borrowing_value(0u8).interface(());
+    assert!(borrowing
+        .downcast_with_move_ptr(|_: MovePtr<'_, u8>| ())
+        .is_err());
+}
+
+#[test]
+fn type_name_test() {
+    let foo_name = std::any::type_name::<Foo<String>>();
+
+    let mut wrapped = DynTrait::from_value(new_foo()).interface(FooInterface);
+    assert_eq!(wrapped.sabi_type_name(), foo_name);
+    assert_eq!(wrapped.clone().sabi_type_name(), foo_name);
+    assert_eq!(wrapped.reborrow().sabi_type_name(), foo_name);
+    assert_eq!(wrapped.reborrow_mut().sabi_type_name(), foo_name);
+
+    let borrowing = DynTrait::from_borrowing_value(RVec::<u8>::new()).interface(());
+    assert_eq!(
+        borrowing.sabi_type_name(),
+        std::any::type_name::<RVec<u8>>()
+    );
+
+    let ptr: DynTrait<'_, RRef<'_, ()>, ()> = DynTrait::from_ptr(&0u16);
+    assert_eq!(ptr.sabi_type_name(), std::any::type_name::<u16>());
+}
+
+#[test]
+fn needs_drop_test() {
+    let mut wrapped = DynTrait::from_value(new_foo()).interface(FooInterface);
+    assert!(wrapped.sabi_needs_drop());
+    assert!(wrapped.clone().sabi_needs_drop());
+    assert!(!wrapped.reborrow().sabi_needs_drop());
+    assert!(!wrapped.reborrow_mut().sabi_needs_drop());
+
+    // Copying the reborrow doesn't require dropping either copy.
+    let reborrow = wrapped.reborrow();
+    let copy = reborrow.clone();
+    assert!(!reborrow.sabi_needs_drop());
+    assert!(!copy.sabi_needs_drop());
+
+    let borrowing = DynTrait::from_borrowing_ptr(&0u8).interface(());
+    assert!(!borrowing.sabi_needs_drop());
+
+    // The extra value needs to be dropped
+    let with_string: DynTrait<'_, RRef<'_, ()>, (), RString> =
+        DynTrait::with_extra_value::<_, TD_Opaque>(&0u8, RString::from("hello"));
+    assert!(with_string.sabi_needs_drop());
+
+    let with_u8: DynTrait<'_, RRef<'_, ()>, (), u8> =
+        DynTrait::with_extra_value::<_, TD_Opaque>(&0u8, 3);
+    assert!(!with_u8.sabi_needs_drop());
+}
+
+#[test]
+fn map_extra_value_test() {
+    #[derive(Debug, PartialEq)]
+    struct PluginId {
+        id: u64,
+        name: RString,
+    }
+
+    let arc = RArc::new(new_foo());
+
+    let wrapped: DynTrait<'static, RArc<()>, FooInterface, u64> =
+        DynTrait::with_extra_value::<_, TD_Opaque>(arc.clone(), 8);
+    assert_eq!(RArc::strong_count(&arc), 2);
+
+    let wrapped = wrapped.map_extra_value(|id| PluginId {
+        id,
+        name: RString::from("eight"),
+    });
+    assert_eq!(
+        wrapped.sabi_extra_value(),
+        &PluginId {
+            id: 8,
+            name: RString::from("eight")
+        }
+    );
+    assert_eq!(RArc::strong_count(&arc), 2);
+    assert_eq!(wrapped.to_string(), new_foo().to_string());
+    assert_eq!(
+        unsafe { wrapped.unchecked_downcast_as::<Foo<String>>() },
+        &*arc
+    );
+
+    drop(wrapped);
+    assert_eq!(RArc::strong_count(&arc), 1);
+
+    // The object is dropped if the closure panics
+    let wrapped: DynTrait<'static, RArc<()>, FooInterface, u64> =
+        DynTrait::with_extra_value::<_, TD_Opaque>(arc.clone(), 8);
+    assert_eq!(RArc::strong_count(&arc), 2);
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        wrapped.map_extra_value(|_| -> PluginId { panic!() })
+    }));
+    assert!(res.is_err());
+    assert_eq!(RArc::strong_count(&arc), 1);
+}
+
 //////////////////////////////////////////////////////////////////////
 
 mod borrowing {
@@ -812,6 +1220,39 @@ mod borrowing {
         assert_eq!(extending(RSome(3)), collected(Some(3)));
     }
 
+    #[test]
+    fn iterator_returned_owned() {
+        use crate::erased_types::interfaces::IteratorInterface;
+        use std::sync::Arc;
+
+        type OwnedIter = DynTraitBox<IteratorInterface<Arc<RString>>>;
+
+        fn owned_iter(s: &str) -> OwnedIter {
+            let lines = s
+                .lines()
+                .map(|line| Arc::new(RString::from(line)))
+                .collect::<Vec<_>>();
+            DynTrait::from_value(lines.into_iter()).interface(IteratorInterface::NEW)
+        }
+
+        let mut iter = {
+            let s = "line0\nline1\nline2".to_string();
+            owned_iter(&s)
+        };
+
+        let first = iter.next().unwrap();
+        assert_eq!(&**first, "line0");
+
+        let rest = iter.collect::<Vec<_>>();
+        assert_eq!(rest.len(), 2);
+        assert_eq!(&**rest[0], "line1");
+        assert_eq!(&**rest[1], "line2");
+
+        // the items were moved out of the iterator, which was dropped by `collect`
+        assert_eq!(Arc::strong_count(&first), 1);
+        assert!(rest.iter().all(|x| Arc::strong_count(x) == 1));
+    }
+
     ////////////////
 
     #[test]
@@ -883,6 +1324,40 @@ mod borrowing {
         assert!(!wrapped.sabi_is_same_type(&dbg_wrapped));
     }
 
+    #[test]
+    fn borrowing_partial_eq() {
+        let names = String::from("foobar");
+        let (foo, bar) = names.split_at(3);
+
+        let new =
+            |name| DynTrait::from_borrowing_value(Foo::new(name)).interface(PartialEqInterface);
+
+        let wrapped_foo = new(foo);
+        let wrapped_foo2 = new(&names[..3]);
+        let wrapped_bar = new(bar);
+        let wrapped_str = DynTrait::from_borrowing_value(foo).interface(PartialEqInterface);
+
+        // safety: `Foo<'a>` is covariant over `'a`
+        unsafe {
+            assert!(wrapped_foo.sabi_partial_eq(&wrapped_foo));
+            assert!(wrapped_foo.sabi_partial_eq(&wrapped_foo2));
+            assert!(wrapped_foo2.sabi_partial_eq(&wrapped_foo));
+            assert!(!wrapped_foo.sabi_partial_eq(&wrapped_bar));
+            assert!(!wrapped_bar.sabi_partial_eq(&wrapped_foo2));
+
+            assert!(wrapped_foo
+                .reborrow()
+                .sabi_partial_eq(&wrapped_foo2.reborrow()));
+            assert!(!wrapped_foo
+                .reborrow()
+                .sabi_partial_eq(&wrapped_bar.reborrow()));
+
+            // Different types are never equal
+            assert!(!wrapped_foo.sabi_partial_eq(&wrapped_str));
+            assert!(!wrapped_str.sabi_partial_eq(&wrapped_foo));
+        }
+    }
+
     #[test]
     fn unerase_should_not_work() {
         let value: &str = "hello";
@@ -1008,6 +1483,23 @@ mod borrowing {
         assert_eq!(wrapped.read_exact(&mut out[8..]).map_err(drop), Err(()));
     }
 
+    #[test]
+    fn io_copy_trait_objects() {
+        use std::io::{self, Cursor};
+
+        let mut source = Cursor::new((0..=255u8).cycle().take(10_000).collect::<Vec<u8>>());
+        let mut dest = Vec::<u8>::new();
+        {
+            let mut reader = DynTrait::from_ptr(&mut source).interface(IoInterface);
+            let mut writer = DynTrait::from_ptr(&mut dest)
+                .interface(crate::erased_types::interfaces::IoWriteInterface);
+
+            let copied = io::copy(reader.as_io_read(), writer.as_io_write()).unwrap();
+            assert_eq!(copied, 10_000);
+        }
+        assert_eq!(&dest, source.get_ref());
+    }
+
     #[repr(C)]
     #[derive(StableAbi)]
     #[sabi(impl_InterfaceType(Send, Sync, IoRead, IoBufRead))]
@@ -1082,4 +1574,58 @@ mod borrowing {
             assert_eq!(&out[..8], &[1, 2, 3, 4, 8, 9, 10, 7][..]);
         }
     }
+
+    #[test]
+    fn write_seek_interface_test() {
+        use crate::erased_types::interfaces::WriteSeekInterface;
+
+        type GI = GetImpls<DynTraitBox<WriteSeekInterface>>;
+        assert!(!GI::IMPLS_SEND);
+        assert!(!GI::IMPLS_SYNC);
+        assert!(!GI::IMPLS_UNPIN);
+        assert!(!GI::IMPLS_CLONE);
+        assert!(!GI::IMPLS_DISPLAY);
+        assert!(!GI::IMPLS_DEBUG);
+        assert!(!GI::IMPLS_SERIALIZE);
+        assert!(!GI::IMPLS_EQ);
+        assert!(!GI::IMPLS_PARTIAL_EQ);
+        assert!(!GI::IMPLS_ORD);
+        assert!(!GI::IMPLS_PARTIAL_ORD);
+        assert!(!GI::IMPLS_HASH);
+        assert!(!GI::IMPLS_DESERIALIZE);
+        assert!(!GI::IMPLS_ITERATOR);
+        assert!(!GI::IMPLS_DOUBLE_ENDED_ITERATOR);
+        assert!(!GI::IMPLS_FMT_WRITE);
+        assert!(GI::IMPLS_IO_WRITE);
+        assert!(GI::IMPLS_IO_SEEK);
+        assert!(!GI::IMPLS_IO_READ);
+        assert!(!GI::IMPLS_IO_BUF_READ);
+        assert!(!GI::IMPLS_ERROR);
+    }
+
+    #[test]
+    fn io_write_seek() {
+        use crate::erased_types::interfaces::WriteSeekInterface;
+        use std::io::{Cursor, Seek, SeekFrom, Write};
+
+        let mut buff = Cursor::new(Vec::<u8>::new());
+        {
+            let mut wrapped = DynTrait::from_ptr(&mut buff).interface(WriteSeekInterface);
+
+            wrapped.write_all(&[0, 1, 2, 3, 4, 5]).unwrap();
+            // `Seek::stream_position` works through the `Seek` impl
+            assert_eq!(wrapped.stream_position().unwrap(), 6);
+
+            wrapped.seek(SeekFrom::Current(-4)).unwrap();
+            assert_eq!(wrapped.stream_position().unwrap(), 2);
+
+            wrapped.write_all(&[20, 30]).unwrap();
+            assert_eq!(wrapped.stream_position().unwrap(), 4);
+
+            wrapped.seek(SeekFrom::End(0)).unwrap();
+            wrapped.write_all(&[6]).unwrap();
+            assert_eq!(wrapped.stream_position().unwrap(), 7);
+        }
+        assert_eq!(&**buff.get_ref(), &[0, 1, 20, 30, 4, 5, 6][..]);
+    }
 }
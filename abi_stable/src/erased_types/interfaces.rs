@@ -1,6 +1,40 @@
 use super::*;
 use std::marker::PhantomData;
 
+/// Implements `InterfaceType`, requiring the traits that either `A` or `B` require.
+///
+/// The [`SerializeProxyType`] and [`IteratorItem`] impls delegate to `A`
+/// if it requires `Serialize`/`Iterator` respectively, otherwise they delegate to `B`.
+///
+/// # Example
+///
+/// ```rust
+/// use abi_stable::{
+///     erased_types::interfaces::{CloneInterface, CombineInterfaces, DebugDisplayInterface},
+///     std_types::RBox,
+///     DynTrait,
+/// };
+///
+/// type Interface = CombineInterfaces<DebugDisplayInterface, CloneInterface>;
+///
+/// let object: DynTrait<'static, RBox<()>, Interface> = DynTrait::from_value(3u8);
+/// let clone = object.clone();
+///
+/// assert_eq!(format!("{:?}", object), "3");
+/// assert_eq!(format!("{}", clone), "3");
+///
+/// ```
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct CombineInterfaces<A, B>(PhantomData<A>, PhantomData<B>);
+
+impl<A, B> CombineInterfaces<A, B> {
+    /// Constructs a `CombineInterfaces`.
+    pub const NEW: Self = Self(PhantomData, PhantomData);
+}
+
+//////////////////////////////////////////////
+
 /// Implements `InterfaceType`, requiring `Send + Sync + Clone`
 #[repr(C)]
 #[derive(StableAbi)]
@@ -82,6 +116,14 @@ pub struct IoWriteInterface;
 
 //////////////////////////////////////////////
 
+/// Implements `InterfaceType`, requiring `std::io::Write + std::io::Seek`
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(IoWrite, IoSeek))]
+pub struct WriteSeekInterface;
+
+//////////////////////////////////////////////
+
 /// Implements `InterfaceType`, requiring `Send + Sync + Debug + Display`
 #[repr(C)]
 #[derive(StableAbi)]
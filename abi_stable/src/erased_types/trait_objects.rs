@@ -12,7 +12,7 @@ use super::{c_functions::*, *};
 
 use crate::{
     marker_type::ErasedObject,
-    pointer_trait::{AsPtr, TransmuteElement},
+    pointer_trait::{AsMutPtr, AsPtr, TransmuteElement},
     sabi_types::{RMut, RRef},
     std_types::RBox,
 };
@@ -55,24 +55,25 @@ impl<'a> HasherObject<'a> {
 }
 
 macro_rules! impl_write {
-    ( $(($ty:ty, $fn:ident)),* ) => {
+    ( $to_rmut:ident; $(($ty:ty, $fn:ident)),* ) => {
+        fn finish(&self) -> u64 {
+            unsafe { (self.finish)(self.this.as_rref()) }
+        }
+        fn write(&mut self, bytes: &[u8]) {
+            unsafe { (self.write_fns.write)(self.this.$to_rmut(), bytes.into()) }
+        }
+
         $(
             fn $fn(&mut self, val: $ty) {
-                unsafe { (self.write_fns.$fn)(self.this.reborrow(), val) }
+                unsafe { (self.write_fns.$fn)(self.this.$to_rmut(), val) }
             }
         )*
     }
 }
 
 impl<'a> Hasher for HasherObject<'a> {
-    fn finish(&self) -> u64 {
-        unsafe { (self.finish)(self.this.as_rref()) }
-    }
-    fn write(&mut self, bytes: &[u8]) {
-        unsafe { (self.write_fns.write)(self.this.reborrow(), bytes.into()) }
-    }
-
     impl_write!(
+        reborrow;
         (i16, write_i16),
         (i32, write_i32),
         (i64, write_i64),
@@ -133,6 +134,107 @@ impl<T: Hasher> MakeWriteFns<T> {
 
 //////////////
 
+/// An ffi-safe equivalent of `Box<dyn std::hash::Hasher>`.
+///
+/// This allows passing an owned hasher across dynamic libraries,
+/// with all of its methods being called in the library that constructed it.
+///
+/// `RHasher` is `Send + Sync`,
+/// so it can only be constructed from hashers that are `Send + Sync`.
+///
+/// # Example
+///
+/// ```rust
+/// use abi_stable::erased_types::trait_objects::RHasher;
+///
+/// use std::{
+///     collections::hash_map::DefaultHasher,
+///     hash::{Hash, Hasher},
+/// };
+///
+/// let mut hasher = RHasher::new(DefaultHasher::new());
+/// "hello".hash(&mut hasher);
+///
+/// let mut expected = DefaultHasher::new();
+/// "hello".hash(&mut expected);
+///
+/// assert_eq!(hasher.finish(), expected.finish());
+///
+/// ```
+///
+/// Hashers that aren't thread-safe can't be wrapped:
+///
+/// ```compile_fail
+/// use abi_stable::erased_types::trait_objects::RHasher;
+///
+/// use std::{cell::Cell, hash::Hasher, rc::Rc};
+///
+/// struct RcHasher(Rc<Cell<u64>>);
+///
+/// impl Hasher for RcHasher {
+///     fn finish(&self) -> u64 {
+///         self.0.get()
+///     }
+///     fn write(&mut self, bytes: &[u8]) {
+///         self.0.set(self.0.get() + bytes.len() as u64);
+///     }
+/// }
+///
+/// let _ = RHasher::new(RcHasher(Rc::new(Cell::new(0))));
+///
+/// ```
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RHasher {
+    this: RBox<ErasedObject>,
+    write_fns: &'static WriteFns,
+    finish: unsafe extern "C" fn(RRef<'_, ErasedObject>) -> u64,
+}
+
+impl RHasher {
+    /// Constructs an `RHasher` from any thread-safe `Hasher`.
+    pub fn new<T>(hasher: T) -> RHasher
+    where
+        T: Hasher + Send + Sync + 'static,
+    {
+        RHasher {
+            this: unsafe {
+                // The lifetime here is 'static,so it's fine to erase the type.
+                RBox::new(hasher).transmute_element::<ErasedObject>()
+            },
+            write_fns: MakeWriteFns::<T>::V,
+            finish: finish_Hasher::<T>,
+        }
+    }
+
+    /// Borrows this `RHasher` as a `HasherObject`.
+    pub fn as_hasher_object(&mut self) -> HasherObject<'_> {
+        HasherObject {
+            this: self.this.as_rmut(),
+            write_fns: self.write_fns,
+            finish: self.finish,
+        }
+    }
+}
+
+impl Hasher for RHasher {
+    impl_write!(
+        as_rmut;
+        (i16, write_i16),
+        (i32, write_i32),
+        (i64, write_i64),
+        (i8, write_i8),
+        (isize, write_isize),
+        (u16, write_u16),
+        (u32, write_u32),
+        (u64, write_u64),
+        (u8, write_u8),
+        (usize, write_usize)
+    );
+}
+
+//////////////
+
 /// An ffi-safe equivalent of `Box<dyn Debug + Display>`
 /// (if `dyn Debug + Display` was possible).
 #[repr(C)]
@@ -200,3 +302,39 @@ impl Debug for NoFmt {
 }
 
 //////////////
+
+//////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+
+    /// Hashes the same values with `hasher` and a `DefaultHasher`.
+    fn hash_values<H: Hasher>(hasher: &mut H) {
+        hasher.write(b"hello world");
+        hasher.write_u8(3);
+        hasher.write_u64(5);
+        hasher.write_isize(-8);
+        "foo".hash(hasher);
+    }
+
+    #[test]
+    fn erased_hasher() {
+        let mut expected = DefaultHasher::new();
+        hash_values(&mut expected);
+
+        let mut hasher = RHasher::new(DefaultHasher::new());
+        hash_values(&mut hasher);
+        assert_eq!(hasher.finish(), expected.finish());
+
+        let mut hasher = RHasher::new(DefaultHasher::new());
+        hash_values(&mut hasher.as_hasher_object());
+        assert_eq!(hasher.finish(), expected.finish());
+
+        let mut inner = DefaultHasher::new();
+        hash_values(&mut HasherObject::new(&mut inner));
+        assert_eq!(inner.finish(), expected.finish());
+    }
+}
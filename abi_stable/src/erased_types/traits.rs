@@ -1,11 +1,11 @@
 //! Traits for types wrapped in `DynTrait<_>`
 
-use crate::std_types::RBoxError;
+use crate::{erased_types::interfaces::CombineInterfaces, std_types::RBoxError};
 
 #[allow(unused_imports)]
 use crate::type_level::{
     bools::{False, True},
-    impl_enum::{Implementability, Implemented, Unimplemented},
+    impl_enum::{ImplOr, ImplOr_, Implementability, Implemented, Unimplemented},
     trait_marker,
 };
 
@@ -32,6 +32,25 @@ macro_rules! declare_InterfaceType {
             type define_this_in_the_impl_InterfaceType_macro;
         }
 
+        // Implemented here so that it can't get out of sync with
+        // the associated types of `InterfaceType`.
+        impl<A, B> InterfaceType for CombineInterfaces<A, B>
+        where
+            A: InterfaceType,
+            B: InterfaceType,
+            $(
+                <A as InterfaceType>::$trait_: ImplOr_<<B as InterfaceType>::$trait_>,
+            )*
+        {
+            $(
+                type $trait_ = ImplOr<
+                    <A as InterfaceType>::$trait_,
+                    <B as InterfaceType>::$trait_,
+                >;
+            )*
+
+            type define_this_in_the_impl_InterfaceType_macro = ();
+        }
 
     )
 }
@@ -316,6 +335,37 @@ where
     type ProxyType = ();
 }
 
+/// Uses the proxy type of `A` if it requires `Serialize`, otherwise the one of `B`.
+impl<'borr, A, B> SerializeProxyType<'borr> for CombineInterfaces<A, B>
+where
+    Self: InterfaceType,
+    A: InterfaceType,
+    <A as InterfaceType>::Serialize: CombinedSerializeProxyHelper<'borr, A, B>,
+{
+    type Proxy =
+        <<A as InterfaceType>::Serialize as CombinedSerializeProxyHelper<'borr, A, B>>::Proxy;
+}
+
+#[doc(hidden)]
+pub trait CombinedSerializeProxyHelper<'borr, A, B> {
+    type Proxy: 'borr;
+}
+
+impl<'borr, A, B> CombinedSerializeProxyHelper<'borr, A, B> for Implemented<trait_marker::Serialize>
+where
+    A: SerializeProxyType<'borr>,
+{
+    type Proxy = <A as SerializeProxyType<'borr>>::Proxy;
+}
+
+impl<'borr, A, B> CombinedSerializeProxyHelper<'borr, A, B>
+    for Unimplemented<trait_marker::Serialize>
+where
+    B: SerializeProxyType<'borr>,
+{
+    type Proxy = <B as SerializeProxyType<'borr>>::Proxy;
+}
+
 ///////////////////////////////////////
 
 /// Describes how `D` is deserialized, using a proxy to do so.
@@ -426,6 +476,35 @@ impl<'borr, I> IteratorItemOrDefaultHelper<'borr, Unimplemented<trait_marker::It
     type Item = ();
 }
 
+/// Uses the item type of `A` if it requires `Iterator`, otherwise the one of `B`.
+impl<'borr, A, B> IteratorItem<'borr> for CombineInterfaces<A, B>
+where
+    Self: InterfaceType,
+    A: InterfaceType,
+    <A as InterfaceType>::Iterator: CombinedIteratorItemHelper<'borr, A, B>,
+{
+    type Item = <<A as InterfaceType>::Iterator as CombinedIteratorItemHelper<'borr, A, B>>::Item;
+}
+
+#[doc(hidden)]
+pub trait CombinedIteratorItemHelper<'borr, A, B> {
+    type Item;
+}
+
+impl<'borr, A, B> CombinedIteratorItemHelper<'borr, A, B> for Implemented<trait_marker::Iterator>
+where
+    A: IteratorItem<'borr>,
+{
+    type Item = <A as IteratorItem<'borr>>::Item;
+}
+
+impl<'borr, A, B> CombinedIteratorItemHelper<'borr, A, B> for Unimplemented<trait_marker::Iterator>
+where
+    B: IteratorItem<'borr>,
+{
+    type Item = <B as IteratorItem<'borr>>::Item;
+}
+
 /////////////////////////////////////////////////////////////////////
 
 crate::impl_InterfaceType! {
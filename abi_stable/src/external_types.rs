@@ -3,6 +3,10 @@
 //! The modules here are named after the crates whose types are being wrapped.
 //!
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "bytes")))]
+pub mod bytes;
+
 #[cfg(feature = "crossbeam-channel")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "channels")))]
 pub mod crossbeam_channel;
@@ -13,7 +17,24 @@ pub mod parking_lot;
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "serde_json")))]
 pub mod serde_json;
 
+#[cfg(feature = "smallvec")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "smallvec")))]
+pub mod smallvec;
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "uuid")))]
+pub mod uuid;
+
 pub use self::parking_lot::{RMutex, ROnce, RRwLock};
 
+#[cfg(feature = "bytes")]
+pub use self::bytes::RBytes;
+
 #[cfg(feature = "serde_json")]
 pub use self::serde_json::{RawValueBox, RawValueRef};
+
+#[cfg(feature = "smallvec")]
+pub use self::smallvec::RSmallVec;
+
+#[cfg(feature = "uuid")]
+pub use self::uuid::RUuid;
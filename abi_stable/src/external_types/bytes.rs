@@ -0,0 +1,510 @@
+//! Ffi-safe equivalent of `bytes::Bytes`.
+
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    ops::{Bound, Deref, RangeBounds},
+};
+
+use bytes::Bytes;
+
+use crate::std_types::{RArc, RSlice, RVec};
+
+/// Ffi-safe equivalent of `bytes::Bytes`,
+/// a cheaply clonable and sliceable reference-counted byte buffer.
+///
+/// Cloning and slicing an `RBytes` never copies the bytes,
+/// it only increments the reference count of the buffer,
+/// which (like with [`RArc`]) is always manipulated in the
+/// dynamic library/binary that constructed the `RBytes`.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::external_types::RBytes;
+///
+/// let bytes = RBytes::copy_from_slice(b"hello world");
+///
+/// let hello = bytes.slice(..5);
+/// let world = bytes.slice(6..);
+///
+/// assert_eq!(hello, b"hello"[..]);
+/// assert_eq!(world, b"world"[..]);
+///
+/// // All the `RBytes` share the same buffer.
+/// assert!(RBytes::shares_buffer(&hello, &world));
+/// assert_eq!(world.as_ptr(), bytes[6..].as_ptr());
+///
+/// ```
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RBytes {
+    buffer: RArc<RVec<u8>>,
+    start: usize,
+    len: usize,
+}
+
+impl RBytes {
+    /// Constructs an empty `RBytes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// let bytes = RBytes::new();
+    ///
+    /// assert!(bytes.is_empty());
+    ///
+    /// ```
+    pub fn new() -> Self {
+        Self::from_rvec(RVec::new())
+    }
+
+    /// Constructs an `RBytes` by copying the bytes of `slice`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// let bytes = RBytes::copy_from_slice(&[3, 5, 8]);
+    ///
+    /// assert_eq!(bytes.as_slice(), &[3, 5, 8]);
+    ///
+    /// ```
+    pub fn copy_from_slice(slice: &[u8]) -> Self {
+        Self::from_rvec(RVec::from_slice(slice))
+    }
+
+    /// Constructs an `RBytes` from the bytes of `vec`, without copying them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{external_types::RBytes, rvec};
+    ///
+    /// let vec = rvec![3, 5, 8];
+    /// let ptr = vec.as_ptr();
+    ///
+    /// let bytes = RBytes::from_rvec(vec);
+    ///
+    /// assert_eq!(bytes.as_ptr(), ptr);
+    /// assert_eq!(bytes.as_slice(), &[3, 5, 8]);
+    ///
+    /// ```
+    pub fn from_rvec(vec: RVec<u8>) -> Self {
+        Self {
+            len: vec.len(),
+            start: 0,
+            buffer: RArc::new(vec),
+        }
+    }
+
+    /// Gets the bytes this points to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// let bytes = RBytes::copy_from_slice(b"foo");
+    ///
+    /// assert_eq!(bytes.as_slice(), b"foo");
+    ///
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buffer[self.start..self.start + self.len]
+    }
+
+    /// Gets the bytes this points to, as an `RSlice`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{external_types::RBytes, std_types::RSlice};
+    ///
+    /// let bytes = RBytes::copy_from_slice(b"foo");
+    ///
+    /// assert_eq!(bytes.as_rslice(), RSlice::from_slice(b"foo"));
+    ///
+    /// ```
+    #[inline]
+    pub fn as_rslice(&self) -> RSlice<'_, u8> {
+        self.as_slice().into()
+    }
+
+    /// Returns the amount of bytes this points to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// assert_eq!(RBytes::new().len(), 0);
+    /// assert_eq!(RBytes::copy_from_slice(b"foo").len(), 3);
+    /// assert_eq!(RBytes::copy_from_slice(b"foo").slice(1..).len(), 2);
+    ///
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this points to no bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// assert!(RBytes::new().is_empty());
+    /// assert!(RBytes::copy_from_slice(b"foo").slice(3..).is_empty());
+    /// assert!(!RBytes::copy_from_slice(b"foo").is_empty());
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an `RBytes` pointing to the `range` range of bytes in this one,
+    /// sharing the same buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if its start is greater than its end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// let bytes = RBytes::copy_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(bytes.slice(..), [0, 1, 2, 3, 4, 5][..]);
+    /// assert_eq!(bytes.slice(..2), [0, 1][..]);
+    /// assert_eq!(bytes.slice(2..), [2, 3, 4, 5][..]);
+    /// assert_eq!(bytes.slice(2..5).slice(1..=2), [3, 4][..]);
+    ///
+    /// ```
+    pub fn slice<R>(&self, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Unbounded => 0,
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.saturating_add(1),
+        };
+        let end = match range.end_bound() {
+            Bound::Unbounded => self.len,
+            Bound::Included(&n) => n.saturating_add(1),
+            Bound::Excluded(&n) => n,
+        };
+        // Panics with the same messages as slicing does for invalid ranges.
+        let _ = &self.as_slice()[start..end];
+
+        Self {
+            buffer: self.buffer.clone(),
+            start: self.start + start,
+            len: end - start,
+        }
+    }
+
+    /// Whether both `RBytes` point into the same buffer,
+    /// regardless of which range of bytes each one points to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// let bytes = RBytes::copy_from_slice(b"hello");
+    ///
+    /// assert!(RBytes::shares_buffer(&bytes, &bytes.slice(1..)));
+    /// assert!(!RBytes::shares_buffer(&bytes, &RBytes::copy_from_slice(b"hello")));
+    ///
+    /// ```
+    pub fn shares_buffer(this: &Self, other: &Self) -> bool {
+        std::ptr::eq(&*this.buffer, &*other.buffer)
+    }
+
+    /// Converts this `RBytes` into an `RVec<u8>`,
+    /// only copying the bytes if the buffer is shared,
+    /// or if this doesn't point to the whole buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{external_types::RBytes, rvec};
+    ///
+    /// let bytes = RBytes::from_rvec(rvec![3, 5, 8]);
+    /// let ptr = bytes.as_ptr();
+    ///
+    /// let vec = bytes.into_rvec();
+    /// assert_eq!(vec, [3, 5, 8]);
+    /// assert_eq!(vec.as_ptr(), ptr);
+    ///
+    /// ```
+    pub fn into_rvec(self) -> RVec<u8> {
+        if self.start == 0 && self.len == self.buffer.len() {
+            match RArc::try_unwrap(self.buffer) {
+                Ok(vec) => vec,
+                Err(buffer) => RVec::from_slice(&buffer),
+            }
+        } else {
+            RVec::from_slice(self.as_slice())
+        }
+    }
+
+    /// Converts this `RBytes` into a `bytes::Bytes`,
+    /// only copying the bytes if the buffer is shared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RBytes;
+    ///
+    /// use bytes::Bytes;
+    ///
+    /// let bytes: Bytes = RBytes::copy_from_slice(b"hello").into_bytes();
+    ///
+    /// assert_eq!(bytes, b"hello"[..]);
+    ///
+    /// ```
+    pub fn into_bytes(self) -> Bytes {
+        let Self { buffer, start, len } = self;
+        match RArc::try_unwrap(buffer) {
+            Ok(vec) => Bytes::from(vec.into_vec()).slice(start..start + len),
+            Err(buffer) => Bytes::copy_from_slice(&buffer[start..start + len]),
+        }
+    }
+}
+
+impl Clone for RBytes {
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer.clone(),
+            start: self.start,
+            len: self.len,
+        }
+    }
+}
+
+impl Deref for RBytes {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for RBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Borrow<[u8]> for RBytes {
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Default for RBytes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<RVec<u8>> for RBytes {
+    fn from(vec: RVec<u8>) -> Self {
+        Self::from_rvec(vec)
+    }
+}
+
+impl From<Vec<u8>> for RBytes {
+    fn from(vec: Vec<u8>) -> Self {
+        Self::from_rvec(vec.into())
+    }
+}
+
+impl From<&[u8]> for RBytes {
+    fn from(slice: &[u8]) -> Self {
+        Self::copy_from_slice(slice)
+    }
+}
+
+impl From<Bytes> for RBytes {
+    /// Converts the `Bytes` into an `RBytes`,
+    /// only copying the bytes if `Bytes` can't be converted to a `Vec<u8>` without copying.
+    fn from(bytes: Bytes) -> Self {
+        Self::from(Vec::<u8>::from(bytes))
+    }
+}
+
+impl From<RBytes> for Bytes {
+    fn from(bytes: RBytes) -> Self {
+        bytes.into_bytes()
+    }
+}
+
+impl From<RBytes> for RVec<u8> {
+    fn from(bytes: RBytes) -> Self {
+        bytes.into_rvec()
+    }
+}
+
+impl Debug for RBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl Eq for RBytes {}
+
+impl PartialEq for RBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl PartialEq<[u8]> for RBytes {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl PartialEq<Bytes> for RBytes {
+    fn eq(&self, other: &Bytes) -> bool {
+        self.as_slice() == other.as_ref()
+    }
+}
+
+impl Ord for RBytes {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl PartialOrd for RBytes {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for RBytes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    use abi_stable_shared::test_utils::must_panic;
+
+    #[test]
+    fn clone_shares_buffer() {
+        let bytes = RBytes::copy_from_slice(b"hello world");
+        assert_eq!(RArc::strong_count(&bytes.buffer), 1);
+
+        let clone = bytes.clone();
+        assert!(RBytes::shares_buffer(&bytes, &clone));
+        assert_eq!(bytes.as_ptr(), clone.as_ptr());
+        assert_eq!(RArc::strong_count(&bytes.buffer), 2);
+
+        drop(bytes);
+        assert_eq!(RArc::strong_count(&clone.buffer), 1);
+        assert_eq!(clone, b"hello world"[..]);
+    }
+
+    #[test]
+    fn slice_shares_buffer() {
+        let bytes = RBytes::copy_from_slice(b"hello world");
+
+        let hello = bytes.slice(..5);
+        let world = bytes.slice(6..);
+        let orl = world.slice(1..=3);
+
+        assert_eq!(hello, b"hello"[..]);
+        assert_eq!(world, b"world"[..]);
+        assert_eq!(orl, b"orl"[..]);
+        assert_eq!(hello.len(), 5);
+        assert_eq!(orl.len(), 3);
+
+        assert_eq!(hello.as_ptr(), bytes.as_ptr());
+        assert_eq!(world.as_ptr(), bytes[6..].as_ptr());
+        assert_eq!(orl.as_ptr(), bytes[7..].as_ptr());
+        assert!(RBytes::shares_buffer(&bytes, &orl));
+        assert_eq!(RArc::strong_count(&bytes.buffer), 4);
+
+        assert!(bytes.slice(11..).is_empty());
+        assert!(world.slice(2..2).is_empty());
+    }
+
+    #[test]
+    fn slice_out_of_bounds() {
+        let bytes = RBytes::copy_from_slice(b"hello world");
+        let world = bytes.slice(6..);
+
+        must_panic(|| world.slice(..6)).unwrap();
+        must_panic(|| world.slice(6..)).unwrap();
+        #[allow(clippy::reversed_empty_ranges)]
+        must_panic(|| world.slice(3..2)).unwrap();
+        world.slice(5..);
+    }
+
+    #[test]
+    fn conversions() {
+        {
+            let vec = RVec::from(b"hello".to_vec());
+            let ptr = vec.as_ptr();
+            let bytes = RBytes::from(vec);
+            assert_eq!(bytes.into_rvec().as_ptr(), ptr);
+        }
+        {
+            let bytes = RBytes::copy_from_slice(b"hello");
+            let _clone = bytes.clone();
+            assert_eq!(bytes.into_rvec(), b"hello"[..]);
+        }
+        {
+            let bytes = RBytes::copy_from_slice(b"hello").slice(1..4);
+            assert_eq!(RVec::from(bytes), b"ell"[..]);
+        }
+        {
+            let bytes = RBytes::copy_from_slice(b"hello world").slice(6..);
+            let ptr = bytes.as_ptr();
+            let bytes = Bytes::from(bytes);
+            assert_eq!(bytes, b"world"[..]);
+            assert_eq!(bytes.as_ptr(), ptr);
+
+            let rbytes = RBytes::from(bytes);
+            assert_eq!(rbytes, Bytes::from_static(b"world"));
+        }
+        {
+            let bytes = RBytes::copy_from_slice(b"hello");
+            let clone = bytes.clone();
+            assert_eq!(bytes.into_bytes(), b"hello"[..]);
+            assert_eq!(clone, b"hello"[..]);
+        }
+    }
+
+    #[test]
+    fn comparison() {
+        let bytes = RBytes::copy_from_slice(&[3, 5, 8]);
+
+        assert_eq!(bytes, RBytes::copy_from_slice(&[0, 3, 5, 8]).slice(1..));
+        assert_ne!(bytes, RBytes::copy_from_slice(&[3, 5]));
+        assert!(bytes < RBytes::copy_from_slice(&[3, 6]));
+        assert_eq!(format!("{:?}", bytes), "[3, 5, 8]");
+    }
+}
@@ -366,6 +366,18 @@ mod tests {
         assert_eq!(*mutex.lock(), 300);
     }
 
+    #[test]
+    fn try_lock_while_held() {
+        let mutex: RMutex<usize> = RMutex::new(0);
+        {
+            let mut guard = mutex.lock();
+            *guard += 1;
+            assert_eq!(mutex.try_lock().map(drop), RNone);
+        }
+        *mutex.try_lock().unwrap() += 1;
+        assert_eq!(*mutex.lock(), 2);
+    }
+
     #[test]
     fn into_inner() {
         let mutex: RMutex<usize> = RMutex::new(0);
@@ -135,6 +135,34 @@ impl ROnce {
         unsafe { self.vtable().state()(&self.opaque_once) }
     }
 
+    /// Whether an initialization function passed to `call_once*` has run to completion,
+    /// equivalent to `self.state().done()`.
+    ///
+    /// This returns `false` if the ROnce is poisoned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::ROnce;
+    ///
+    /// use std::panic::AssertUnwindSafe;
+    ///
+    /// let once = ROnce::new();
+    /// assert!(!once.is_completed());
+    ///
+    /// let _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     once.call_once(|| panic!());
+    /// }));
+    /// assert!(!once.is_completed());
+    ///
+    /// once.call_once_force(|state| assert!(state.poisoned()));
+    /// assert!(once.is_completed());
+    ///
+    /// ```
+    pub fn is_completed(&self) -> bool {
+        self.state().done()
+    }
+
     /// Runs an initialization function.
     ///
     /// `f` will be run only if this is the first time this method has been called
@@ -582,7 +610,9 @@ mod tests {
         {
             let once = ROnce::new();
             let mut a = 0;
+            assert!(!once.is_completed());
             once.call_once_force(|_| a += 1);
+            assert!(once.is_completed());
             once.call_once_force(|_| a += 2);
             assert_eq!(a, 1);
         }
@@ -596,10 +626,12 @@ mod tests {
                 });
             })
             .unwrap();
+            assert!(!once.is_completed());
             once.call_once_force(|state| {
                 assert_eq!(state, ROnceState::Poisoned);
                 *a += 2;
             });
+            assert!(once.is_completed());
             once.call_once_force(|_| *a += 4);
             once.call_once_force(|_| panic!());
             assert_eq!(*a, 2);
@@ -545,6 +545,24 @@ mod tests {
         assert_eq!(*lock.read(), 200);
     }
 
+    #[test]
+    fn try_lock_while_held() {
+        let lock: RRwLock<usize> = RRwLock::new(0);
+        {
+            let mut guard = lock.write();
+            *guard += 1;
+            assert_eq!(lock.try_read().map(drop), RNone);
+            assert_eq!(lock.try_write().map(drop), RNone);
+        }
+        {
+            let _guard = lock.read();
+            assert_eq!(*lock.try_read().unwrap(), 1);
+            assert_eq!(lock.try_write().map(drop), RNone);
+        }
+        *lock.try_write().unwrap() += 1;
+        assert_eq!(*lock.read(), 2);
+    }
+
     #[test]
     fn into_inner() {
         let lock: RRwLock<usize> = RRwLock::new(0);
@@ -8,7 +8,7 @@ use std::{
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{error::Error as JsonError, value::RawValue};
 
-use crate::std_types::{RStr, RString};
+use crate::std_types::{RBoxError, RErr, ROk, RResult, RStr, RString};
 
 /// An ffi-safe equivalent of `&serde_json::value::RawValue`
 ///
@@ -191,6 +191,26 @@ impl<'a> RawValueRef<'a> {
     pub const fn get_rstr(&self) -> RStr<'a> {
         self.ref_
     }
+
+    /// Copies the json into a `RawValueBox`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::{RawValueBox, RawValueRef};
+    ///
+    /// const JSON: &'static str = r##"{"bugs":"life"}"##;
+    ///
+    /// let raw = RawValueRef::try_from_str(JSON).unwrap();
+    /// let owned: RawValueBox = raw.to_owned();
+    ///
+    /// assert_eq!(owned.get(), JSON);
+    ///
+    /// ```
+    #[inline]
+    pub fn to_owned(&self) -> RawValueBox {
+        unsafe { RawValueBox::from_rstring_unchecked(self.get().into()) }
+    }
 }
 
 impl<'a> Debug for RawValueRef<'a> {
@@ -382,6 +402,43 @@ impl RawValueBox {
         input.try_into()
     }
 
+    /// Serializes `value` into a `RawValueBox`.
+    ///
+    /// Returns an error in the same cases as `serde_json::to_string` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RawValueBox;
+    ///
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Pair {
+    ///     first: u32,
+    ///     second: &'static str,
+    /// }
+    ///
+    /// let pair = Pair {
+    ///     first: 3,
+    ///     second: "hello",
+    /// };
+    ///
+    /// let raw = RawValueBox::from_serialize(&pair).unwrap();
+    ///
+    /// assert_eq!(raw.get(), r##"{"first":3,"second":"hello"}"##);
+    ///
+    /// ```
+    pub fn from_serialize<T>(value: &T) -> RResult<Self, RBoxError>
+    where
+        T: ?Sized + Serialize,
+    {
+        match serde_json::value::to_raw_value(value) {
+            Ok(x) => ROk(Self::from(x)),
+            Err(e) => RErr(RBoxError::new(e)),
+        }
+    }
+
     /// Gets the json being serialized,as a `&str`.
     ///
     /// # Example
@@ -499,3 +556,47 @@ const unsafe fn into_ref_rawvalue(x: &str) -> &RawValue {
     // serde_json somehow changes RawValue to not be a transparent wrapper around `str`
     unsafe { &*(x as *const str as *const RawValue) }
 }
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+        name: String,
+    }
+
+    #[test]
+    fn from_serialize_roundtrip() {
+        let point = Point {
+            x: 3,
+            y: -5,
+            name: "origin\"ish\"".into(),
+        };
+
+        let raw = RawValueBox::from_serialize(&point).unwrap();
+        assert_eq!(raw.get(), serde_json::to_string(&point).unwrap());
+        assert_eq!(serde_json::from_str::<Point>(raw.get()).unwrap(), point);
+
+        let reser = serde_json::to_string(&raw).unwrap();
+        assert_eq!(reser, raw.get());
+
+        let owned = raw.as_raw_value_ref().to_owned();
+        assert_eq!(owned.get(), raw.get());
+        assert_eq!(serde_json::from_str::<Point>(owned.get()).unwrap(), point);
+    }
+
+    #[test]
+    fn from_serialize_error() {
+        // json maps can't have non-string keys
+        let map = vec![((0, 1), 2)].into_iter().collect::<BTreeMap<_, _>>();
+        let err = RawValueBox::from_serialize(&map).unwrap_err();
+        assert!(!err.to_string().is_empty());
+    }
+}
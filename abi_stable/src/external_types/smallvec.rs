@@ -0,0 +1,632 @@
+//! Ffi-safe equivalent of `smallvec::SmallVec`.
+
+use std::{
+    fmt::{self, Debug},
+    iter::FromIterator,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr, slice,
+};
+
+use smallvec::SmallVec;
+
+use crate::std_types::RVec;
+
+/// Ffi-safe equivalent of `smallvec::SmallVec<[T; N]>`,
+/// a vector that stores up to `N` elements inline,
+/// moving them into an [`RVec`] once it needs to store more.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::{external_types::RSmallVec, sabi_extern_fn, std_types::RVec};
+///
+/// #[sabi_extern_fn]
+/// fn digits(mut n: u64) -> RSmallVec<u8, 4> {
+///     let mut list = RSmallVec::new();
+///     loop {
+///         list.push((n % 10) as u8);
+///         n /= 10;
+///         if n == 0 {
+///             break list;
+///         }
+///     }
+/// }
+///
+/// let few = digits(123);
+/// assert_eq!(few, [3, 2, 1]);
+/// assert!(!few.spilled());
+///
+/// let many = digits(1234567);
+/// assert_eq!(many, [7, 6, 5, 4, 3, 2, 1]);
+/// assert!(many.spilled());
+///
+/// assert_eq!(many.into_rvec(), RVec::from(vec![7, 6, 5, 4, 3, 2, 1]));
+///
+/// ```
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RSmallVec<T, const N: usize> {
+    /// The amount of initialized elements in `inline`, unused when `spilled` is true.
+    inline_len: usize,
+    /// Whether the elements are stored in `heap`.
+    spilled: bool,
+    heap: RVec<T>,
+    inline: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> RSmallVec<T, N> {
+    /// Constructs an empty `RSmallVec`, which stores its elements inline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RSmallVec;
+    ///
+    /// let list = RSmallVec::<u32, 8>::new();
+    ///
+    /// assert!(list.is_empty());
+    /// assert!(!list.spilled());
+    /// assert_eq!(list.capacity(), 8);
+    ///
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            inline_len: 0,
+            spilled: false,
+            heap: RVec::new(),
+            // safety: an array of `MaybeUninit` doesn't need to be initialized
+            inline: unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() },
+        }
+    }
+
+    /// The amount of elements that can be stored inline.
+    pub const fn inline_size(&self) -> usize {
+        N
+    }
+
+    /// Gets the amount of elements in this `RSmallVec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RSmallVec;
+    ///
+    /// let mut list = RSmallVec::<u32, 2>::new();
+    /// assert_eq!(list.len(), 0);
+    ///
+    /// list.extend([3, 5, 8]);
+    /// assert_eq!(list.len(), 3);
+    ///
+    /// ```
+    pub fn len(&self) -> usize {
+        if self.spilled {
+            self.heap.len()
+        } else {
+            self.inline_len
+        }
+    }
+
+    /// Whether this `RSmallVec` contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the amount of elements this `RSmallVec` can store without reallocating.
+    pub fn capacity(&self) -> usize {
+        if self.spilled {
+            self.heap.capacity()
+        } else {
+            N
+        }
+    }
+
+    /// Whether the elements were moved out of the inline buffer into an `RVec`.
+    ///
+    /// Once spilled, the elements stay in the `RVec` even if
+    /// the length goes back to `N` or less.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RSmallVec;
+    ///
+    /// let mut list = RSmallVec::<u32, 2>::new();
+    ///
+    /// list.extend([3, 5]);
+    /// assert!(!list.spilled());
+    ///
+    /// list.push(8);
+    /// assert!(list.spilled());
+    ///
+    /// ```
+    pub fn spilled(&self) -> bool {
+        self.spilled
+    }
+
+    /// Borrows the elements of this `RSmallVec` as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        if self.spilled {
+            &self.heap
+        } else {
+            unsafe { slice::from_raw_parts(self.inline.as_ptr().cast::<T>(), self.inline_len) }
+        }
+    }
+
+    /// Mutably borrows the elements of this `RSmallVec` as a slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        if self.spilled {
+            &mut self.heap
+        } else {
+            unsafe {
+                slice::from_raw_parts_mut(self.inline.as_mut_ptr().cast::<T>(), self.inline_len)
+            }
+        }
+    }
+
+    /// Appends `value` at the end of this `RSmallVec`,
+    /// moving the elements into an `RVec` if the inline buffer is full.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RSmallVec;
+    ///
+    /// let mut list = RSmallVec::<u32, 1>::new();
+    ///
+    /// list.push(3);
+    /// assert_eq!(list, [3]);
+    ///
+    /// list.push(5);
+    /// assert_eq!(list, [3, 5]);
+    ///
+    /// ```
+    pub fn push(&mut self, value: T) {
+        if self.spilled {
+            self.heap.push(value);
+        } else if self.inline_len < N {
+            self.inline[self.inline_len] = MaybeUninit::new(value);
+            self.inline_len += 1;
+        } else {
+            self.spill(N + 1);
+            self.heap.push(value);
+        }
+    }
+
+    /// Removes and returns the last element of this `RSmallVec`,
+    /// returning `None` if it's empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RSmallVec;
+    ///
+    /// let mut list = RSmallVec::<u32, 4>::new();
+    /// list.extend([3, 5]);
+    ///
+    /// assert_eq!(list.pop(), Some(5));
+    /// assert_eq!(list.pop(), Some(3));
+    /// assert_eq!(list.pop(), None);
+    ///
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.spilled {
+            self.heap.pop()
+        } else if self.inline_len == 0 {
+            None
+        } else {
+            self.inline_len -= 1;
+            unsafe { Some(self.inline[self.inline_len].as_ptr().read()) }
+        }
+    }
+
+    /// Removes all the elements of this `RSmallVec`.
+    ///
+    /// This doesn't move the elements back into the inline buffer if it spilled.
+    pub fn clear(&mut self) {
+        if self.spilled {
+            self.heap.clear();
+        } else {
+            let len = mem::replace(&mut self.inline_len, 0);
+            unsafe {
+                let elems = slice::from_raw_parts_mut(self.inline.as_mut_ptr().cast::<T>(), len);
+                ptr::drop_in_place(elems);
+            }
+        }
+    }
+
+    /// Converts this `RSmallVec` into an `RVec`,
+    /// without reallocating if it had already spilled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{external_types::RSmallVec, std_types::RVec};
+    ///
+    /// let list: RSmallVec<u32, 2> = vec![3, 5, 8].into_iter().collect();
+    ///
+    /// assert_eq!(list.into_rvec(), RVec::from(vec![3, 5, 8]));
+    ///
+    /// ```
+    pub fn into_rvec(mut self) -> RVec<T> {
+        if !self.spilled {
+            self.spill(self.inline_len);
+        }
+        mem::take(&mut self.heap)
+    }
+
+    /// Converts an `RVec` into an `RSmallVec`,
+    /// moving the elements inline if there are at most `N` of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{external_types::RSmallVec, std_types::RVec};
+    ///
+    /// let list = RSmallVec::<u32, 2>::from_rvec(RVec::from(vec![3, 5]));
+    /// assert_eq!(list, [3, 5]);
+    /// assert!(!list.spilled());
+    ///
+    /// let list = RSmallVec::<u32, 2>::from_rvec(RVec::from(vec![3, 5, 8]));
+    /// assert_eq!(list, [3, 5, 8]);
+    /// assert!(list.spilled());
+    ///
+    /// ```
+    pub fn from_rvec(mut vec: RVec<T>) -> Self {
+        let mut this = Self::new();
+        let len = vec.len();
+        if len <= N {
+            unsafe {
+                vec.set_len(0);
+                ptr::copy_nonoverlapping(vec.as_ptr(), this.inline.as_mut_ptr().cast::<T>(), len);
+            }
+            this.inline_len = len;
+        } else {
+            this.heap = vec;
+            this.spilled = true;
+        }
+        this
+    }
+
+    /// Converts this `RSmallVec` into a `smallvec::SmallVec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RSmallVec;
+    ///
+    /// use smallvec::SmallVec;
+    ///
+    /// let list: RSmallVec<u32, 4> = vec![3, 5, 8].into_iter().collect();
+    ///
+    /// let list: SmallVec<[u32; 4]> = list.into_smallvec();
+    /// assert_eq!(list[..], [3, 5, 8]);
+    /// assert!(!list.spilled());
+    ///
+    /// ```
+    pub fn into_smallvec(self) -> SmallVec<[T; N]> {
+        if self.spilled {
+            SmallVec::from_vec(self.into_rvec().into_vec())
+        } else {
+            self.into_iter_inline().collect()
+        }
+    }
+
+    /// Moves the inline elements into an `RVec` with room for `capacity` elements.
+    fn spill(&mut self, capacity: usize) {
+        let len = mem::replace(&mut self.inline_len, 0);
+        let mut heap = RVec::with_capacity(capacity);
+        unsafe {
+            ptr::copy_nonoverlapping(self.inline.as_ptr().cast::<T>(), heap.as_mut_ptr(), len);
+            heap.set_len(len);
+        }
+        self.heap = heap;
+        self.spilled = true;
+    }
+
+    /// Moves the inline elements out of this `RSmallVec`.
+    fn into_iter_inline(self) -> impl Iterator<Item = T> {
+        let this = ManuallyDrop::new(self);
+        let len = this.inline_len;
+        // safety: `this` is never dropped, so the first `len` elements are read only once
+        let inline = unsafe { ptr::read(&this.inline) };
+        inline
+            .into_iter()
+            .take(len)
+            .map(|elem| unsafe { elem.assume_init() })
+    }
+}
+
+impl<T, const N: usize> Drop for RSmallVec<T, N> {
+    fn drop(&mut self) {
+        if !self.spilled {
+            self.clear();
+        }
+    }
+}
+
+impl<T, const N: usize> Default for RSmallVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for RSmallVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for RSmallVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Clone for RSmallVec<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        if self.spilled {
+            Self::from_rvec(self.heap.clone())
+        } else {
+            self.iter().cloned().collect()
+        }
+    }
+}
+
+impl<T, const N: usize> Debug for RSmallVec<T, N>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<RSmallVec<U, M>> for RSmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &RSmallVec<U, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<[U; M]> for RSmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.as_slice() == &other[..]
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U]> for RSmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, const N: usize> Eq for RSmallVec<T, N> where T: Eq {}
+
+impl<T, const N: usize> Extend<T> for RSmallVec<T, N> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for RSmallVec<T, N> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<T, const N: usize> From<RVec<T>> for RSmallVec<T, N> {
+    fn from(vec: RVec<T>) -> Self {
+        Self::from_rvec(vec)
+    }
+}
+
+impl<T, const N: usize> From<RSmallVec<T, N>> for RVec<T> {
+    fn from(this: RSmallVec<T, N>) -> Self {
+        this.into_rvec()
+    }
+}
+
+impl<T, const N: usize> From<SmallVec<[T; N]>> for RSmallVec<T, N> {
+    fn from(this: SmallVec<[T; N]>) -> Self {
+        if this.spilled() {
+            Self::from_rvec(RVec::from(this.into_vec()))
+        } else {
+            this.into_iter().collect()
+        }
+    }
+}
+
+impl<T, const N: usize> From<RSmallVec<T, N>> for SmallVec<[T; N]> {
+    fn from(this: RSmallVec<T, N>) -> Self {
+        this.into_smallvec()
+    }
+}
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod test {
+    use super::*;
+
+    use std::{cell::Cell, rc::Rc};
+
+    use abi_stable_shared::test_utils::must_panic;
+
+    use crate::{abi_stability::abi_checking::check_layout_compatibility, StableAbi};
+
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn inline_state() {
+        let mut list = RSmallVec::<String, 3>::new();
+        assert!(list.is_empty());
+        assert_eq!(list.capacity(), 3);
+        assert_eq!(list.inline_size(), 3);
+
+        list.extend(["foo", "bar", "baz"].iter().map(|s| s.to_string()));
+        assert!(!list.spilled());
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.capacity(), 3);
+        assert_eq!(list, ["foo", "bar", "baz"]);
+
+        list[1].push('!');
+        assert_eq!(list.pop().as_deref(), Some("baz"));
+        assert_eq!(list.as_slice(), ["foo", "bar!"]);
+        assert_eq!(format!("{:?}", list), r#"["foo", "bar!"]"#);
+        assert_eq!(list.clone(), list);
+
+        list.clear();
+        assert!(list.is_empty());
+        assert!(!list.spilled());
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn spilled_state() {
+        let mut list: RSmallVec<u32, 2> = (0..2).collect();
+        assert!(!list.spilled());
+
+        list.push(2);
+        assert!(list.spilled());
+        assert!(list.capacity() >= 3);
+        assert_eq!(list, [0, 1, 2]);
+
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+        assert!(list.spilled());
+        assert_eq!(list, [0]);
+
+        let cloned = list.clone();
+        assert!(!cloned.spilled());
+        assert_eq!(cloned, list);
+
+        list.clear();
+        assert!(list.is_empty());
+        assert!(list.spilled());
+    }
+
+    #[test]
+    fn zero_inline_size() {
+        let mut list = RSmallVec::<u8, 0>::new();
+        assert!(!list.spilled());
+        assert_eq!(list.pop(), None);
+
+        list.push(3);
+        assert!(list.spilled());
+        assert_eq!(list, [3]);
+    }
+
+    #[test]
+    fn rvec_conversions() {
+        let list = RSmallVec::<u32, 4>::from(RVec::from(vec![3, 5, 8]));
+        assert!(!list.spilled());
+        assert_eq!(list, [3, 5, 8]);
+        assert_eq!(RVec::from(list), RVec::from(vec![3, 5, 8]));
+
+        let vec = RVec::from(vec![3, 5, 8, 13, 21]);
+        let ptr = vec.as_ptr();
+        let list = RSmallVec::<u32, 4>::from(vec);
+        assert!(list.spilled());
+        assert_eq!(list, [3, 5, 8, 13, 21]);
+
+        let vec = list.into_rvec();
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(vec, RVec::from(vec![3, 5, 8, 13, 21]));
+    }
+
+    #[test]
+    fn smallvec_conversions() {
+        let small: SmallVec<[u32; 4]> = SmallVec::from_slice(&[3, 5]);
+        let list = RSmallVec::from(small.clone());
+        assert!(!list.spilled());
+        assert_eq!(list, [3, 5]);
+        assert_eq!(SmallVec::from(list), small);
+
+        let small: SmallVec<[u32; 4]> = SmallVec::from_slice(&[3, 5, 8, 13, 21]);
+        let list = RSmallVec::from(small.clone());
+        assert!(list.spilled());
+        assert_eq!(list, [3, 5, 8, 13, 21]);
+
+        let back = list.into_smallvec();
+        assert!(back.spilled());
+        assert_eq!(back, small);
+    }
+
+    #[test]
+    fn drops_elements() {
+        let count = Rc::new(Cell::new(0));
+        let counter = || DropCounter(count.clone());
+
+        let mut list = RSmallVec::<DropCounter, 2>::new();
+        list.push(counter());
+        drop(list.pop());
+        assert_eq!(count.get(), 1);
+
+        list.push(counter());
+        list.push(counter());
+        drop(list);
+        assert_eq!(count.get(), 3);
+
+        let list: RSmallVec<DropCounter, 2> = (0..3).map(|_| counter()).collect();
+        assert!(list.spilled());
+        drop(list);
+        assert_eq!(count.get(), 6);
+
+        let list: RSmallVec<DropCounter, 2> = (0..2).map(|_| counter()).collect();
+        let vec = list.into_rvec();
+        assert_eq!(count.get(), 6);
+        drop(vec);
+        assert_eq!(count.get(), 8);
+
+        let list: RSmallVec<DropCounter, 4> = (0..3).map(|_| counter()).collect();
+        let small = list.into_smallvec();
+        assert_eq!(count.get(), 8);
+        drop(small);
+        assert_eq!(count.get(), 11);
+
+        must_panic(|| {
+            let _list: RSmallVec<DropCounter, 2> = (0..2)
+                .map(|i| if i == 1 { panic!() } else { counter() })
+                .collect();
+        })
+        .unwrap();
+        assert_eq!(count.get(), 12);
+    }
+
+    #[test]
+    fn layout_depends_on_inline_size() {
+        let layout = <RSmallVec<u32, 4> as StableAbi>::LAYOUT;
+        check_layout_compatibility(layout, layout).unwrap();
+
+        check_layout_compatibility(layout, <RSmallVec<u32, 8> as StableAbi>::LAYOUT).unwrap_err();
+        check_layout_compatibility(layout, <RSmallVec<u64, 4> as StableAbi>::LAYOUT).unwrap_err();
+    }
+}
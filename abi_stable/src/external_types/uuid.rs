@@ -0,0 +1,218 @@
+//! Ffi-safe equivalent of `uuid::Uuid`.
+
+use std::{
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uuid::{Error as UuidError, Uuid};
+
+/// Ffi-safe equivalent of `uuid::Uuid`.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::{external_types::RUuid, sabi_extern_fn};
+///
+/// use uuid::Uuid;
+///
+/// #[sabi_extern_fn]
+/// fn is_nil(id: RUuid) -> bool {
+///     id.into_uuid().is_nil()
+/// }
+///
+/// let id = RUuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+///
+/// assert!(!is_nil(id));
+/// assert!(is_nil(Uuid::nil().into()));
+///
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, StableAbi)]
+pub struct RUuid {
+    bytes: [u8; 16],
+}
+
+impl RUuid {
+    /// The uuid with all bits set to zero.
+    pub const NIL: Self = Self { bytes: [0; 16] };
+
+    /// Constructs an `RUuid` from its bytes, in big-endian order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RUuid;
+    ///
+    /// let id = RUuid::from_bytes([
+    ///     0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f,
+    ///     0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+    /// ]);
+    ///
+    /// assert_eq!(id.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self { bytes }
+    }
+
+    /// Gets the bytes of this `RUuid`, in big-endian order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RUuid;
+    ///
+    /// let id = RUuid::from_bytes([7; 16]);
+    ///
+    /// assert_eq!(id.as_bytes(), &[7; 16]);
+    ///
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.bytes
+    }
+
+    /// Parses an `RUuid` from any of the string formats that `uuid::Uuid` accepts,
+    /// eg: `"67e55044-10b1-426f-9247-bb680e5fe0c8"` or `"67e5504410b1426f9247bb680e5fe0c8"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RUuid;
+    ///
+    /// let hyphenated = RUuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    /// let simple = RUuid::parse_str("67e5504410b1426f9247bb680e5fe0c8").unwrap();
+    ///
+    /// assert_eq!(hyphenated, simple);
+    ///
+    /// assert!(RUuid::parse_str("67e55044").is_err());
+    ///
+    /// ```
+    pub fn parse_str(str: &str) -> Result<Self, UuidError> {
+        Uuid::parse_str(str).map(Self::from)
+    }
+
+    /// Converts this `RUuid` into a `uuid::Uuid`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RUuid;
+    ///
+    /// use uuid::Uuid;
+    ///
+    /// assert_eq!(RUuid::NIL.into_uuid(), Uuid::nil());
+    ///
+    /// ```
+    pub const fn into_uuid(self) -> Uuid {
+        Uuid::from_bytes(self.bytes)
+    }
+}
+
+impl_from_rust_repr! {
+    impl From<Uuid> for RUuid {
+        fn(this){
+            RUuid::from_bytes(this.into_bytes())
+        }
+    }
+}
+
+impl_into_rust_repr! {
+    impl Into<Uuid> for RUuid {
+        fn(this){
+            this.into_uuid()
+        }
+    }
+}
+
+impl FromStr for RUuid {
+    type Err = UuidError;
+
+    fn from_str(str: &str) -> Result<Self, UuidError> {
+        Self::parse_str(str)
+    }
+}
+
+/// Formats the uuid as a hyphenated string,
+/// eg: `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+impl Display for RUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.into_uuid(), f)
+    }
+}
+
+impl Debug for RUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.into_uuid(), f)
+    }
+}
+
+impl Serialize for RUuid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.into_uuid().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RUuid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Uuid::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn v4_roundtrip() {
+        for _ in 0..16 {
+            let uuid = uuid::Builder::from_random_bytes(rand::random()).into_uuid();
+            assert_eq!(uuid.get_version_num(), 4);
+
+            let ruuid = RUuid::from(uuid);
+            assert_eq!(ruuid.as_bytes(), uuid.as_bytes());
+            assert_eq!(ruuid.to_string(), uuid.to_string());
+            assert_eq!(format!("{:?}", ruuid), format!("{:?}", uuid));
+            assert_eq!(ruuid.into_uuid(), uuid);
+            assert_eq!(Uuid::from(ruuid), uuid);
+        }
+    }
+
+    #[test]
+    fn parse_hyphenated() {
+        const STR: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let expected = RUuid::from_bytes([
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ]);
+
+        assert_eq!(RUuid::parse_str(STR).unwrap(), expected);
+        assert_eq!(STR.parse::<RUuid>().unwrap(), expected);
+        assert_eq!(RUuid::parse_str(&STR.to_uppercase()).unwrap(), expected);
+        assert_eq!(expected.to_string(), STR);
+
+        for invalid in [
+            "",
+            "67e55044-10b1-426f-9247",
+            "67e55044-10b1-426f-9247-bb680e5fe0cz",
+        ] {
+            assert!(RUuid::parse_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let id = RUuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, r#""67e55044-10b1-426f-9247-bb680e5fe0c8""#);
+        assert_eq!(serde_json::from_str::<RUuid>(&json).unwrap(), id);
+    }
+}
@@ -83,15 +83,59 @@ features = [  ]
 ```
 enabling the features you need in the `features` array.
 
+### Optional
+
+These are non-default cargo features that enable optional crates:
+
+- "uuid":
+    Depends on `uuid`,
+    providing an ffi-safe equivalent of `uuid::Uuid`,
+    in `abi_stable::external_types::uuid` .
+
+- "smallvec":
+    Depends on `smallvec`,
+    providing an ffi-safe equivalent of `smallvec::SmallVec`,
+    in `abi_stable::external_types::smallvec` .
+
+- "bytes":
+    Depends on `bytes`,
+    providing an ffi-safe equivalent of `bytes::Bytes`,
+    in `abi_stable::external_types::bytes` .
+
+- "arbitrary":
+    Depends on `arbitrary`,
+    implementing its `Arbitrary` trait for
+    `RVec`, `RString`, `ROption`, `RResult`, and `RHashMap`,
+    to generate them in fuzz targets.
+
+- "rayon":
+    Depends on `rayon`,
+    implementing its parallel iterator traits for `RVec`, `RSlice`, and `RSliceMut`,
+    by exposing their elements as slices.
+
 ### Manually enabled
 
 These are crate features to manually enable support for newer language features:
 
 - "rust_1_64": Turns many functions for converting types to slices into const fns.
 
+- "rust_1_78":
+  Uses `#[diagnostic::on_unimplemented]` to improve some compile-time errors,
+  eg: when requiring a `DynTrait` with an `RMut` pointer to implement `Clone`.
+
 - "rust_latest_stable":
 Enables the "rust_1_*" features for all the stable releases.
 
+### Nightly
+
+These are crate features that require a nightly compiler:
+
+- "nightly_try":
+Implements the unstable `Try` and `FromResidual` traits for
+[`RResult`](crate::std_types::RResult) and [`ROption`](crate::std_types::ROption),
+allowing the `?` operator to be used on them,
+and to convert between them and their `std` equivalents.
+
 # Glossary
 
 `interface crate`: the crate that declares the public functions, types, and traits that
@@ -208,6 +252,7 @@ https://github.com/rodrimati1992/abi_stable_crates/blob/master/readme.md#readme_
 // #![deny(clippy::missing_const_for_fn)]
 #![deny(unsafe_op_in_unsafe_fn)]
 #![cfg_attr(feature = "docsrs", feature(doc_cfg))]
+#![cfg_attr(feature = "nightly_try", feature(try_trait_v2, try_trait_v2_residual))]
 
 #[macro_use]
 extern crate serde_derive;
@@ -52,23 +52,28 @@ pub mod c_abi_testing;
 pub mod development_utils;
 mod errors;
 mod lib_header;
+mod load_future;
 
 #[cfg(test)]
 mod library_tests;
 
 mod raw_library;
+mod root_mod_cache;
 mod root_mod_trait;
 
 #[doc(no_inline)]
 pub use self::c_abi_testing::{CAbiTestingFns, C_ABI_TESTING_FNS};
 
 pub use self::{
-    errors::{IntoRootModuleResult, LibraryError, RootModuleError},
-    lib_header::{AbiHeader, AbiHeaderRef, LibHeader},
+    errors::{IntoRootModuleResult, InvalidRootModuleName, LibraryError, RootModuleError},
+    lib_header::{AbiHeader, AbiHeaderRef, LibHeader, LibraryManifest},
+    load_future::LoadFuture,
     raw_library::RawLibrary,
+    root_mod_cache::RootModuleCache,
     root_mod_trait::{
         abi_header_from_path, abi_header_from_raw_library, lib_header_from_path,
-        lib_header_from_raw_library, RootModule, RootModuleConsts,
+        lib_header_from_raw_library, named_lib_header_from_raw_library, RootModule,
+        RootModuleConsts,
     },
 };
 
@@ -31,8 +31,16 @@ pub enum LibraryError {
         /// The path to the library
         library: PathBuf,
         /// The name of the function/static.Does not have to be utf-8.
+        ///
+        /// When loading a root module,
+        /// this is the mangled name of the function that loads it
+        /// ([`ROOT_MODULE_LOADER_NAME`] for unnamed root modules),
+        /// including the nul terminator.
+        ///
+        /// [`ROOT_MODULE_LOADER_NAME`]: crate::library::ROOT_MODULE_LOADER_NAME
         symbol: Vec<u8>,
-        /// The cause of the error
+        /// The cause of the error,
+        /// which includes the message from the OS (eg: `dlerror` on unix).
         err: Box<libloading::Error>,
     },
     /// The version string could not be parsed into a version number.
@@ -95,8 +103,8 @@ impl Display for LibraryError {
                 err,
             } => writeln!(
                 f,
-                "Could load symbol:\n\t{}\nin library:\n\t{}\nbecause:\n\t{}",
-                String::from_utf8_lossy(symbol),
+                "Could not load symbol:\n\t{}\nin library:\n\t{}\nbecause:\n\t{}",
+                String::from_utf8_lossy(symbol.strip_suffix(b"\0").unwrap_or(symbol)),
                 library.display(),
                 err
             ),
@@ -182,6 +190,27 @@ impl RootModuleError {
         }
     }
 
+    /// Gets the error returned by the root module loader function,
+    /// returning None if it panicked instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use abi_stable::{library::RootModuleError, std_types::RBoxError};
+    ///
+    /// let returned = RootModuleError::Returned(RBoxError::from_fmt(&"oh no"));
+    /// assert_eq!(returned.error().unwrap().to_string(), "oh no");
+    ///
+    /// assert!(RootModuleError::Unwound.error().is_none());
+    ///
+    /// ```
+    pub fn error(&self) -> Option<&RBoxError> {
+        match self {
+            Self::Returned(e) => Some(e),
+            Self::Unwound => None,
+        }
+    }
+
     /// Converts this `RootModuleError` into a `LibraryError`,
     /// with metadata about the module that failed to load.
     pub fn into_library_error<M: RootModule>(self) -> LibraryError {
@@ -209,6 +238,49 @@ impl ::std::error::Error for RootModuleError {}
 
 //////////////////////////////////////////////////////////////////////
 
+/// The error for when the name passed to the `RootModule::load_named_from*` functions
+/// is not a valid root module name.
+///
+/// This is returned inside a [`LibraryError::RootModule`],
+/// as a [`RootModuleError::Returned`] that can be downcasted to this type.
+///
+/// # Example
+///
+/// ```rust
+/// use abi_stable::library::{InvalidRootModuleName, LibraryError};
+///
+/// fn is_invalid_name(err: &LibraryError) -> bool {
+///     match err {
+///         LibraryError::RootModule { err, .. } => err
+///             .error()
+///             .and_then(|e| e.downcast_ref::<InvalidRootModuleName>())
+///             .is_some(),
+///         _ => false,
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InvalidRootModuleName {
+    /// The invalid name
+    pub name: String,
+}
+
+impl Display for InvalidRootModuleName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid root module name: {:?}\n\
+             expected a non-empty string of ASCII alphanumeric characters and underscores.",
+            self.name,
+        )
+    }
+}
+
+impl ::std::error::Error for InvalidRootModuleName {}
+
+//////////////////////////////////////////////////////////////////////
+
 /// For converting the return value of a `#[export_root_module]` function
 /// to a `Result<_, RootModuleError>`.
 pub trait IntoRootModuleResult {
@@ -286,7 +358,10 @@ mod tests {
                 PREFIX.0.to_raw_ptr() as usize
             );
 
-            let downcasted = match err.err().unwrap() {
+            let err = err.err().unwrap();
+            assert_eq!(err.error().unwrap().to_string(), FmtError.to_string());
+
+            let downcasted = match err {
                 RootModuleError::Returned(x) => x.downcast::<FmtError>().unwrap(),
                 RootModuleError::Unwound => unreachable!(),
             };
@@ -309,4 +384,27 @@ mod tests {
             test_case(ok.into_root_module_result(), err.into_root_module_result());
         }
     }
+
+    #[test]
+    fn returned_error_is_retrievable() {
+        let err: RResult<Module_Ref, RBoxError> = RErr(RBoxError::from_fmt(&"failed to init"));
+        let err = err.into_root_module_result().err().unwrap();
+
+        assert_eq!(err.error().unwrap().to_string(), "failed to init");
+
+        let lib_err = err.into_library_error::<Module_Ref>();
+        match &lib_err {
+            LibraryError::RootModule { err, .. } => {
+                assert_eq!(err.error().unwrap().to_string(), "failed to init");
+            }
+            _ => panic!("unexpected error: {:?}", lib_err),
+        }
+        assert!(
+            lib_err.to_string().contains("failed to init"),
+            "{}",
+            lib_err
+        );
+
+        assert!(RootModuleError::Unwound.error().is_none());
+    }
 }
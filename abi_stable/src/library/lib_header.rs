@@ -2,7 +2,9 @@ use super::*;
 
 use crate::{
     prefix_type::{PrefixRef, PrefixRefTrait},
+    reflection::ModReflMode,
     sabi_types::RRef,
+    type_layout::TLData,
 };
 
 /// Used to check the layout of modules returned by module-loading functions
@@ -83,6 +85,47 @@ impl LibHeader {
         self.root_mod_consts.layout().into_option()
     }
 
+    /// Describes what the library exports,
+    /// without loading the root module nor checking its layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use abi_stable::{
+    ///     for_examples::{Module, Module_Ref},
+    ///     library::LibHeader,
+    ///     prefix_type::WithMetadata,
+    ///     std_types::{RNone, RStr},
+    /// };
+    ///
+    /// const WITH_META: &WithMetadata<Module> = &WithMetadata::new(Module {
+    ///     first: RNone,
+    ///     second: RStr::from_str("hello"),
+    ///     third: 100,
+    /// });
+    ///
+    /// let header = LibHeader::from_module(Module_Ref(WITH_META.static_as_prefix()));
+    ///
+    /// let manifest = header.describe();
+    /// assert_eq!(manifest.name, "example_root_module");
+    /// assert_eq!(
+    ///     manifest.field_names,
+    ///     Some(vec!["first", "second", "third"]),
+    /// );
+    ///
+    /// ```
+    ///
+    pub fn describe(&self) -> LibraryManifest {
+        LibraryManifest {
+            abi_major: self.header.abi_major,
+            abi_minor: self.header.abi_minor,
+            base_name: self.root_mod_consts.base_name(),
+            name: self.root_mod_consts.name(),
+            version_strings: self.version_strings(),
+            field_names: self.layout().map(root_module_field_names),
+        }
+    }
+
     pub(super) fn initialize_library_globals(&self, globals: &'static Globals) {
         (self.init_globals_with.0)(globals);
     }
@@ -300,6 +343,51 @@ impl LibHeader {
 
 //////////////////////////////////////////////////////////////////////
 
+/// A description of what a dynamic library exports,
+/// gotten from its [`LibHeader`] without loading its root module.
+///
+/// This is returned by [`LibHeader::describe`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LibraryManifest {
+    /// The major abi version of abi_stable that the library was compiled with.
+    pub abi_major: u32,
+    /// The minor abi version of abi_stable that the library was compiled with.
+    pub abi_minor: u32,
+    /// The name of the dynamic library, see [`RootModule::BASE_NAME`].
+    pub base_name: RStr<'static>,
+    /// The name of the root module, see [`RootModule::NAME`].
+    pub name: RStr<'static>,
+    /// The version of the library, see [`RootModule::VERSION_STRINGS`].
+    pub version_strings: VersionStrings,
+    /// The names of the fields of the root module, in declaration order.
+    ///
+    /// This is `None` if the library doesn't include the layout of its root module
+    /// (by using the `#[unsafe_no_layout_constant]` helper attribute).
+    pub field_names: Option<Vec<&'static str>>,
+}
+
+fn root_module_field_names(mut layout: &'static TypeLayout) -> Vec<&'static str> {
+    loop {
+        match layout.mod_refl_mode() {
+            ModReflMode::DelegateDeref { layout_index } => {
+                layout = layout.shared_vars().type_layouts()[layout_index as usize]();
+            }
+            ModReflMode::Module => {
+                let fields = match layout.data() {
+                    TLData::Struct { fields } => fields,
+                    TLData::PrefixType(prefix) => prefix.fields,
+                    _ => return Vec::new(),
+                };
+                return fields.iter().map(|f| f.name()).collect();
+            }
+            ModReflMode::Opaque => return Vec::new(),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////
+
 struct GetAbortingConstructor<T>(T);
 
 impl<T> GetAbortingConstructor<T> {
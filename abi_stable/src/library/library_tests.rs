@@ -13,3 +13,173 @@ fn root_module_loader_name_test() {
     assert_eq!(ROOT_MODULE_LOADER_NAME_NULSTR.to_str(), name);
     assert_eq!(ROOT_MODULE_LOADER_NAME_NULSTR.to_str_with_nul(), with_nul);
 }
+
+#[test]
+fn named_root_module_loader_name_test() {
+    use abi_stable_shared::{is_valid_root_module_name, mangled_named_root_module_loader_name};
+
+    let unnamed = mangled_root_module_loader_name();
+    let foo = mangled_named_root_module_loader_name("foo");
+    let bar = mangled_named_root_module_loader_name("bar_1");
+
+    assert!(foo.starts_with(&unnamed), "{}", foo);
+    assert!(bar.starts_with(&unnamed), "{}", bar);
+    assert_ne!(foo, unnamed);
+    assert_ne!(foo, bar);
+
+    assert!(is_valid_root_module_name("foo"));
+    assert!(is_valid_root_module_name("bar_1"));
+    assert!(!is_valid_root_module_name(""));
+    assert!(!is_valid_root_module_name("foo.bar"));
+    assert!(!is_valid_root_module_name("foo bar"));
+    assert!(!is_valid_root_module_name("ñandú"));
+    assert!(!is_valid_root_module_name("foo\u{0660}"));
+
+    crate::test_utils::must_panic(|| mangled_named_root_module_loader_name("")).unwrap();
+    crate::test_utils::must_panic(|| mangled_named_root_module_loader_name("a-b")).unwrap();
+    crate::test_utils::must_panic(|| mangled_named_root_module_loader_name("ñ")).unwrap();
+}
+
+#[test]
+fn load_from_directories_errors() {
+    use crate::{
+        for_examples::Module_Ref,
+        library::{LibraryError, RootModule},
+    };
+    use std::path::Path;
+
+    let dirs: &[&Path] = &[
+        "nonexistent_dir_0/foo".as_ref(),
+        "nonexistent_dir_1/bar".as_ref(),
+    ];
+
+    match Module_Ref::load_from_directories(dirs).err().unwrap() {
+        LibraryError::Many(errors) => {
+            assert_eq!(errors.len(), 2);
+            for (err, dir) in errors.iter().zip(dirs) {
+                match err {
+                    LibraryError::OpenError { path, .. } => {
+                        assert!(path.starts_with(dir), "{:?}", path);
+                    }
+                    _ => panic!("unexpected error: {:?}", err),
+                }
+            }
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+
+    match Module_Ref::load_from_directories(&[]).err().unwrap() {
+        LibraryError::Many(errors) => assert!(errors.is_empty()),
+        e => panic!("unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn load_from_directory_async_errors() {
+    use crate::{
+        for_examples::Module_Ref,
+        library::{LibraryError, RootModule},
+    };
+    use abi_stable_shared::test_utils::block_on;
+    use std::path::Path;
+
+    let dir: &Path = "nonexistent_dir_0/foo".as_ref();
+
+    let future = Module_Ref::load_from_directory_async(dir);
+    match block_on(future).err().unwrap() {
+        LibraryError::OpenError { path, .. } => {
+            assert!(path.starts_with(dir), "{:?}", path);
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn get_symbol_error_display() {
+    use crate::library::LibraryError;
+
+    let err = LibraryError::GetSymbolError {
+        library: "foo/libbar.so".into(),
+        symbol: ROOT_MODULE_LOADER_NAME_WITH_NUL.as_bytes().to_vec(),
+        err: Box::new(libloading::Error::DlSymUnknown),
+    };
+    let formatted = err.to_string();
+
+    assert!(formatted.contains("foo/libbar.so"), "{}", formatted);
+    assert!(
+        formatted.contains(&format!("\t{}\n", ROOT_MODULE_LOADER_NAME)),
+        "{}",
+        formatted
+    );
+    assert!(!formatted.contains('\0'), "{:?}", formatted);
+    assert!(
+        formatted.contains(&libloading::Error::DlSymUnknown.to_string()),
+        "{}",
+        formatted
+    );
+}
+
+#[test]
+fn describe_lib_header() {
+    use crate::{
+        for_examples::{Module, Module_Ref},
+        library::{AbiHeader, CheckTypeLayout, LibHeader, RootModule, RootModuleResult},
+        prefix_type::WithMetadata,
+        std_types::{RSome, RStr},
+    };
+
+    const WITH_META: &WithMetadata<Module> = &WithMetadata::new(Module {
+        first: RSome(3),
+        second: RStr::from_str("hello"),
+        third: 5,
+    });
+
+    let header = LibHeader::from_module(Module_Ref(WITH_META.static_as_prefix()));
+    let manifest = header.describe();
+
+    assert_eq!(manifest.abi_major, AbiHeader::VALUE.abi_major);
+    assert_eq!(manifest.abi_minor, AbiHeader::VALUE.abi_minor);
+    assert_eq!(manifest.base_name, Module_Ref::BASE_NAME);
+    assert_eq!(manifest.name, Module_Ref::NAME);
+    assert_eq!(manifest.version_strings, Module_Ref::VERSION_STRINGS);
+    assert_eq!(manifest.field_names, Some(vec!["first", "second", "third"]));
+
+    extern "C" fn constructor() -> RootModuleResult {
+        unreachable!()
+    }
+
+    let header =
+        unsafe { LibHeader::from_constructor::<Module_Ref>(constructor, CheckTypeLayout::No) };
+    let manifest = header.describe();
+
+    assert_eq!(manifest.name, Module_Ref::NAME);
+    assert_eq!(manifest.field_names, None);
+}
+
+#[test]
+fn load_named_invalid_name_test() {
+    use crate::{
+        for_examples::Module_Ref,
+        library::{InvalidRootModuleName, LibraryError, RootModule},
+    };
+
+    use std::path::Path;
+
+    // The directory doesn't exist,
+    // getting an `OpenError` would mean that the name was checked after opening the library.
+    let err = Module_Ref::load_named_from_directory(Path::new("/nonexistent/dir"), "foo.bar")
+        .err()
+        .unwrap();
+
+    match &err {
+        LibraryError::RootModule { err, .. } => {
+            let invalid = err
+                .error()
+                .and_then(|e| e.downcast_ref::<InvalidRootModuleName>())
+                .unwrap();
+            assert_eq!(invalid.name, "foo.bar");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    assert!(err.to_string().contains("foo.bar"), "{}", err);
+}
@@ -0,0 +1,109 @@
+//! Contains the `LoadFuture` type,
+//! returned by the async methods of `RootModule` that load a dynamic library.
+
+use std::{
+    fmt::{self, Debug},
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use super::LibraryError;
+
+/// A future that loads a root module in a separate thread,
+/// so that blocking on the dynamic library loader doesn't block the async executor.
+///
+/// This is returned by
+/// [`RootModule::load_from_directory_async`
+/// ](crate::library::RootModule::load_from_directory_async).
+///
+/// This future doesn't depend on any async runtime,
+/// it wakes up the task that polled it when the loading thread finishes.
+///
+/// # Panics
+///
+/// Polling this future panics if the loading thread panicked,
+/// propagating the panic.
+#[must_use = "futures do nothing unless polled"]
+pub struct LoadFuture<M> {
+    shared: Arc<Mutex<Shared<M>>>,
+}
+
+struct Shared<M> {
+    result: Option<thread::Result<Result<M, LibraryError>>>,
+    waker: Option<Waker>,
+}
+
+impl<M> LoadFuture<M>
+where
+    M: Send + 'static,
+{
+    /// Calls `load` in a new thread, returning a future of its result.
+    pub(super) fn spawn<F>(load: F) -> Self
+    where
+        F: FnOnce() -> Result<M, LibraryError> + Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+
+        let thread_shared = shared.clone();
+        thread::spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(load));
+
+            let waker = {
+                let mut guard = lock_shared(&thread_shared);
+                guard.result = Some(result);
+                guard.waker.take()
+            };
+            // Waking after releasing the lock,
+            // so that the woken task doesn't block when it polls this future.
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        Self { shared }
+    }
+}
+
+fn lock_shared<M>(shared: &Mutex<Shared<M>>) -> MutexGuard<'_, Shared<M>> {
+    // The mutex is never held while running user code,
+    // so it can only be poisoned by a panicking `Waker::clone`.
+    shared.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl<M> Future for LoadFuture<M> {
+    type Output = Result<M, LibraryError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut guard = lock_shared(&self.shared);
+        match guard.result.take() {
+            Some(Ok(result)) => Poll::Ready(result),
+            Some(Err(payload)) => {
+                drop(guard);
+                panic::resume_unwind(payload)
+            }
+            None => {
+                match &mut guard.waker {
+                    Some(waker) if waker.will_wake(cx.waker()) => {}
+                    waker => *waker = Some(cx.waker().clone()),
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<M> Debug for LoadFuture<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_finished = lock_shared(&self.shared).result.is_some();
+        f.debug_struct("LoadFuture")
+            .field("is_finished", &is_finished)
+            .finish()
+    }
+}
@@ -47,13 +47,52 @@ impl RawLibrary {
 
     /// Gets access to a static/function declared by the library.
     ///
+    /// This can be used to get symbols that a library exports
+    /// in addition to its root module,
+    /// getting the `RawLibrary` of a loaded root module with
+    /// [`RootModule::get_raw_library`](./trait.RootModule.html#method.get_raw_library).
+    ///
     /// # Safety
     ///
     /// Passing a `T` of a type different than the compiled library declared is
     /// undefined behavior.
     ///
+    /// For functions, `T` must be a function pointer type with the same
+    /// signature and ABI (usually `extern "C" fn(..) -> ..`)
+    /// as the function exported by the library.
+    ///
+    /// For statics, `T` must be a pointer to the type of the static.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use abi_stable::{
+    ///     for_examples::Module_Ref,
+    ///     library::RootModule,
+    ///     std_types::RStr,
+    /// };
+    ///
+    /// // The library exports this function (besides the root module):
+    /// // ```
+    /// // #[no_mangle]
+    /// // pub extern "C" fn example_version_banner() -> RStr<'static> {
+    /// //     RStr::from_str("example 1.0.0")
+    /// // }
+    /// // ```
+    ///
+    /// Module_Ref::load_from_directory("target/debug".as_ref()).unwrap();
+    ///
+    /// let raw_library = Module_Ref::get_raw_library().unwrap();
+    ///
+    /// let banner = unsafe {
+    ///     raw_library
+    ///         .get::<extern "C" fn() -> RStr<'static>>(b"example_version_banner\0")
+    ///         .unwrap()
+    /// };
     ///
+    /// println!("{}", banner());
     ///
+    /// ```
     pub unsafe fn get<T>(&self, symbol_name: &[u8]) -> Result<LLSymbol<'_, T>, LibraryError> {
         match unsafe { self.library.get::<T>(symbol_name) } {
             Ok(symbol) => Ok(symbol),
@@ -0,0 +1,239 @@
+use super::*;
+
+use crate::{external_types::RMutex, utils::leak_value};
+
+/// A cache of root modules of the same type, loaded from multiple dynamic libraries,
+/// keyed by the canonicalized path of each library.
+///
+/// This is useful for loading many plugins that share the same root module type,
+/// since the [`RootModule`]`::load_from*` associated functions
+/// only load one root module of each type.
+///
+/// Loading a library through different spellings of the same path
+/// (eg: `plugins/foo.so` and `./plugins/../plugins/foo.so`)
+/// returns the root module that was loaded the first time.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use abi_stable::{
+///     library::{RootModule, RootModuleCache},
+///     sabi_types::VersionStrings,
+///     StableAbi,
+/// };
+///
+/// #[repr(C)]
+/// #[derive(StableAbi)]
+/// #[sabi(kind(Prefix(prefix_ref = PluginMod_Ref)))]
+/// pub struct PluginMod {
+///     #[sabi(last_prefix_field)]
+///     pub get_number: extern "C" fn() -> u32,
+/// }
+///
+/// impl RootModule for PluginMod_Ref {
+///     abi_stable::declare_root_module_statics! {PluginMod_Ref}
+///     const BASE_NAME: &'static str = "plugin";
+///     const NAME: &'static str = "plugin";
+///     const VERSION_STRINGS: VersionStrings = abi_stable::package_version_strings!();
+/// }
+///
+/// static PLUGINS: RootModuleCache<PluginMod_Ref> = RootModuleCache::new();
+///
+/// # fn main(){
+/// let first = PLUGINS.load_from_file("plugins/libfoo.so".as_ref()).unwrap();
+/// let second = PLUGINS.load_from_file("./plugins/../plugins/libfoo.so".as_ref()).unwrap();
+///
+/// assert!(std::ptr::eq(
+///     first.0.to_raw_ptr(),
+///     second.0.to_raw_ptr(),
+/// ));
+/// # }
+/// ```
+pub struct RootModuleCache<M> {
+    modules: RMutex<Vec<(PathBuf, M)>>,
+}
+
+impl<M> RootModuleCache<M> {
+    /// Constructs an empty `RootModuleCache`.
+    pub const fn new() -> Self {
+        Self {
+            modules: RMutex::new(Vec::new()),
+        }
+    }
+}
+
+impl<M> Default for RootModuleCache<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> RootModuleCache<M>
+where
+    M: RootModule,
+{
+    /// Gets the root module that was loaded from the library at `path`,
+    /// returning `None` if it wasn't loaded with this cache.
+    pub fn get(&self, path: &Path) -> Option<M> {
+        self.get_canonical(&canonicalize(path))
+    }
+
+    fn get_canonical(&self, path: &Path) -> Option<M> {
+        self.modules
+            .lock()
+            .iter()
+            .find(|(loaded, _)| *loaded == path)
+            .map(|(_, module)| *module)
+    }
+
+    /// Gets the paths (canonicalized where possible) of all the libraries
+    /// loaded with this cache, in the order they were loaded.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.modules
+            .lock()
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    /// Loads the root module from the library at `path`,
+    /// returning the already loaded root module if that library was loaded before.
+    ///
+    /// The path is canonicalized before comparing it to those of
+    /// previously loaded libraries,
+    /// if that fails the path is used as it is.
+    ///
+    /// This leaks the underlying dynamic library,
+    /// and calls [`RootModule::initialization`] on the root module
+    /// the first time that it's loaded.
+    ///
+    /// The library is loaded without locking the cache,
+    /// so other threads can look up modules while it loads,
+    /// and [`RootModule::initialization`] can use the cache.
+    ///
+    /// # Interaction with `RootModule::load_from*`
+    ///
+    /// If no root module of type `M` was loaded yet,
+    /// the first library loaded by this cache becomes the one returned by
+    /// [`RootModule::get_module`] and [`RootModule::get_raw_library`],
+    /// as though it had been loaded with [`RootModule::load_from_file`].
+    ///
+    /// Loading the library that [`RootModule::get_module`] returns the root module of
+    /// reuses that root module, without calling [`RootModule::initialization`] again.
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`lib_header_from_path`]
+    /// and [`LibHeader::init_root_module`],
+    /// as well as any error returned by [`RootModule::initialization`].
+    ///
+    /// Libraries that failed to load are not cached.
+    pub fn load_from_file(&self, path: &Path) -> Result<M, LibraryError> {
+        let path = canonicalize(path);
+
+        if let Some(module) = self.get_canonical(&path) {
+            return Ok(module);
+        }
+
+        let raw_library = RawLibrary::load_at(&path)?;
+        let module =
+            unsafe { lib_header_from_raw_library(&raw_library)? }.init_root_module::<M>()?;
+
+        // if the library isn't leaked
+        // it would cause any use of the module to be a use after free.
+        let raw_library = leak_value(raw_library);
+
+        let statics = M::root_module_statics();
+        let mut initialized = None;
+        let global = statics.root_mod.try_init(|| {
+            let module = module.initialization()?;
+            statics.raw_lib.init(|| raw_library);
+            initialized = Some(module);
+            Ok::<M, LibraryError>(module)
+        })?;
+        let module = match initialized {
+            Some(module) => module,
+            None if same_module(global, module) => global,
+            None => module.initialization()?,
+        };
+
+        let mut modules = self.modules.lock();
+
+        // Another thread could have loaded the same library while this one was loading it.
+        if let Some((_, module)) = modules.iter().find(|(loaded, _)| *loaded == path) {
+            return Ok(*module);
+        }
+
+        modules.push((path, module));
+
+        Ok(module)
+    }
+
+    /// Loads the root module from the library in the `directory`,
+    /// using [`RootModule::get_library_path`] to get the file name of the library.
+    ///
+    /// Behaves the same as [`load_from_file`](Self::load_from_file) otherwise.
+    pub fn load_from_directory(&self, directory: &Path) -> Result<M, LibraryError> {
+        self.load_from_file(&M::get_library_path(directory))
+    }
+}
+
+/// Whether both values point to the same root module.
+fn same_module<M>(left: M, right: M) -> bool
+where
+    M: RootModule,
+{
+    std::ptr::eq(
+        left.to_prefix_ref().to_raw_ptr(),
+        right.to_prefix_ref().to_raw_ptr(),
+    )
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_owned())
+}
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    use crate::{prefix_type::WithMetadata, sabi_types::VersionStrings, StableAbi};
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_ref = Module_Ref)))]
+    pub struct Module {
+        #[sabi(last_prefix_field)]
+        pub first: u8,
+    }
+
+    impl RootModule for Module_Ref {
+        crate::declare_root_module_statics! {Module_Ref}
+        const BASE_NAME: &'static str = "root_mod_cache_test";
+        const NAME: &'static str = "root_mod_cache_test";
+        const VERSION_STRINGS: VersionStrings = crate::package_version_strings!();
+    }
+
+    #[test]
+    fn failed_loads_are_not_cached() {
+        static CACHE: RootModuleCache<Module_Ref> = RootModuleCache::new();
+
+        let path: &Path = "foo/bar/libroot_mod_cache_test.so".as_ref();
+        for _ in 0..2 {
+            let err = CACHE.load_from_file(path).err().unwrap();
+            assert!(matches!(err, LibraryError::OpenError { .. }), "{:?}", err);
+
+            let err = CACHE.load_from_directory("foo/bar".as_ref()).err().unwrap();
+            assert!(matches!(err, LibraryError::OpenError { .. }), "{:?}", err);
+        }
+        assert!(CACHE.get(path).is_none());
+        assert!(CACHE.paths().is_empty());
+    }
+
+    #[test]
+    fn module_accessor() {
+        const MODULE: &WithMetadata<Module> = &WithMetadata::new(Module { first: 3 });
+
+        assert_eq!(Module_Ref(MODULE.static_as_prefix()).first(), 3);
+    }
+}
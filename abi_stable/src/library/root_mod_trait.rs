@@ -1,6 +1,10 @@
 use super::*;
 
-use crate::{prefix_type::PrefixRefTrait, utils::leak_value};
+use crate::{
+    prefix_type::PrefixRefTrait,
+    std_types::{RBoxError, RVec},
+    utils::leak_value,
+};
 
 /// The root module of a dynamic library,
 /// which may contain other modules,function pointers,and static references.
@@ -161,30 +165,7 @@ pub trait RootModule: Sized + StableAbi + PrefixRefTrait + 'static {
     /// If the root module initializer returned an error or panicked.
     ///
     fn load_from(where_: LibraryPath<'_>) -> Result<Self, LibraryError> {
-        let statics = Self::root_module_statics();
-        statics.root_mod.try_init(|| {
-            let lib = statics.raw_lib.try_init(|| -> Result<_, LibraryError> {
-                let raw_library = load_raw_library::<Self>(where_)?;
-
-                // if the library isn't leaked
-                // it would cause any use of the module to be a use after free.
-                //
-                // By leaking the library
-                // this allows the root module loader to do anything that'd prevent
-                // sound library unloading.
-                Ok(leak_value(raw_library))
-            })?;
-            let items = unsafe { lib_header_from_raw_library(lib)? };
-
-            items.ensure_layout::<Self>()?;
-
-            // safety: the layout was checked in the code above,
-            unsafe {
-                items
-                    .init_root_module_with_unchecked_layout::<Self>()?
-                    .initialization()
-            }
-        })
+        load_from_with_header::<Self>(where_, |lib| unsafe { lib_header_from_raw_library(lib) })
     }
 
     /// Loads this module from the directory specified by `where_`,
@@ -199,6 +180,50 @@ pub trait RootModule: Sized + StableAbi + PrefixRefTrait + 'static {
         Self::load_from(LibraryPath::Directory(where_))
     }
 
+    /// Loads this module from the directory specified by `where_` in a new thread,
+    /// returning a future that completes once the module is loaded.
+    ///
+    /// This is for async code, since loading a dynamic library can block,
+    /// eg: when it's in a slow filesystem.
+    ///
+    /// Once the root module is loaded,
+    /// this will return the already loaded root module.
+    ///
+    /// Warnings and Errors are detailed in [`load_from`](#method.load_from),
+    ///
+    fn load_from_directory_async(where_: &Path) -> LoadFuture<Self>
+    where
+        Self: Send,
+    {
+        let where_ = where_.to_path_buf();
+        LoadFuture::spawn(move || Self::load_from_directory(&where_))
+    }
+
+    /// Loads this module from the first directory in `dirs` that it can be loaded from,
+    /// trying each directory in order.
+    ///
+    /// Once the root module is loaded,
+    /// this will return the already loaded root module.
+    ///
+    /// Warnings and Errors are detailed in [`load_from`](#method.load_from),
+    ///
+    /// # Errors
+    ///
+    /// If the module couldn't be loaded from any of the directories,
+    /// this returns a `LibraryError::Many` with the error for each directory,
+    /// in the same order as `dirs`.
+    ///
+    fn load_from_directories(dirs: &[&Path]) -> Result<Self, LibraryError> {
+        let mut errors = RVec::with_capacity(dirs.len());
+        for dir in dirs {
+            match Self::load_from_directory(dir) {
+                Ok(module) => return Ok(module),
+                Err(e) => errors.push(e),
+            }
+        }
+        Err(LibraryError::Many(errors))
+    }
+
     /// Loads this module from the file at `path_`,
     /// first loading the dynamic library if it wasn't already loaded.
     ///
@@ -211,6 +236,60 @@ pub trait RootModule: Sized + StableAbi + PrefixRefTrait + 'static {
         Self::load_from(LibraryPath::FullPath(path_))
     }
 
+    /// Loads this module from the path specified by `where_`,
+    /// using the root module exported with `#[export_root_module(name = "...")]`,
+    /// where `name` is the same as the `name` argument of this function.
+    ///
+    /// This allows a dynamic library to export multiple independent root modules,
+    /// each one loaded through a different `RootModule` type.
+    ///
+    /// Once the root module is loaded,
+    /// this will return the already loaded root module.
+    ///
+    /// Warnings and Errors are detailed in [`load_from`](#method.load_from).
+    ///
+    /// If `name` is empty or contains characters other than ASCII alphanumerics and underscores,
+    /// this returns a `LibraryError::RootModule` error wrapping an [`InvalidRootModuleName`],
+    /// without opening the library.
+    ///
+    /// [`InvalidRootModuleName`]: crate::library::InvalidRootModuleName
+    fn load_named_from(where_: LibraryPath<'_>, name: &str) -> Result<Self, LibraryError> {
+        if !abi_stable_shared::is_valid_root_module_name(name) {
+            let err = InvalidRootModuleName { name: name.into() };
+            return Err(RootModuleError::Returned(RBoxError::new(err)).into_library_error::<Self>());
+        }
+
+        load_from_with_header::<Self>(where_, |lib| unsafe {
+            named_lib_header_from_raw_library(lib, name)
+        })
+    }
+
+    /// Loads this module from the directory specified by `where_`,
+    /// using the root module exported with `#[export_root_module(name = "...")]`.
+    ///
+    /// Once the root module is loaded,
+    /// this will return the already loaded root module.
+    ///
+    /// Warnings and Errors are detailed in
+    /// [`load_named_from`](#method.load_named_from),
+    ///
+    fn load_named_from_directory(where_: &Path, name: &str) -> Result<Self, LibraryError> {
+        Self::load_named_from(LibraryPath::Directory(where_), name)
+    }
+
+    /// Loads this module from the file at `path_`,
+    /// using the root module exported with `#[export_root_module(name = "...")]`.
+    ///
+    /// Once the root module is loaded,
+    /// this will return the already loaded root module.
+    ///
+    /// Warnings and Errors are detailed in
+    /// [`load_named_from`](#method.load_named_from),
+    ///
+    fn load_named_from_file(path_: &Path, name: &str) -> Result<Self, LibraryError> {
+        Self::load_named_from(LibraryPath::FullPath(path_), name)
+    }
+
     /// Defines behavior that happens once the module is loaded.
     ///
     /// This is ran in the `RootModule::load*` associated functions
@@ -222,6 +301,41 @@ pub trait RootModule: Sized + StableAbi + PrefixRefTrait + 'static {
     }
 }
 
+/// Loads the root module from the library at `where_`,
+/// getting the `LibHeader` of the root module with `get_header`.
+fn load_from_with_header<M>(
+    where_: LibraryPath<'_>,
+    get_header: impl FnOnce(&'static RawLibrary) -> Result<&'static LibHeader, LibraryError>,
+) -> Result<M, LibraryError>
+where
+    M: RootModule,
+{
+    let statics = M::root_module_statics();
+    statics.root_mod.try_init(|| {
+        let lib = statics.raw_lib.try_init(|| -> Result<_, LibraryError> {
+            let raw_library = load_raw_library::<M>(where_)?;
+
+            // if the library isn't leaked
+            // it would cause any use of the module to be a use after free.
+            //
+            // By leaking the library
+            // this allows the root module loader to do anything that'd prevent
+            // sound library unloading.
+            Ok(leak_value(raw_library))
+        })?;
+        let items = get_header(lib)?;
+
+        items.ensure_layout::<M>()?;
+
+        // safety: the layout was checked in the code above,
+        unsafe {
+            items
+                .init_root_module_with_unchecked_layout::<M>()?
+                .initialization()
+        }
+    })
+}
+
 /// Loads the raw library at `where_`
 fn load_raw_library<M>(where_: LibraryPath<'_>) -> Result<RawLibrary, LibraryError>
 where
@@ -282,6 +396,40 @@ pub unsafe fn abi_header_from_raw_library(
     Ok(header)
 }
 
+/// Gets the LibHeader of the root module exported with
+/// `#[export_root_module(name = "...")]` in a library.
+///
+/// # Errors
+///
+/// This will return these errors:
+///
+/// - `LibraryError::GetSymbolError`:
+/// If no root module with that name was exported.
+///
+/// - `LibraryError::InvalidAbiHeader`:
+/// If the abi_stable used by the library is not compatible.
+///
+/// # Panics
+///
+/// Panics if `name` is empty or contains characters other than
+/// ASCII alphanumerics and underscores.
+///
+/// # Safety
+///
+/// The LibHeader is implicitly tied to the lifetime of the library,
+/// it will contain dangling `'static` references if the library is dropped before it does.
+///
+pub unsafe fn named_lib_header_from_raw_library(
+    raw_library: &RawLibrary,
+    name: &str,
+) -> Result<&'static LibHeader, LibraryError> {
+    let mut mangled = abi_stable_shared::mangled_named_root_module_loader_name(name);
+    mangled.push('\0');
+    let header: AbiHeaderRef = unsafe { *raw_library.get::<AbiHeaderRef>(mangled.as_bytes())? };
+
+    header.upgrade()
+}
+
 /// Gets the LibHeader of the library at the path.
 ///
 /// This leaks the underlying dynamic library,
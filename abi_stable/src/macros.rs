@@ -191,6 +191,13 @@ macro_rules! check_unerased {
 /// `extern_fn_panic_handling!{no_early_return; <code here> }`,
 /// which *might* be cheaper(this has not been tested yet).
 ///
+/// # Logging panics
+///
+/// `extern_fn_panic_handling!{log_panic; <code here> }`
+/// passes the message of the panic to the logger registered with
+/// [`set_panic_logger`](crate::utils::set_panic_logger) before aborting,
+/// allowing early returns the same as the default mode.
+///
 /// # Example
 ///
 /// ```
@@ -279,6 +286,26 @@ macro_rules! extern_fn_panic_handling {
 
         res
     });
+    (log_panic; $($fn_contents:tt)* ) => ({
+        $crate::extern_fn_panic_handling!{
+            no_early_return;
+            let a = $crate::marker_type::NotCopyNotClone;
+            let res = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(move||{
+                {a};
+                {
+                    $($fn_contents)*
+                }
+            }));
+            match res {
+                Ok(x) => x,
+                Err(payload) => {
+                    use $crate::utils::{log_panic_and_abort, PanicInfo};
+                    const INFO: &PanicInfo = &PanicInfo{file:file!(),line:line!()};
+                    log_panic_and_abort(payload, INFO)
+                }
+            }
+        }
+    });
     ( $($fn_contents:tt)* ) => (
         #[allow(clippy::redundant_closure_call)]
         {
@@ -397,6 +397,60 @@ where
         }
     }
 
+    /// Converts the wrapped enum with `f` into another nonexhaustive enum,
+    /// if it's of a variant that is valid in this context.
+    ///
+    /// # Errors
+    ///
+    /// This returns `self` back, without calling `f`,
+    /// if the wrapped enum is of a variant that is not valid in this context.
+    ///
+    /// # Panic
+    ///
+    /// This panics if the storage `S2` has an alignment or size smaller than that of `U`.
+    ///
+    /// # Example
+    ///
+    /// This shows how some `NonExhaustive<enum>` can be converted, and others cannot.<br>
+    /// That enum comes from a newer version of the library than this knows.
+    ///
+    /// ```
+    /// use abi_stable::nonexhaustive_enum::{
+    ///     doc_enums::example_2::{new_a, new_b, new_c, Foo},
+    ///     NonExhaustiveFor,
+    /// };
+    ///
+    /// fn increment(x: Foo) -> Foo {
+    ///     match x {
+    ///         Foo::B(n) => Foo::B(n + 1),
+    ///         x => x,
+    ///     }
+    /// }
+    ///
+    /// let mapped: NonExhaustiveFor<Foo> = new_b(10).map_known(increment).unwrap();
+    /// assert_eq!(mapped, Foo::B(11));
+    ///
+    /// let mapped: Result<NonExhaustiveFor<Foo>, _> = new_a().map_known(increment);
+    /// assert_eq!(mapped.unwrap(), Foo::A);
+    ///
+    /// // `new_c()` returns a variant that is unknown to this version of `Foo`,
+    /// // so it's returned unchanged.
+    /// let unknown = new_c();
+    /// let mapped: Result<NonExhaustiveFor<Foo>, _> = unknown.clone().map_known(increment);
+    /// assert_eq!(mapped.unwrap_err(), unknown);
+    ///
+    /// ```
+    pub fn map_known<U, S2, I2, F>(self, f: F) -> Result<NonExhaustive<U, S2, I2>, Self>
+    where
+        U: GetVTable<S2, I2>,
+        F: FnOnce(E) -> U,
+    {
+        match self.into_enum() {
+            Ok(x) => Ok(NonExhaustive::with_storage_and_interface(f(x))),
+            Err(e) => Err(e.into_inner()),
+        }
+    }
+
     /// Returns whether the discriminant of this enum is valid in this context.
     ///
     /// The only way for it to be invalid is if the dynamic library is a
@@ -254,6 +254,45 @@ fn transmuting_enums() {
     }
 }
 
+#[test]
+fn map_known_test() {
+    use self::{command_a::Foo as FooA, command_b::Foo as FooB, command_c::Foo as FooC};
+
+    fn to_b(x: FooA) -> FooB {
+        match x {
+            FooA::A => FooB::C,
+            FooA::B(n) => FooB::B(n * 2),
+        }
+    }
+
+    type NeB = NonExhaustiveFor<FooB>;
+
+    unsafe {
+        let variant_a = NonExhaustive::new(FooC::A).transmute_enum::<FooA>();
+        let variant_b = NonExhaustive::new(FooC::B(11)).transmute_enum::<FooA>();
+        let variant_d = FooC::D {
+            name: "what".into(),
+        }
+        .piped(NonExhaustive::new)
+        .transmute_enum::<FooA>();
+
+        assert_eq!(variant_a.map_known(to_b), Ok(NeB::new(FooB::C)));
+        assert_eq!(variant_b.map_known(to_b), Ok(NeB::new(FooB::B(22))));
+
+        let unknown = variant_d.clone();
+        let mapped: Result<NeB, _> = variant_d.map_known(|_| panic!("unknown variant"));
+        let mapped = mapped.unwrap_err();
+        assert_eq!(mapped.is_valid_discriminant(), false);
+        assert_eq!(mapped, unknown);
+        assert_eq!(
+            mapped.transmute_enum::<FooC>().into_enum(),
+            Ok(FooC::D {
+                name: "what".into()
+            })
+        );
+    }
+}
+
 #[test]
 fn clone_test() {
     use self::generic_a::Foo;
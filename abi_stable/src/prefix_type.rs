@@ -14,6 +14,7 @@ use repr_offset::offset_calc::next_field_offset;
 
 mod accessible_fields;
 mod layout;
+mod missing_field;
 mod prefix_ref;
 mod pt_metadata;
 
@@ -23,6 +24,7 @@ mod tests;
 pub use self::{
     accessible_fields::{FieldAccessibility, FieldConditionality, IsAccessible, IsConditional},
     layout::PTStructLayout,
+    missing_field::MissingFieldError,
     prefix_ref::PrefixRef,
 };
 
@@ -365,32 +367,7 @@ fn panic_on_missing_field_val(
     actual: &'static PTStructLayout,
 ) -> ! {
     panic!(
-        "\n
-Attempting to access nonexistent field:
-    index:{index} 
-    named:{field_named}
-
-Inside of:{struct_name}{struct_generics}
-
-Package:'{package}' 
-
-Expected:
-    Version:{expected_package_version} (or compatible version number)
-    Field count:{expected_field_count}
-
-Found:
-    Version:{actual_package_version}
-    Field count:{actual_field_count}
-
-\n",
-        index = field_index,
-        field_named = field_name,
-        struct_name = expected.mono_layout.name(),
-        struct_generics = expected.generics.as_str(),
-        package = expected.mono_layout.item_info().package(),
-        expected_package_version = expected.mono_layout.item_info().version(),
-        expected_field_count = expected.get_field_names().count(),
-        actual_package_version = actual.mono_layout.item_info().version(),
-        actual_field_count = actual.get_field_names().count(),
+        "{}",
+        MissingFieldError::with_field_name(field_index, field_name, expected, actual)
     );
 }
@@ -0,0 +1,143 @@
+use super::{PTStructLayout, PrefixTypeTrait};
+
+use crate::std_types::RStr;
+
+use std::{
+    error::Error as ErrorTrait,
+    fmt::{self, Display},
+};
+
+/// The error returned by the accessors of prefix types that use the
+/// `#[sabi(missing_field(result))]` [`StableAbi`](derive@crate::StableAbi)
+/// attribute, when the field is not in the loaded prefix type.
+///
+/// # Example
+///
+/// ```rust
+/// use abi_stable::{
+///     prefix_type::{MissingFieldError, PrefixTypeTrait, WithMetadata},
+///     std_types::{RErr, ROk},
+///     StableAbi,
+/// };
+///
+/// #[repr(C)]
+/// #[derive(StableAbi)]
+/// #[sabi(kind(Prefix))]
+/// #[sabi(missing_field(result))]
+/// pub struct Module {
+///     #[sabi(last_prefix_field)]
+///     pub first: u32,
+///     pub second: u64,
+/// }
+///
+/// const MOD: Module_Ref = Module_Ref(
+///     WithMetadata::new(Module { first: 3, second: 5 }).static_as_prefix(),
+/// );
+///
+/// assert_eq!(MOD.first(), 3);
+/// assert_eq!(MOD.second().unwrap(), 5);
+///
+/// // If `MOD` was loaded from an older version of the library
+/// // which didn't have the `second` field,
+/// // `MOD.second()` would return an `RErr(MissingFieldError {..})`
+///
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone, StableAbi)]
+pub struct MissingFieldError {
+    field_index: usize,
+    field_name: RStr<'static>,
+    expected_layout: &'static PTStructLayout,
+    actual_layout: &'static PTStructLayout,
+}
+
+impl MissingFieldError {
+    /// Constructs a `MissingFieldError` for the `field_index`th field of `T`,
+    /// where `actual_layout` is the layout of the prefix type that was found.
+    #[cold]
+    #[inline(never)]
+    pub fn new<T>(field_index: usize, actual_layout: &'static PTStructLayout) -> Self
+    where
+        T: PrefixTypeTrait,
+    {
+        let expected_layout = T::PT_LAYOUT;
+        let field_name = expected_layout
+            .get_field_name(field_index)
+            .unwrap_or("<unavailable>");
+
+        Self::with_field_name(field_index, field_name, expected_layout, actual_layout)
+    }
+
+    pub(super) const fn with_field_name(
+        field_index: usize,
+        field_name: &'static str,
+        expected_layout: &'static PTStructLayout,
+        actual_layout: &'static PTStructLayout,
+    ) -> Self {
+        Self {
+            field_index,
+            field_name: RStr::from_str(field_name),
+            expected_layout,
+            actual_layout,
+        }
+    }
+
+    /// The index of the missing field.
+    pub const fn field_index(&self) -> usize {
+        self.field_index
+    }
+
+    /// The name of the missing field.
+    pub const fn field_name(&self) -> RStr<'static> {
+        self.field_name
+    }
+
+    /// The layout of the prefix type that has the field.
+    pub const fn expected_layout(&self) -> &'static PTStructLayout {
+        self.expected_layout
+    }
+
+    /// The layout of the prefix type that was found, which doesn't have the field.
+    pub const fn actual_layout(&self) -> &'static PTStructLayout {
+        self.actual_layout
+    }
+}
+
+impl Display for MissingFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected = self.expected_layout;
+        let actual = self.actual_layout;
+        write!(
+            f,
+            "\n
+Attempting to access nonexistent field:
+    index:{index} 
+    named:{field_named}
+
+Inside of:{struct_name}{struct_generics}
+
+Package:'{package}' 
+
+Expected:
+    Version:{expected_package_version} (or compatible version number)
+    Field count:{expected_field_count}
+
+Found:
+    Version:{actual_package_version}
+    Field count:{actual_field_count}
+
+\n",
+            index = self.field_index,
+            field_named = self.field_name,
+            struct_name = expected.mono_layout.name(),
+            struct_generics = expected.generics.as_str(),
+            package = expected.mono_layout.item_info().package(),
+            expected_package_version = expected.mono_layout.item_info().version(),
+            expected_field_count = expected.get_field_names().count(),
+            actual_package_version = actual.mono_layout.item_info().version(),
+            actual_field_count = actual.get_field_names().count(),
+        )
+    }
+}
+
+impl ErrorTrait for MissingFieldError {}
@@ -51,6 +51,11 @@ The return type of the annotated function can be one of:
 All those types are supported through the [`IntoRootModuleResult`] trait,
 which you can implement if you want to return some other type.
 
+When the function returns an error, loading the root module fails with a
+[`LibraryError::RootModule`] error, whose `err` field is a
+`RootModuleError::Returned` with the returned error,
+retrievable with the [`RootModuleError::error`] method.
+
 # Generated code
 
 Exporting the root module creates a 
@@ -87,6 +92,51 @@ This attribute should not be used unconditionally,
 it should be disabled in Continuous Integration so that the 
 binary compatibility of a dynamic library is checked at some point before releasing it.
 
+# Named root modules
+
+A dynamic library can export multiple independent root modules by giving each one a name,
+with the `#[export_root_module(name = "...")]` syntax,
+the name must be made of ASCII alphanumeric characters and underscores.
+
+Named root modules are loaded with the `RootModule::load_named_from*` associated functions
+(eg: [`RootModule::load_named_from_directory`]),
+passing the same name that was used in the attribute.
+
+```rust
+use abi_stable::{export_root_module, prefix_type::PrefixTypeTrait};
+
+#[export_root_module(name = "adder")]
+pub fn get_adder() -> AdderMod_Ref {
+    AdderMod { add }.leak_into_prefix()
+}
+
+extern "C" fn add(l: u32, r: u32) -> u32 {
+    l + r
+}
+
+// Loaded with `AdderMod_Ref::load_named_from_directory(directory, "adder")`
+
+# #[repr(C)]
+# #[derive(abi_stable::StableAbi)]
+# #[sabi(kind(Prefix(prefix_ref= AdderMod_Ref)))]
+# #[sabi(missing_field(panic))]
+# pub struct AdderMod {
+#     #[sabi(last_prefix_field)]
+#     pub add: extern "C" fn(u32, u32) -> u32,
+# }
+#
+# impl abi_stable::library::RootModule for AdderMod_Ref {
+#     abi_stable::declare_root_module_statics!{AdderMod_Ref}
+#     const BASE_NAME: &'static str = "stuff";
+#     const NAME: &'static str = "stuff";
+#     const VERSION_STRINGS: abi_stable::sabi_types::VersionStrings =
+#           abi_stable::package_version_strings!();
+# }
+#
+# fn main(){}
+
+```
+
 # More examples
 
 For a more detailed example look in the README in the repository for this crate.
@@ -96,6 +146,10 @@ For a more detailed example look in the README in the repository for this crate.
 [`IntoRootModuleResult`]: ./library/trait.IntoRootModuleResult.html
 [`LateStaticRef`]: ./sabi_types/struct.LateStaticRef.html
 [`LibHeader`]: ./library/struct.LibHeader.html
+[`LibraryError::RootModule`]: ./library/enum.LibraryError.html#variant.RootModule
+[`RootModuleError::error`]: ./library/enum.RootModuleError.html#method.error
+[`RootModule::load_named_from_directory`]:
+./library/trait.RootModule.html#method.load_named_from_directory
 
 */
 #[doc(inline)]
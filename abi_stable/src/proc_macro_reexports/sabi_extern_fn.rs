@@ -94,6 +94,41 @@ assert_eq!(hello(), vec!["hello".into_c(), "world".into()].into_c(),);
 ```
 
 
+# log_panic
+
+You can use `#[sabi_extern_fn(log_panic)]` to pass the message of panics
+to the logger registered with [`set_panic_logger`](crate::utils::set_panic_logger)
+before the process is aborted.
+
+This variant of the attribute catches the panic with [`std::panic::catch_unwind`],
+and supports early returns the same as the attribute without parameters.
+
+### Example
+
+```rust
+use abi_stable::{sabi_extern_fn, std_types::RStr, utils::set_panic_logger};
+
+extern "C" fn log_panic(message: RStr<'_>) {
+    eprintln!("error: {}", message);
+}
+
+#[sabi_extern_fn(log_panic)]
+pub fn parse_number(text: RStr<'_>) -> u32 {
+    match text.parse::<u32>() {
+        Ok(x) => return x,
+        Err(e) => panic!("could not parse {:?}: {}", text, e),
+    }
+}
+
+set_panic_logger(Some(log_panic));
+
+assert_eq!(parse_number("100".into()), 100);
+
+// This would call `log_panic` with the panic message, then abort the process
+// parse_number("hello".into());
+
+```
+
 */
 #[doc(inline)]
 pub use abi_stable_derive::sabi_extern_fn;
\ No newline at end of file
@@ -220,6 +220,17 @@ you can still call the inherent versions of those methods on the trait object.
 This is useful to reduce compile-time overhead,
 and to allow users to declare a blanket(generic) implementation of the trait.
 
+This is the only generated item that can be omitted,
+the other items are always generated because the trait object depends on them:
+
+- The trait itself, which is implemented by the types the trait object is constructed from.
+
+- `Trait_TO`, with its constructors and inherent method equivalents of the trait methods.
+
+- The vtable, which is constructed from the trait impl of the erased type.
+
+- `Trait_CTO`, which is only a type alias.
+
 ###  `#[sabi(no_default_fallback)]`
 
 Stops using default implementation of methods (from the trait declaration) 
@@ -249,17 +260,80 @@ The only valid way to refer to associated types in the trait declaration is with
 Associated types in the trait object are transformed into type parameters 
 that come after those of the trait.
 
+# Associated constants
+
+Traits with associated constants require the `#[sabi(no_trait_impl)]` attribute,
+since the trait object can't implement the trait with a constant that
+depends on the type it was constructed from.
+
+Each associated constant is stored in the vtable as a function that returns its value,
+and can be read with an inherent method on the trait object,
+named like the lowercased constant (eg: `KIND` is read with `Trait_TO::kind`).
+Since the function returns a new value on every call,
+constants of non-`Copy` types are supported.
+
+The vtable stores constants and methods in declaration order,
+so for [extensibility](#extensibility) purposes associated constants
+count as methods: they can only be added at the end of the trait.
+
+The type of associated constants can't mention `Self`,
+and the getter can't have the same name as a method of the trait
+or an inherent method of the trait object (eg: `FROM_PTR`).
+
+```rust
+use abi_stable::{
+    sabi_trait,
+    sabi_trait::TD_Opaque,
+    std_types::RStr,
+};
+
+#[sabi_trait]
+#[sabi(no_trait_impl)]
+pub trait Animal {
+    const KIND: u32;
+
+    const NAME: RStr<'static>;
+
+    fn legs(&self) -> u32;
+}
+
+struct Dog;
+
+impl Animal for Dog {
+    const KIND: u32 = 1;
+
+    const NAME: RStr<'static> = RStr::from_str("dog");
+
+    fn legs(&self) -> u32 {
+        4
+    }
+}
+
+# fn main() {
+let animal = Animal_TO::from_value(Dog, TD_Opaque);
+assert_eq!(animal.kind(), 1);
+assert_eq!(animal.name(), "dog");
+assert_eq!(animal.legs(), 4);
+# }
+```
+
 # Object safety
 
 Trait objects generated using this attribute have similar restrictions to built-in trait objects: 
 
 - `Self` can only be used to access associated types 
-    (using the `Self::AssocType` syntax).
+    (using the `Self::AssocType` syntax),
+    or as the return type of a method taking `self` by value.
 
 - `self` is a valid method receiver,
     this requires that the pointer that the generated trait object wraps 
     implements `abi_stable::pointer_trait::OwnedPointer`.
 
+- Methods taking `self` by value can return `Self`, 
+    in the trait object this replaces the value behind the pointer
+    with the one returned by the method, returning the same trait object.
+    This allows builder-style methods to be chained on trait objects.
+
 # Questions and Answers
 
 **Question: ** Why does Calling from_ptr/from_value give me a expected a `'static` value error?
@@ -464,6 +538,66 @@ assert!(!IS_CARD.contains(&'B'));
 
 ```
 
+### Const generic trait
+
+This shows a trait with a const parameter,
+each instantiation of the trait has its own trait object type (and vtable).
+
+```rust
+use abi_stable::{
+    sabi_trait,
+    sabi_trait::TD_Opaque,
+    std_types::RBox,
+};
+
+#[sabi_trait]
+pub trait Buffer<const N: usize> {
+    fn get(&self, index: usize) -> u8;
+
+    fn set(&mut self, index: usize, value: u8);
+
+    fn to_array(&self) -> [u8; N];
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+struct ArrayBuffer<const N: usize>([u8; N]);
+
+impl<const N: usize> Buffer<N> for ArrayBuffer<N> {
+    fn get(&self, index: usize) -> u8 {
+        self.0[index]
+    }
+    fn set(&mut self, index: usize, value: u8) {
+        self.0[index] = value;
+    }
+    fn to_array(&self) -> [u8; N] {
+        self.0
+    }
+}
+
+# fn main() {
+let mut small: Buffer_TO<'static, RBox<()>, 16> =
+    Buffer_TO::from_value(ArrayBuffer([0; 16]), TD_Opaque);
+
+let mut large: Buffer_TO<'static, RBox<()>, 32> =
+    Buffer_TO::from_value(ArrayBuffer([0; 32]), TD_Opaque);
+
+small.set(15, 3);
+large.set(31, 5);
+
+assert_eq!(small.get(15), 3);
+assert_eq!(small.capacity(), 16);
+assert_eq!(small.to_array()[15], 3);
+
+assert_eq!(large.get(31), 5);
+assert_eq!(large.capacity(), 32);
+assert_eq!(large.to_array()[31], 5);
+# }
+
+```
+
 ### Cloning an `RArc`-using trait object.
 
 Because of a quirk of how `#[sabi_trait]` trait objects work,
@@ -26,6 +26,14 @@ for compatibility.
 Adds a virtual type parameter to the type layout constant,
 which is checked for compatibility.
 
+###  `#[sabi(phantom_lifetime = lifetime)]` 
+
+Adds a virtual field of type `&'lifetime ()` to the type layout constant,
+recording that the type uses that lifetime parameter
+even if no field mentions it in a way that the derive macro can see.
+
+The lifetime must be either `'static`, or a lifetime parameter of the type.
+
 ###  `#[sabi(phantom_const_param = constant expression)]` 
 
 Adds a virtual const parameter to the type layout constant,
@@ -272,9 +280,17 @@ This has the `unsafe` prefix because SomeType is relied on being correct by
 Does not require the field to implement [`StableAbi`],
 and instead uses the StableAbi impl of [`UnsafeOpaqueField`]`<FieldType>`.
 
+Only the size and alignment of the field are checked when loading the library,
+so the type of the field can change its structure between versions.
+
 This is unsafe because the layout of the type won't be verified when loading the library,
 which causes Undefined Behavior if the type has a different layout.
 
+This can be used to wrap a type that doesn't implement [`StableAbi`]
+(eg: a type from another crate whose layout you've verified) in a
+`#[repr(transparent)]` newtype, whose layout is then checked as
+opaque bytes with the size and alignment of the wrapped type.
+
 ###  `#[sabi(unsafe_sabi_opaque_field)]`
 
 Requires the field to implement [`StableAbi`] (unless overridden),
@@ -370,6 +386,10 @@ Panics if the field doesn't exist, with an informative error message.
 Returns None if the field doesn't exist, Some(fieldvalue) if it does.
 This is the default.
 
+`#[sabi(missing_field(result))]`<br>
+Returns `RErr(`[`MissingFieldError`](crate::prefix_type::MissingFieldError)`)`
+if the field doesn't exist, `ROk(fieldvalue)` if it does.
+
 `#[sabi(missing_field(with = somefunction))]`<br>
 Returns `somefunction()` if the field doesn't exist.
 
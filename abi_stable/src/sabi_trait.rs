@@ -16,6 +16,7 @@ pub mod reexports {
                 DynTrait, MakeVTable as MakeDynTraitVTable, VTable_Ref as DynTraitVTable_Ref,
             },
             extern_fn_panic_handling,
+            inline_storage::InlineStorage,
             marker_type::{
                 NonOwningPhantom, SyncSend, SyncUnsend, UnsafeIgnoredType, UnsyncSend, UnsyncUnsend,
             },
@@ -25,7 +26,7 @@ pub mod reexports {
                 robject::RObject,
                 vtable::{GetRObjectVTable, RObjectVtable, RObjectVtable_Ref},
             },
-            sabi_types::{MovePtr, RMut, RRef},
+            sabi_types::{MovePtr, RMut, RRef, RSmallBox},
             std_types::RBox,
             traits::IntoInner,
             utils::take_manuallydrop,
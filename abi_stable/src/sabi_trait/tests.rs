@@ -2,7 +2,7 @@ use std::mem;
 
 use crate::{
     sabi_trait::prelude::*,
-    std_types::{RBox, RStr},
+    std_types::{RBox, RNone, ROption, RSome, RStr, RString, RVec},
     type_level::bools::*,
     *,
 };
@@ -193,6 +193,77 @@ fn default_methods() {
     assert_eq!(c.foo(1), 331);
 }
 
+/*////////////////////////////////////////////////////////////////////////////////
+Test that default methods can call other methods through the vtable,
+including when the default method is missing from the vtable.
+*/////////////////////////////////////////////////////////////////////////////////
+
+mod named_v0 {
+    use super::*;
+
+    #[sabi_trait]
+    pub trait Named {
+        #[sabi(last_prefix_field)]
+        fn name(&self) -> RString;
+    }
+
+    impl Named for RString {
+        fn name(&self) -> RString {
+            self.clone()
+        }
+    }
+}
+
+mod named_v1 {
+    use super::*;
+
+    #[sabi_trait]
+    pub trait Named {
+        #[sabi(last_prefix_field)]
+        fn name(&self) -> RString;
+
+        fn describe(&self) -> RString {
+            format!("named {}", self.name()).into()
+        }
+    }
+
+    impl Named for RString {
+        fn name(&self) -> RString {
+            self.clone()
+        }
+    }
+
+    impl Named for u32 {
+        fn name(&self) -> RString {
+            self.to_string().into()
+        }
+
+        fn describe(&self) -> RString {
+            format!("number {}", self).into()
+        }
+    }
+}
+
+#[test]
+fn default_method_calls_vtable_method() {
+    use self::named_v1::Named_TO;
+
+    let string = Named_TO::from_value(RString::from("foo"), TD_Opaque);
+    assert_eq!(string.describe(), "named foo");
+
+    let number = Named_TO::from_value(3u32, TD_Opaque);
+    assert_eq!(number.describe(), "number 3");
+
+    unsafe {
+        // Emulating an object constructed by a library
+        // that uses a version of the trait without `describe`.
+        let v0 = named_v0::Named_TO::from_value(RString::from("bar"), TD_Opaque);
+        let object = mem::transmute::<_, Named_TO<'_, RBox<()>>>(v0);
+        assert_eq!(object.name(), "bar");
+        assert_eq!(object.describe(), "named bar");
+    }
+}
+
 /*////////////////////////////////////////////////////////////////////////////////
 Test that #[sabi(no_trait_impl)] disables the trait impl for the trait object.
 */////////////////////////////////////////////////////////////////////////////////
@@ -209,6 +280,47 @@ trait NoTraitImplB {}
 
 impl<This: ?Sized> NoTraitImplB for This {}
 
+#[sabi_trait]
+#[sabi(no_trait_impl)]
+trait NoTraitImplC {
+    fn get(&self) -> u32;
+
+    fn set(&mut self, value: u32);
+
+    fn into_inner(self) -> u32;
+}
+
+impl NoTraitImplC for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn set(&mut self, value: u32) {
+        *self = value;
+    }
+
+    fn into_inner(self) -> u32 {
+        self
+    }
+}
+
+#[test]
+fn no_trait_impl_object_works() {
+    let mut object = NoTraitImplC_TO::from_value(3u32, TD_CanDowncast);
+    assert_eq!(object.get(), 3);
+
+    object.set(5);
+    assert_eq!(object.get(), 5);
+    assert_eq!(object.sabi_reborrow().get(), 5);
+
+    object.sabi_reborrow_mut().set(8);
+    assert_eq!(object.obj.downcast_as::<u32>().ok(), Some(&8));
+    assert_eq!(object.into_inner(), 8);
+
+    let object = NoTraitImplC_TO::from_ptr(RBox::new(13u32), TD_Opaque);
+    assert_eq!(object.into_inner(), 13);
+}
+
 /*////////////////////////////////////////////////////////////////////////////////
 Test that prefix methods can have a default impl.
 */////////////////////////////////////////////////////////////////////////////////
@@ -294,6 +406,33 @@ fn borrow_kinds() {
     assert_eq!(obj.not_borrow(), 89);
 }
 
+#[sabi_trait]
+trait HasName {
+    fn name(&self) -> RStr<'_>;
+}
+
+impl HasName for RString {
+    fn name(&self) -> RStr<'_> {
+        self.as_rstr()
+    }
+}
+
+#[test]
+fn borrows_object_data() {
+    let string = RString::from("hello world");
+    let address = string.as_ptr();
+
+    let obj = HasName_TO::from_value(string, TD_Opaque);
+    let name = obj.name();
+    assert_eq!(name.as_ptr(), address);
+    assert_eq!(name, "hello world");
+
+    let borrowed = obj.sabi_reborrow();
+    let name_b = borrowed.name();
+    assert_eq!(name_b.as_ptr(), address);
+    assert_eq!(name, name_b);
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 mod has_docs {
@@ -333,3 +472,372 @@ fn docs_are_included_test() {
         has_docs::TOKENS
     );
 }
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that trait objects can share ownership of the value through an `RArc`.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+trait SharedCounter: Clone + Send + Sync {
+    fn increment(&self) -> u32;
+
+    fn get(&self) -> u32;
+}
+
+struct Counter(std::sync::atomic::AtomicU32);
+
+/// Cloning an `RArc`-backed trait object only clones the `RArc`.
+impl Clone for Counter {
+    fn clone(&self) -> Self {
+        panic!("the Counter itself must not be cloned")
+    }
+}
+
+impl SharedCounter for Counter {
+    fn increment(&self) -> u32 {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
+    }
+
+    fn get(&self) -> u32 {
+        self.0.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+#[test]
+fn rarc_shared_object() {
+    use crate::std_types::RArc;
+    use std::sync::atomic::AtomicU32;
+
+    let arc = RArc::new(Counter(AtomicU32::new(10)));
+
+    let first: SharedCounter_TO<'_, RArc<()>> =
+        SharedCounter_TO::from_ptr(arc.clone(), TD_CanDowncast);
+    assert_eq!(RArc::strong_count(&arc), 2);
+
+    let second = first.clone();
+    assert_eq!(RArc::strong_count(&arc), 3);
+
+    assert_eq!(first.increment(), 11);
+    assert_eq!(second.increment(), 12);
+    assert_eq!(first.get(), 12);
+    assert_eq!(second.get(), 12);
+
+    std::thread::spawn(move || second.increment())
+        .join()
+        .unwrap();
+    assert_eq!(first.get(), 13);
+    assert_eq!(RArc::strong_count(&arc), 2);
+
+    let unerased = first.obj.downcast_into::<Counter>().unwrap();
+    assert!(std::ptr::eq(&*unerased, &*arc));
+    drop(unerased);
+    assert_eq!(RArc::strong_count(&arc), 1);
+}
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that `Send`/`Sync` supertraits make the trait object `Send`/`Sync`.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+trait SendJob: Send {
+    fn run(&mut self) -> RString;
+}
+
+impl SendJob for std::cell::Cell<u32> {
+    fn run(&mut self) -> RString {
+        self.set(self.get() * 2);
+        self.get().to_string().into()
+    }
+}
+
+#[sabi_trait]
+trait SyncSendJob: Send + Sync {
+    fn run(&self) -> u32;
+}
+
+impl SyncSendJob for u32 {
+    fn run(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[test]
+fn send_sync_objects() {
+    fn assert_send<T: Send>(_: &T) {}
+    fn assert_sync<T: Sync>(_: &T) {}
+
+    let mut job = SendJob_TO::from_value(std::cell::Cell::new(3), TD_Opaque);
+    assert_send(&job);
+    assert_eq!(job.run(), "6");
+    let mut job = std::thread::spawn(move || {
+        assert_eq!(job.run(), "12");
+        job
+    })
+    .join()
+    .unwrap();
+    assert_eq!(job.run(), "24");
+
+    let job = SyncSendJob_TO::from_value(5u32, TD_Opaque);
+    assert_send(&job);
+    assert_sync(&job);
+    assert_eq!(std::thread::spawn(move || job.run()).join().unwrap(), 6);
+}
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that associated constants are stored in the vtable.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+#[sabi(no_trait_impl)]
+trait Shape {
+    const KIND: u32;
+
+    const NAME: RStr<'static>;
+
+    /// A non-`Copy` constant with a default value.
+    const LABEL: ROption<RString> = RNone;
+
+    fn sides(&self) -> u32;
+}
+
+struct Triangle;
+
+impl Shape for Triangle {
+    const KIND: u32 = 3;
+
+    const NAME: RStr<'static> = RStr::from_str("triangle");
+
+    const LABEL: ROption<RString> = RSome(RString::new());
+
+    fn sides(&self) -> u32 {
+        3
+    }
+}
+
+impl Shape for () {
+    const KIND: u32 = 0;
+
+    const NAME: RStr<'static> = RStr::from_str("unit");
+
+    fn sides(&self) -> u32 {
+        0
+    }
+}
+
+#[test]
+fn assoc_consts() {
+    let triangle = Shape_TO::from_value(Triangle, TD_Opaque);
+    assert_eq!(triangle.kind(), 3);
+    assert_eq!(triangle.name(), "triangle");
+    assert_eq!(triangle.label(), RSome(RString::new()));
+    assert_eq!(triangle.sides(), 3);
+
+    let unit = Shape_TO::from_ptr(RBox::new(()), TD_Opaque);
+    assert_eq!(unit.kind(), 0);
+    assert_eq!(unit.name(), "unit");
+    assert_eq!(unit.label(), RNone);
+    assert_eq!(unit.sides(), 0);
+}
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that small values can be stored inline in the trait object.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+trait Plugin {
+    fn name(&self) -> RStr<'static>;
+
+    fn run(&mut self, input: u32) -> u32;
+}
+
+#[derive(Debug, PartialEq)]
+struct Doubler;
+
+impl Plugin for Doubler {
+    fn name(&self) -> RStr<'static> {
+        RStr::from_str("doubler")
+    }
+
+    fn run(&mut self, input: u32) -> u32 {
+        input * 2
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Accumulator([u64; 4]);
+
+impl Plugin for Accumulator {
+    fn name(&self) -> RStr<'static> {
+        RStr::from_str("accumulator")
+    }
+
+    fn run(&mut self, input: u32) -> u32 {
+        self.0[0] += u64::from(input);
+        self.0[0] as u32
+    }
+}
+
+#[test]
+fn small_value_objects() {
+    use crate::sabi_types::RSmallBox;
+
+    type SmallPlugin = Plugin_TO<'static, RSmallBox<(), [usize; 2]>>;
+
+    fn is_stored_inline(object: &SmallPlugin) -> bool {
+        let start = object as *const SmallPlugin as usize;
+        let address = object.obj.sabi_erased_ref().as_ptr() as usize;
+        (start..start + mem::size_of::<SmallPlugin>()).contains(&address)
+    }
+
+    let mut doubler: SmallPlugin = Plugin_TO::from_small_value(Doubler, TD_CanDowncast);
+    assert!(is_stored_inline(&doubler));
+    assert_eq!(doubler.name(), "doubler");
+    assert_eq!(doubler.run(3), 6);
+
+    let unerased = doubler.obj.downcast_into::<Doubler>().unwrap();
+    assert!(RSmallBox::is_inline(&unerased));
+    assert_eq!(RSmallBox::into_inner(unerased), Doubler);
+
+    // Too large to be stored inline, so it's heap allocated.
+    let mut accum: SmallPlugin = Plugin_TO::from_small_value(Accumulator([0; 4]), TD_Opaque);
+    assert!(!is_stored_inline(&accum));
+    assert_eq!(accum.name(), "accumulator");
+    assert_eq!(accum.run(3), 3);
+    assert_eq!(accum.run(5), 8);
+}
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that the trait object implements the trait,
+so that it can be passed to functions generic over the trait.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+trait Summer {
+    type Output;
+
+    fn add(&mut self, n: u32);
+
+    fn finish(self) -> Self::Output;
+}
+
+#[derive(Debug, PartialEq)]
+struct Sum(u64);
+
+impl Summer for Sum {
+    type Output = RString;
+
+    fn add(&mut self, n: u32) {
+        self.0 += u64::from(n);
+    }
+
+    fn finish(self) -> RString {
+        self.0.to_string().into()
+    }
+}
+
+#[test]
+fn trait_object_implements_trait() {
+    fn use_plugin<P: Plugin>(plugin: &mut P) -> (RStr<'static>, u32) {
+        (plugin.name(), plugin.run(10))
+    }
+
+    fn sum_all<S: Summer>(mut summer: S, list: &[u32]) -> S::Output {
+        for &n in list {
+            summer.add(n);
+        }
+        summer.finish()
+    }
+
+    let mut doubler = Plugin_TO::from_value(Doubler, TD_Opaque);
+    assert_eq!(use_plugin(&mut doubler), ("doubler".into(), 20));
+
+    let mut accum = Plugin_TO::from_ptr(RBox::new(Accumulator([5, 0, 0, 0])), TD_Opaque);
+    assert_eq!(use_plugin(&mut accum), ("accumulator".into(), 15));
+    assert_eq!(use_plugin(&mut accum), ("accumulator".into(), 25));
+
+    let summer: Summer_TO<'static, RBox<()>, RString> = Summer_TO::from_value(Sum(1), TD_Opaque);
+    assert_eq!(sum_all(summer, &[2, 3, 4]), "10");
+}
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test by-value methods that return `Self`,
+which replace the value behind the pointer of the trait object.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq, StableAbi)]
+struct Config {
+    name: RString,
+    retries: u32,
+}
+
+#[sabi_trait]
+trait Builder {
+    fn with_config(self, config: Config) -> Self;
+
+    fn with_retries(self, retries: u32) -> Self;
+
+    fn with_default_retries(self) -> Self {
+        self.with_retries(1)
+    }
+
+    fn configs(&self) -> RVec<Config>;
+}
+
+#[derive(Debug, Clone, Default)]
+struct ConfigList(RVec<Config>);
+
+impl Builder for ConfigList {
+    fn with_config(mut self, config: Config) -> Self {
+        self.0.push(config);
+        self
+    }
+
+    fn with_retries(mut self, retries: u32) -> Self {
+        for config in &mut self.0 {
+            config.retries = retries;
+        }
+        self
+    }
+
+    fn configs(&self) -> RVec<Config> {
+        self.0.clone()
+    }
+}
+
+#[test]
+fn by_value_method_returning_self() {
+    fn config(name: &str, retries: u32) -> Config {
+        Config {
+            name: name.into(),
+            retries,
+        }
+    }
+
+    let builder = Builder_TO::from_value(ConfigList::default(), TD_CanDowncast);
+    let address = builder.obj.sabi_erased_ref().as_ptr() as usize;
+
+    let builder = builder
+        .with_config(config("foo", 0))
+        .with_config(config("bar", 1));
+    assert_eq!(builder.configs(), rvec![config("foo", 0), config("bar", 1)]);
+
+    let builder = builder.with_retries(3);
+    assert_eq!(builder.configs(), rvec![config("foo", 3), config("bar", 3)]);
+
+    let builder = builder.with_default_retries();
+    assert_eq!(builder.configs(), rvec![config("foo", 1), config("bar", 1)]);
+
+    // The value is replaced in place, reusing the allocation.
+    assert_eq!(builder.obj.sabi_erased_ref().as_ptr() as usize, address);
+
+    let unerased = builder.obj.downcast_into::<ConfigList>().unwrap();
+    assert_eq!(unerased.0.len(), 2);
+
+    // Goes through the trait impl of the trait object.
+    fn add_default<B: Builder>(builder: B) -> B {
+        builder.with_config(config("default", 5))
+    }
+    let builder = Builder_TO::from_value(ConfigList::default(), TD_Opaque);
+    assert_eq!(add_default(builder).configs(), rvec![config("default", 5)]);
+}
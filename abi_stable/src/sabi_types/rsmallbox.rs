@@ -149,6 +149,31 @@ mod private {
     /// assert!(RSmallBox::is_inline(&just_right));
     ///
     /// ```
+    ///
+    /// ### As the pointer of a `DynTrait`
+    ///
+    /// `RSmallBox` can be used as the pointer type of a `DynTrait`,
+    /// storing small erased values without allocating.
+    ///
+    /// ```
+    /// use abi_stable::{
+    ///     erased_types::interfaces::DebugDisplayInterface, sabi_types::RSmallBox,
+    ///     std_types::RString, DynTrait,
+    /// };
+    ///
+    /// type SmallDyn = DynTrait<'static, RSmallBox<(), [usize; 2]>, DebugDisplayInterface>;
+    ///
+    /// let small: SmallDyn = DynTrait::from_ptr(RSmallBox::new(3u8));
+    /// // `RString` is 4 `usize`s large, so it doesn't fit in `[usize; 2]`
+    /// let large: SmallDyn = DynTrait::from_ptr(RSmallBox::new(RString::from("hello")));
+    ///
+    /// assert_eq!(small.to_string(), "3");
+    /// assert_eq!(large.to_string(), "hello");
+    ///
+    /// assert!(RSmallBox::is_inline(&small.downcast_into::<u8>().unwrap()));
+    /// assert!(RSmallBox::is_heap_allocated(&large.downcast_into::<RString>().unwrap()));
+    ///
+    /// ```
     #[repr(C)]
     #[derive(StableAbi)]
     #[sabi(not_stableabi(Inline))]
@@ -139,3 +139,53 @@ fn alignments() {
     assert_eq!(mem::align_of::<AlignTo64<[u8; 1]>>(), 64);
     assert_eq!(mem::align_of::<AlignTo128<[u8; 1]>>(), 128);
 }
+
+#[test]
+fn as_dyn_trait_pointer() {
+    use crate::{erased_types::interfaces::DebugDisplayInterface, std_types::RString, DynTrait};
+
+    type SmallDyn = DynTrait<'static, RSmallBox<(), [u64; 1]>, DebugDisplayInterface>;
+
+    let contains_object = |dyn_: &SmallDyn| {
+        let start = dyn_ as *const SmallDyn as usize;
+        let object = dyn_.sabi_object_address();
+        start <= object && object < start + mem::size_of::<SmallDyn>()
+    };
+
+    {
+        let small: SmallDyn = DynTrait::from_ptr(RSmallBox::new(100u64));
+        assert!(contains_object(&small));
+        assert_eq!(small.to_string(), "100");
+
+        let small = small.downcast_into::<u64>().unwrap();
+        assert!(RSmallBox::is_inline(&small));
+        assert_eq!(*small, 100);
+    }
+    {
+        let large: SmallDyn = DynTrait::from_ptr(RSmallBox::new(RString::from("hello")));
+        assert!(!contains_object(&large));
+        assert_eq!(large.to_string(), "hello");
+
+        let large = large.downcast_into::<RString>().unwrap();
+        assert!(RSmallBox::is_heap_allocated(&large));
+        assert_eq!(&*large, "hello");
+    }
+    {
+        let arc = Arc::new(100);
+        {
+            let small: SmallDyn = DynTrait::from_ptr(RSmallBox::new(ArcDisplay(arc.clone())));
+            assert!(contains_object(&small));
+            assert_eq!(Arc::strong_count(&arc), 2);
+        }
+        assert_eq!(Arc::strong_count(&arc), 1);
+    }
+}
+
+#[derive(Debug)]
+struct ArcDisplay(Arc<u32>);
+
+impl std::fmt::Display for ArcDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -36,7 +36,7 @@ use crate::std_types::RStr;
 /// let v1_0_0 = VersionStrings::new("1.0.0").parsed().unwrap();
 /// let v1_0_5 = VersionStrings::new("1.0.5").parsed().unwrap();
 /// let v1_1_0 = VersionStrings::new("1.1.0").parsed().unwrap();
-/// let v2_0_0 = VersionStrings::new("1.0.5").parsed().unwrap();
+/// let v2_0_0 = VersionStrings::new("2.0.0").parsed().unwrap();
 ///
 /// assert!(v1_0_0.is_compatible(v1_0_5), "'{}' '{}'", v1_0_0, v1_0_5);
 /// assert!(v1_0_5.is_compatible(v1_1_0), "'{}' '{}'", v1_0_5, v1_1_0);
@@ -409,3 +409,72 @@ impl Display for ParseVersionError {
 }
 
 impl error::Error for ParseVersionError {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    fn parse(s: &'static str) -> VersionNumber {
+        VersionStrings::new(s).parsed().unwrap()
+    }
+
+    #[test]
+    fn parse_version_strings() {
+        assert_eq!(
+            parse("3.14.15"),
+            VersionNumber {
+                major: 3,
+                minor: 14,
+                patch: 15
+            }
+        );
+        assert_eq!(parse("0.2.4-beta").patch, 4);
+
+        for invalid in ["", "1", "a.2.3", "1.b.3"] {
+            let err = VersionStrings::new(invalid).parsed().unwrap_err();
+            assert_eq!(err.version_strings(), VersionStrings::new(invalid));
+        }
+    }
+
+    #[test]
+    fn pre_1_0_compatibility() {
+        let v0_1_0 = parse("0.1.0");
+        let v0_1_3 = parse("0.1.3");
+        let v0_2_0 = parse("0.2.0");
+        let v1_0_0 = parse("1.0.0");
+
+        // Patch versions are treated like minor versions before 1.0
+        assert!(v0_1_0.is_compatible(v0_1_3));
+        assert!(!v0_1_3.is_compatible(v0_1_0));
+
+        // Minor versions are treated like major versions before 1.0
+        assert!(!v0_1_0.is_compatible(v0_2_0));
+        assert!(!v0_2_0.is_compatible(v0_1_0));
+        assert!(!v0_1_0.is_loosely_compatible(v0_2_0));
+        assert!(v0_1_3.is_loosely_compatible(v0_1_0));
+
+        assert!(!v0_2_0.is_compatible(v1_0_0));
+        assert!(!v1_0_0.is_compatible(v0_2_0));
+    }
+
+    #[test]
+    fn post_1_0_compatibility() {
+        let v1_0_0 = parse("1.0.0");
+        let v1_0_7 = parse("1.0.7");
+        let v1_2_0 = parse("1.2.0");
+        let v2_0_0 = parse("2.0.0");
+
+        assert!(v1_0_0.is_compatible(v1_0_7));
+        assert!(v1_0_7.is_compatible(v1_0_0));
+
+        assert!(v1_0_0.is_compatible(v1_2_0));
+        assert!(!v1_2_0.is_compatible(v1_0_0));
+        assert!(v1_2_0.is_loosely_compatible(v1_0_0));
+
+        assert!(!v1_2_0.is_compatible(v2_0_0));
+        assert!(!v2_0_0.is_compatible(v1_2_0));
+        assert!(!v2_0_0.is_loosely_compatible(v1_0_0));
+    }
+}
@@ -5,11 +5,15 @@
 //! the [external_types module](../external_types/index.html)
 
 pub(crate) mod arc;
+pub(crate) mod arc_slice;
 pub(crate) mod boxed;
 pub(crate) mod cmp_ordering;
 pub mod cow;
 pub mod map;
+pub(crate) mod net;
 pub(crate) mod option;
+pub(crate) mod os_str;
+pub(crate) mod path;
 pub(crate) mod range;
 pub(crate) mod result;
 pub(crate) mod slice_mut;
@@ -23,6 +27,12 @@ pub(crate) mod tuple;
 pub mod utypeid;
 pub mod vec;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+
+#[cfg(feature = "rayon")]
+mod rayon_impls;
+
 /// Some types from the `std::sync` module have ffi-safe equivalents in
 /// `abi_stable::external_types`.
 ///
@@ -37,11 +47,16 @@ pub mod sync {}
 #[doc(inline)]
 pub use self::{
     arc::RArc,
+    arc_slice::RArcSlice,
     boxed::RBox,
     cmp_ordering::RCmpOrdering,
     cow::{RCow, RCowSlice, RCowStr, RCowVal},
     map::RHashMap,
+    net::{RIpAddr, RSocketAddr},
     option::{RNone, ROption, RSome},
+    os_str::{ROsStr, ROsString},
+    path::{RPath, RPathBuf},
+    range::{RRange, RRangeFrom, RRangeInclusive, RRangeTo, RRangeToInclusive},
     result::{RErr, ROk, RResult},
     slice_mut::RSliceMut,
     slices::RSlice,
@@ -0,0 +1,120 @@
+//! Implementations of `arbitrary::Arbitrary` for the ffi-safe std types,
+//! enabled by the "arbitrary" feature.
+//!
+//! These delegate to the impls for the std equivalents of each type.
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::std_types::{RHashMap, ROption, RResult, RString, RVec};
+
+macro_rules! delegate_arbitrary {
+    (
+        impl[$($impl_params:tt)*] $type:ty => $std_type:ty
+        $(where[$($where_preds:tt)*])?
+    ) => {
+        impl<'a, $($impl_params)*> Arbitrary<'a> for $type
+        $(where $($where_preds)*)?
+        {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                <$std_type as Arbitrary<'a>>::arbitrary(u).map(Self::from)
+            }
+
+            fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+                <$std_type as Arbitrary<'a>>::arbitrary_take_rest(u).map(Self::from)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$std_type as Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+    };
+}
+
+delegate_arbitrary! {
+    impl[T] RVec<T> => Vec<T>
+    where[T: Arbitrary<'a>]
+}
+
+delegate_arbitrary! {
+    impl[] RString => String
+}
+
+delegate_arbitrary! {
+    impl[T] ROption<T> => Option<T>
+    where[T: Arbitrary<'a>]
+}
+
+delegate_arbitrary! {
+    impl[T, E] RResult<T, E> => std::result::Result<T, E>
+    where[T: Arbitrary<'a>, E: Arbitrary<'a>]
+}
+
+delegate_arbitrary! {
+    impl[K, V, S] RHashMap<K, V, S> => HashMap<K, V, S>
+    where[
+        K: Arbitrary<'a> + Eq + Hash,
+        V: Arbitrary<'a>,
+        S: BuildHasher + Default,
+    ]
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    const BYTES: &[u8] = &[
+        3, 1, 0, 5, 8, 13, 21, 34, 55, 89, 144, 233, 104, 101, 108, 108, 111, 2, 1, 4, 0, 0, 255,
+        7, 6, 5, 4, 3, 2, 1, 0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100,
+    ];
+
+    /// Constructs a `T` from `BYTES`,
+    /// asserting that it's equal to the std equivalent `U` converted with `conv`.
+    fn arbitrary_eq<'a, T, U>(conv: fn(U) -> T)
+    where
+        T: Arbitrary<'a> + PartialEq + std::fmt::Debug,
+        U: Arbitrary<'a>,
+    {
+        let value = T::arbitrary(&mut Unstructured::new(BYTES)).unwrap();
+        let std_value = U::arbitrary(&mut Unstructured::new(BYTES)).unwrap();
+        assert_eq!(value, conv(std_value));
+        assert_eq!(T::size_hint(0), U::size_hint(0));
+
+        let value = T::arbitrary_take_rest(Unstructured::new(BYTES)).unwrap();
+        let std_value = U::arbitrary_take_rest(Unstructured::new(BYTES)).unwrap();
+        assert_eq!(value, conv(std_value));
+    }
+
+    #[test]
+    fn from_bytes() {
+        arbitrary_eq::<RVec<u8>, Vec<u8>>(RVec::from);
+        arbitrary_eq::<RVec<RString>, Vec<String>>(|v| v.into_iter().map(RString::from).collect());
+        arbitrary_eq::<RString, String>(RString::from);
+        arbitrary_eq::<ROption<u32>, Option<u32>>(ROption::from);
+        arbitrary_eq::<ROption<RVec<u16>>, Option<Vec<u16>>>(|x| x.map(RVec::from).into());
+        arbitrary_eq::<RResult<u8, RString>, std::result::Result<u8, String>>(|x| {
+            x.map_err(RString::from).into()
+        });
+        arbitrary_eq::<RHashMap<u8, RVec<u8>>, HashMap<u8, Vec<u8>>>(|map| {
+            map.into_iter().map(|(k, v)| (k, RVec::from(v))).collect()
+        });
+    }
+
+    #[test]
+    fn fuzz_like() {
+        let mut u = Unstructured::new(BYTES);
+        while !u.is_empty() {
+            let map = RHashMap::<RString, ROption<RVec<u8>>>::arbitrary(&mut u).unwrap();
+            for tuple in map.iter() {
+                let _ = (tuple.0.len(), tuple.1.as_ref().map(|x| x.len()));
+            }
+            let _ = RResult::<RString, u64>::arbitrary(&mut u).unwrap();
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! Contains `RArcSlice`, an ffi-safe equivalent of `std::sync::Arc<[T]>`.
+
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::Arc,
+};
+
+use crate::std_types::{RArc, RSlice, RVec};
+
+/// Ffi-safe equivalent of `std::sync::Arc<[T]>`,
+/// for sharing an immutable slice without copying it.
+///
+/// Cloning an `RArcSlice` only increments the reference count,
+/// which (like with [`RArc`]) is always manipulated in the
+/// dynamic library/binary that constructed the `RArcSlice`.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::RArcSlice;
+///
+/// let slice = RArcSlice::from_slice(&[3, 5, 8, 13]);
+/// let clone = slice.clone();
+///
+/// assert_eq!(slice.len(), 4);
+/// assert_eq!(clone[2], 8);
+/// assert_eq!(&clone[1..], &[5, 8, 13]);
+///
+/// // Both `RArcSlice`s point to the same slice.
+/// assert!(RArcSlice::ptr_eq(&slice, &clone));
+/// assert_eq!(RArcSlice::strong_count(&slice), 2);
+///
+/// ```
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RArcSlice<T> {
+    inner: RArc<RVec<T>>,
+}
+
+impl<T> RArcSlice<T> {
+    /// Constructs an `RArcSlice` by cloning the elements of `slice`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RArcSlice;
+    ///
+    /// let slice = RArcSlice::from_slice(&["foo", "bar"]);
+    ///
+    /// assert_eq!(slice.as_slice(), &["foo", "bar"]);
+    ///
+    /// ```
+    pub fn from_slice(slice: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_rvec(RVec::from_slice(slice))
+    }
+
+    /// Constructs an `RArcSlice` from the elements of `vec`, without copying them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RArcSlice, RVec};
+    ///
+    /// let vec = RVec::from(vec![3, 5, 8]);
+    /// let ptr = vec.as_ptr();
+    ///
+    /// let slice = RArcSlice::from_rvec(vec);
+    ///
+    /// assert_eq!(slice.as_ptr(), ptr);
+    /// assert_eq!(slice.as_slice(), &[3, 5, 8]);
+    ///
+    /// ```
+    pub fn from_rvec(vec: RVec<T>) -> Self {
+        Self {
+            inner: RArc::new(vec),
+        }
+    }
+
+    /// Gets the slice this points to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RArcSlice;
+    ///
+    /// let slice = RArcSlice::from_slice(&[0, 1, 2]);
+    ///
+    /// assert_eq!(slice.as_slice(), &[0, 1, 2]);
+    ///
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+
+    /// Gets the slice this points to, as an `RSlice`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RArcSlice, RSlice};
+    ///
+    /// let slice = RArcSlice::from_slice(&[0, 1, 2]);
+    ///
+    /// assert_eq!(slice.as_rslice(), RSlice::from_slice(&[0, 1, 2]));
+    ///
+    /// ```
+    #[inline]
+    pub fn as_rslice(&self) -> RSlice<'_, T> {
+        self.as_slice().into()
+    }
+
+    /// Returns the amount of elements in the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RArcSlice;
+    ///
+    /// assert_eq!(RArcSlice::<u8>::from_slice(&[]).len(), 0);
+    /// assert_eq!(RArcSlice::from_slice(&[0]).len(), 1);
+    /// assert_eq!(RArcSlice::from_slice(&[0, 1]).len(), 2);
+    ///
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RArcSlice;
+    ///
+    /// assert!(RArcSlice::<u8>::from_slice(&[]).is_empty());
+    /// assert!(!RArcSlice::from_slice(&[0]).is_empty());
+    ///
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Whether both `RArcSlice`s point to the same slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RArcSlice;
+    ///
+    /// let slice = RArcSlice::from_slice(&[0, 1]);
+    ///
+    /// assert!(RArcSlice::ptr_eq(&slice, &slice.clone()));
+    /// assert!(!RArcSlice::ptr_eq(&slice, &RArcSlice::from_slice(&[0, 1])));
+    ///
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        std::ptr::eq(&*this.inner, &*other.inner)
+    }
+
+    /// Gets the number of `RArcSlice`s that point to the same slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RArcSlice;
+    ///
+    /// let slice = RArcSlice::from_slice(&[0, 1]);
+    /// assert_eq!(RArcSlice::strong_count(&slice), 1);
+    ///
+    /// let clone = slice.clone();
+    /// assert_eq!(RArcSlice::strong_count(&slice), 2);
+    ///
+    /// drop(clone);
+    /// assert_eq!(RArcSlice::strong_count(&slice), 1);
+    ///
+    /// ```
+    pub fn strong_count(this: &Self) -> usize {
+        RArc::strong_count(&this.inner)
+    }
+}
+
+impl<T> Clone for RArcSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Deref for RArcSlice<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for RArcSlice<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> Borrow<[T]> for RArcSlice<T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> Default for RArcSlice<T> {
+    fn default() -> Self {
+        Self::from_rvec(RVec::new())
+    }
+}
+
+impl<T> From<RVec<T>> for RArcSlice<T> {
+    fn from(vec: RVec<T>) -> Self {
+        Self::from_rvec(vec)
+    }
+}
+
+impl<T> From<Vec<T>> for RArcSlice<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::from_rvec(vec.into())
+    }
+}
+
+impl<T> From<&[T]> for RArcSlice<T>
+where
+    T: Clone,
+{
+    fn from(slice: &[T]) -> Self {
+        Self::from_slice(slice)
+    }
+}
+
+impl<T> From<Arc<[T]>> for RArcSlice<T>
+where
+    T: Clone,
+{
+    fn from(arc: Arc<[T]>) -> Self {
+        Self::from_slice(&arc)
+    }
+}
+
+impl<T> Debug for RArcSlice<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T> Eq for RArcSlice<T> where T: Eq {}
+
+impl<T, U> PartialEq<RArcSlice<U>> for RArcSlice<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &RArcSlice<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T> Hash for RArcSlice<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_shares_slice() {
+        let arc = Arc::new(0);
+        let slice = RArcSlice::from_slice(&[arc.clone(), arc.clone()]);
+        assert_eq!(Arc::strong_count(&arc), 3);
+
+        let clone = slice.clone();
+        assert!(RArcSlice::ptr_eq(&slice, &clone));
+        assert_eq!(slice.as_ptr(), clone.as_ptr());
+        assert_eq!(RArcSlice::strong_count(&slice), 2);
+
+        // cloning the `RArcSlice` doesn't clone the elements
+        assert_eq!(Arc::strong_count(&arc), 3);
+
+        drop(slice);
+        assert_eq!(RArcSlice::strong_count(&clone), 1);
+        assert_eq!(Arc::strong_count(&arc), 3);
+
+        drop(clone);
+        assert_eq!(Arc::strong_count(&arc), 1);
+    }
+
+    #[test]
+    fn len_and_index() {
+        let slice = RArcSlice::from(vec![3, 5, 8, 13]);
+
+        assert_eq!(slice.len(), 4);
+        assert!(!slice.is_empty());
+        assert_eq!(slice[0], 3);
+        assert_eq!(slice[3], 13);
+        assert_eq!(&slice[1..3], &[5, 8]);
+        assert_eq!(slice.iter().sum::<i32>(), 29);
+
+        let empty = RArcSlice::<u8>::default();
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn comparison() {
+        let slice = RArcSlice::from_slice(&[3, 5]);
+
+        assert_eq!(slice, RArcSlice::from_slice(&[3, 5]));
+        assert_ne!(slice, RArcSlice::from_slice(&[3, 5, 8]));
+        assert_eq!(format!("{:?}", slice), "[3, 5]");
+    }
+}
@@ -81,6 +81,200 @@ impl RCmpOrdering {
             Ordering::Greater => RCmpOrdering::Greater,
         }
     }
+
+    /// Returns whether this is `Equal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Less.is_eq(), false);
+    /// assert_eq!(RCmpOrdering::Equal.is_eq(), true);
+    /// assert_eq!(RCmpOrdering::Greater.is_eq(), false);
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_eq(self) -> bool {
+        matches!(self, RCmpOrdering::Equal)
+    }
+
+    /// Returns whether this is not `Equal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Less.is_ne(), true);
+    /// assert_eq!(RCmpOrdering::Equal.is_ne(), false);
+    /// assert_eq!(RCmpOrdering::Greater.is_ne(), true);
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_ne(self) -> bool {
+        !matches!(self, RCmpOrdering::Equal)
+    }
+
+    /// Returns whether this is `Less`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Less.is_lt(), true);
+    /// assert_eq!(RCmpOrdering::Equal.is_lt(), false);
+    /// assert_eq!(RCmpOrdering::Greater.is_lt(), false);
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_lt(self) -> bool {
+        matches!(self, RCmpOrdering::Less)
+    }
+
+    /// Returns whether this is `Greater`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Less.is_gt(), false);
+    /// assert_eq!(RCmpOrdering::Equal.is_gt(), false);
+    /// assert_eq!(RCmpOrdering::Greater.is_gt(), true);
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_gt(self) -> bool {
+        matches!(self, RCmpOrdering::Greater)
+    }
+
+    /// Returns whether this is `Less` or `Equal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Less.is_le(), true);
+    /// assert_eq!(RCmpOrdering::Equal.is_le(), true);
+    /// assert_eq!(RCmpOrdering::Greater.is_le(), false);
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_le(self) -> bool {
+        !matches!(self, RCmpOrdering::Greater)
+    }
+
+    /// Returns whether this is `Greater` or `Equal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Less.is_ge(), false);
+    /// assert_eq!(RCmpOrdering::Equal.is_ge(), true);
+    /// assert_eq!(RCmpOrdering::Greater.is_ge(), true);
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_ge(self) -> bool {
+        !matches!(self, RCmpOrdering::Less)
+    }
+
+    /// Reverses the ordering, `Less` becomes `Greater` and vice versa.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Less.reverse(), RCmpOrdering::Greater);
+    /// assert_eq!(RCmpOrdering::Equal.reverse(), RCmpOrdering::Equal);
+    /// assert_eq!(RCmpOrdering::Greater.reverse(), RCmpOrdering::Less);
+    ///
+    /// // reversing the comparison of a slice, to sort it from largest to smallest.
+    /// let mut list = [5, 2, 3, 8];
+    /// list.sort_by(|l, r| RCmpOrdering::from(l.cmp(r)).reverse().into());
+    /// assert_eq!(list, [8, 5, 3, 2]);
+    ///
+    /// ```
+    #[inline]
+    pub const fn reverse(self) -> RCmpOrdering {
+        match self {
+            RCmpOrdering::Less => RCmpOrdering::Greater,
+            RCmpOrdering::Equal => RCmpOrdering::Equal,
+            RCmpOrdering::Greater => RCmpOrdering::Less,
+        }
+    }
+
+    /// Chains two orderings,
+    /// returning `self` if it's not `Equal`, otherwise returning `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(RCmpOrdering::Equal.then(RCmpOrdering::Less), RCmpOrdering::Less);
+    /// assert_eq!(RCmpOrdering::Less.then(RCmpOrdering::Equal), RCmpOrdering::Less);
+    /// assert_eq!(RCmpOrdering::Greater.then(RCmpOrdering::Less), RCmpOrdering::Greater);
+    ///
+    /// let x = (1, 2, 7);
+    /// let y = (1, 5, 3);
+    /// let result = RCmpOrdering::from(x.0.cmp(&y.0))
+    ///     .then(x.1.cmp(&y.1).into())
+    ///     .then(x.2.cmp(&y.2).into());
+    ///
+    /// assert_eq!(result, RCmpOrdering::Less);
+    ///
+    /// ```
+    #[inline]
+    pub const fn then(self, other: RCmpOrdering) -> RCmpOrdering {
+        match self {
+            RCmpOrdering::Equal => other,
+            _ => self,
+        }
+    }
+
+    /// Chains the ordering with the one returned by `f`,
+    /// returning `self` if it's not `Equal`, otherwise calling `f` and returning its result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RCmpOrdering;
+    ///
+    /// assert_eq!(
+    ///     RCmpOrdering::Equal.then_with(|| RCmpOrdering::Less),
+    ///     RCmpOrdering::Less
+    /// );
+    /// assert_eq!(
+    ///     RCmpOrdering::Greater.then_with(|| unreachable!()),
+    ///     RCmpOrdering::Greater
+    /// );
+    ///
+    /// let x = (1, 2, 7);
+    /// let y = (1, 5, 3);
+    /// let result = RCmpOrdering::from(x.0.cmp(&y.0))
+    ///     .then_with(|| x.1.cmp(&y.1).into())
+    ///     .then_with(|| x.2.cmp(&y.2).into());
+    ///
+    /// assert_eq!(result, RCmpOrdering::Less);
+    ///
+    /// ```
+    #[inline]
+    pub fn then_with<F>(self, f: F) -> RCmpOrdering
+    where
+        F: FnOnce() -> RCmpOrdering,
+    {
+        match self {
+            RCmpOrdering::Equal => f(),
+            _ => self,
+        }
+    }
 }
 
 impl_from_rust_repr! {
@@ -291,6 +291,31 @@ fn map_key() {
     assert_eq!(value_hash, query_hash2);
 }
 
+#[test]
+fn get_borrowed_key() {
+    use crate::std_types::{RArc, RBox};
+
+    let mut map = RHashMap::<RString, u32>::new();
+    map.insert("foo".into(), 3);
+    map.insert("bar".into(), 5);
+    assert_eq!(map.get("foo"), Some(&3));
+    assert_eq!(map.get("bar"), Some(&5));
+    assert_eq!(map.get("baz"), None);
+    assert!(map.contains_key("foo"));
+    assert_eq!(map.remove("foo"), RSome(3));
+    assert_eq!(map.get("foo"), None);
+
+    let mut map = RHashMap::<RArc<RString>, u32>::new();
+    map.insert(RArc::new("foo".into()), 8);
+    assert_eq!(map.get(&RString::from("foo")), Some(&8));
+    assert_eq!(map.get(&RString::from("bar")), None);
+
+    let mut map = RHashMap::<RBox<u32>, &str>::new();
+    map.insert(RBox::new(13), "thirteen");
+    assert_eq!(map.get(&13), Some(&"thirteen"));
+    assert_eq!(map.get(&21), None);
+}
+
 #[test]
 fn clear() {
     let mut map = RHashMap::<String, _>::new();
@@ -312,6 +337,40 @@ fn clear() {
     assert_eq!(map.get("you"), None);
 }
 
+#[test]
+fn drain() {
+    let mut map = new_map::<u32, u32, DefaultBH>();
+    let capacity = map.capacity();
+
+    let mut list = map.drain().collect::<Vec<_>>();
+    list.sort();
+    assert_eq!(
+        list,
+        vec![
+            Tuple2(10, 20),
+            Tuple2(77, 22),
+            Tuple2(88, 30),
+            Tuple2(90, 40)
+        ]
+    );
+    assert!(map.is_empty(), "map length:{:?}", map.len());
+    assert_eq!(map.capacity(), capacity);
+
+    // Dropping a partially consumed `Drain` removes the remaining entries.
+    map.extend(new_stdmap());
+    {
+        let mut drain = map.drain();
+        assert!(drain.next().is_some());
+    }
+    assert!(map.is_empty(), "map length:{:?}", map.len());
+
+    // The map is reusable after draining it.
+    map.insert(3, 5);
+    assert_eq!(map.get(&3), Some(&5));
+    assert_eq!(map.drain().collect::<Vec<_>>(), vec![Tuple2(3, 5)]);
+    assert!(map.drain().next().is_none());
+}
+
 #[test]
 fn len_is_empty() {
     let mut map = RHashMap::<String, _>::new();
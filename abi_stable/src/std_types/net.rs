@@ -0,0 +1,388 @@
+//! Contains ffi-safe equivalents of `std::net::{IpAddr, SocketAddr}`.
+
+use std::{
+    fmt::{self, Display},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Ffi-safe equivalent of `std::net::IpAddr`,
+/// storing the octets of the address.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::RIpAddr;
+///
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// let addr = RIpAddr::from(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
+///
+/// assert_eq!(addr, RIpAddr::V4([127, 0, 0, 1]));
+/// assert!(addr.is_ipv4());
+/// assert_eq!(addr.to_string(), "127.0.0.1");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[repr(u8)]
+#[derive(StableAbi)]
+pub enum RIpAddr {
+    /// The octets of an IPv4 address.
+    V4([u8; 4]),
+    /// The octets of an IPv6 address.
+    V6([u8; 16]),
+}
+
+impl RIpAddr {
+    /// Whether this is an IPv4 address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RIpAddr;
+    ///
+    /// assert!(RIpAddr::V4([10, 0, 0, 1]).is_ipv4());
+    /// assert!(!RIpAddr::V6([0; 16]).is_ipv4());
+    ///
+    /// ```
+    pub const fn is_ipv4(&self) -> bool {
+        matches!(self, RIpAddr::V4 { .. })
+    }
+
+    /// Whether this is an IPv6 address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RIpAddr;
+    ///
+    /// assert!(RIpAddr::V6([0; 16]).is_ipv6());
+    /// assert!(!RIpAddr::V4([10, 0, 0, 1]).is_ipv6());
+    ///
+    /// ```
+    pub const fn is_ipv6(&self) -> bool {
+        matches!(self, RIpAddr::V6 { .. })
+    }
+
+    /// Converts this `RIpAddr` into a `std::net::IpAddr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RIpAddr;
+    ///
+    /// use std::net::{IpAddr, Ipv6Addr};
+    ///
+    /// let mut octets = [0; 16];
+    /// octets[15] = 1;
+    ///
+    /// assert_eq!(
+    ///     RIpAddr::V6(octets).into_ip_addr(),
+    ///     IpAddr::V6(Ipv6Addr::LOCALHOST),
+    /// );
+    ///
+    /// ```
+    pub fn into_ip_addr(self) -> IpAddr {
+        self.into()
+    }
+}
+
+impl_from_rust_repr! {
+    impl From<IpAddr> for RIpAddr {
+        fn(this){
+            match this {
+                IpAddr::V4(x) => RIpAddr::V4(x.octets()),
+                IpAddr::V6(x) => RIpAddr::V6(x.octets()),
+            }
+        }
+    }
+}
+
+impl_into_rust_repr! {
+    impl Into<IpAddr> for RIpAddr {
+        fn(this){
+            match this {
+                RIpAddr::V4(x) => IpAddr::V4(Ipv4Addr::from(x)),
+                RIpAddr::V6(x) => IpAddr::V6(Ipv6Addr::from(x)),
+            }
+        }
+    }
+}
+
+impl From<Ipv4Addr> for RIpAddr {
+    #[inline]
+    fn from(addr: Ipv4Addr) -> Self {
+        RIpAddr::V4(addr.octets())
+    }
+}
+
+impl From<Ipv6Addr> for RIpAddr {
+    #[inline]
+    fn from(addr: Ipv6Addr) -> Self {
+        RIpAddr::V6(addr.octets())
+    }
+}
+
+impl Display for RIpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.into_ip_addr(), f)
+    }
+}
+
+impl<'de> Deserialize<'de> for RIpAddr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IpAddr::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for RIpAddr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.into_ip_addr().serialize(serializer)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+
+/// Ffi-safe equivalent of `std::net::SocketAddr`.
+///
+/// The IPv6-specific `flowinfo` and `scope_id` are preserved when
+/// converting to and from `std::net::SocketAddr`,
+/// and are always `0` for IPv4 addresses.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::{RIpAddr, RSocketAddr};
+///
+/// use std::net::SocketAddr;
+///
+/// let std_addr: SocketAddr = "192.168.0.8:8080".parse().unwrap();
+/// let addr = RSocketAddr::from(std_addr);
+///
+/// assert_eq!(addr.ip(), RIpAddr::V4([192, 168, 0, 8]));
+/// assert_eq!(addr.port(), 8080);
+/// assert_eq!(addr.into_socket_addr(), std_addr);
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RSocketAddr {
+    ip: RIpAddr,
+    port: u16,
+    flowinfo: u32,
+    scope_id: u32,
+}
+
+impl RSocketAddr {
+    /// Constructs an `RSocketAddr` from an ip address and a port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RIpAddr, RSocketAddr};
+    ///
+    /// let addr = RSocketAddr::new(RIpAddr::V4([127, 0, 0, 1]), 80);
+    ///
+    /// assert_eq!(addr.to_string(), "127.0.0.1:80");
+    ///
+    /// ```
+    pub const fn new(ip: RIpAddr, port: u16) -> Self {
+        Self {
+            ip,
+            port,
+            flowinfo: 0,
+            scope_id: 0,
+        }
+    }
+
+    /// The ip address of this socket address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RIpAddr, RSocketAddr};
+    ///
+    /// let addr = RSocketAddr::new(RIpAddr::V4([10, 0, 0, 3]), 443);
+    ///
+    /// assert_eq!(addr.ip(), RIpAddr::V4([10, 0, 0, 3]));
+    ///
+    /// ```
+    pub const fn ip(&self) -> RIpAddr {
+        self.ip
+    }
+
+    /// The port of this socket address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RIpAddr, RSocketAddr};
+    ///
+    /// let addr = RSocketAddr::new(RIpAddr::V4([10, 0, 0, 3]), 443);
+    ///
+    /// assert_eq!(addr.port(), 443);
+    ///
+    /// ```
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// The IPv6 flow information of this socket address, `0` for IPv4 addresses.
+    pub const fn flowinfo(&self) -> u32 {
+        self.flowinfo
+    }
+
+    /// The IPv6 scope id of this socket address, `0` for IPv4 addresses.
+    pub const fn scope_id(&self) -> u32 {
+        self.scope_id
+    }
+
+    /// Converts this `RSocketAddr` into a `std::net::SocketAddr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RIpAddr, RSocketAddr};
+    ///
+    /// use std::net::SocketAddr;
+    ///
+    /// let addr = RSocketAddr::new(RIpAddr::V4([127, 0, 0, 1]), 8000);
+    ///
+    /// assert_eq!(
+    ///     addr.into_socket_addr(),
+    ///     "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
+    /// );
+    ///
+    /// ```
+    pub fn into_socket_addr(self) -> SocketAddr {
+        self.into()
+    }
+}
+
+impl_from_rust_repr! {
+    impl From<SocketAddr> for RSocketAddr {
+        fn(this){
+            match this {
+                SocketAddr::V4(x) => RSocketAddr::new(RIpAddr::V4(x.ip().octets()), x.port()),
+                SocketAddr::V6(x) => RSocketAddr {
+                    ip: RIpAddr::V6(x.ip().octets()),
+                    port: x.port(),
+                    flowinfo: x.flowinfo(),
+                    scope_id: x.scope_id(),
+                },
+            }
+        }
+    }
+}
+
+impl_into_rust_repr! {
+    impl Into<SocketAddr> for RSocketAddr {
+        fn(this){
+            match this.ip {
+                RIpAddr::V4(ip) => {
+                    SocketAddr::V4(SocketAddrV4::new(ip.into(), this.port))
+                }
+                RIpAddr::V6(ip) => {
+                    SocketAddr::V6(SocketAddrV6::new(
+                        ip.into(),
+                        this.port,
+                        this.flowinfo,
+                        this.scope_id,
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl Display for RSocketAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.into_socket_addr(), f)
+    }
+}
+
+impl<'de> Deserialize<'de> for RSocketAddr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        SocketAddr::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for RSocketAddr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.into_socket_addr().serialize(serializer)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ip_addr_from_into() {
+        let v4: IpAddr = "192.168.1.20".parse().unwrap();
+        let v6: IpAddr = "2001:db8::ff00:42:8329".parse().unwrap();
+
+        assert_eq!(RIpAddr::from(v4), RIpAddr::V4([192, 168, 1, 20]));
+        assert_eq!(RIpAddr::from(v4).into_ip_addr(), v4);
+        assert_eq!(RIpAddr::from(v4).to_string(), v4.to_string());
+
+        assert!(RIpAddr::from(v6).is_ipv6());
+        assert_eq!(RIpAddr::from(v6).into_ip_addr(), v6);
+        assert_eq!(RIpAddr::from(v6).to_string(), v6.to_string());
+    }
+
+    #[test]
+    fn socket_addr_from_into() {
+        let v4: SocketAddr = "192.168.1.20:8080".parse().unwrap();
+        let v6 = SocketAddr::V6(SocketAddrV6::new(
+            "2001:db8::ff00:42:8329".parse().unwrap(),
+            65535,
+            3,
+            5,
+        ));
+
+        let rv4 = RSocketAddr::from(v4);
+        assert_eq!(rv4.ip(), RIpAddr::V4([192, 168, 1, 20]));
+        assert_eq!(rv4.port(), 8080);
+        assert_eq!(rv4.into_socket_addr(), v4);
+        assert_eq!(rv4.to_string(), v4.to_string());
+
+        let rv6 = RSocketAddr::from(v6);
+        assert_eq!(rv6.ip(), RIpAddr::from(v6.ip()));
+        assert_eq!(rv6.port(), 65535);
+        assert_eq!(rv6.flowinfo(), 3);
+        assert_eq!(rv6.scope_id(), 5);
+        assert_eq!(rv6.into_socket_addr(), v6);
+        assert_eq!(rv6.to_string(), v6.to_string());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let addr = RSocketAddr::from("[::1]:3000".parse::<SocketAddr>().unwrap());
+
+        let json = serde_json::to_string(&addr).unwrap();
+        assert_eq!(json, r#""[::1]:3000""#);
+        assert_eq!(serde_json::from_str::<RSocketAddr>(&json).unwrap(), addr);
+
+        let ip = addr.ip();
+        let json = serde_json::to_string(&ip).unwrap();
+        assert_eq!(json, r#""::1""#);
+        assert_eq!(serde_json::from_str::<RIpAddr>(&json).unwrap(), ip);
+    }
+}
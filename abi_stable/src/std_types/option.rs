@@ -10,6 +10,8 @@ use crate::std_types::RResult;
 ///
 /// `Option` is also ffi-safe for NonNull/NonZero types, and references.
 ///
+/// This is serialized and deserialized the same way as `Option<T>`.
+///
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[repr(u8)]
 #[derive(StableAbi)]
@@ -795,11 +797,120 @@ where
 
 /////////////////////////////////////////////////////////////////////
 
+/// Allows using the `?` operator on `ROption`.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::{RNone, ROption, RSome};
+///
+/// fn first_char(s: &str) -> ROption<char> {
+///     s.chars().next().into()
+/// }
+///
+/// fn first_two(s: &str) -> Option<(char, char)> {
+///     Some((first_char(s)?, first_char(&s[1..])?))
+/// }
+///
+/// fn rfirst_two(s: &str) -> ROption<(char, char)> {
+///     RSome((s.chars().next()?, first_char(&s[1..])?))
+/// }
+///
+/// assert_eq!(first_two("hello"), Some(('h', 'e')));
+/// assert_eq!(first_two("h"), None);
+///
+/// assert_eq!(rfirst_two("hello"), RSome(('h', 'e')));
+/// assert_eq!(rfirst_two("h"), RNone);
+///
+/// ```
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T> std::ops::Try for ROption<T> {
+    type Output = T;
+    type Residual = ROption<std::convert::Infallible>;
+
+    #[inline]
+    fn from_output(output: T) -> Self {
+        RSome(output)
+    }
+
+    #[inline]
+    fn branch(self) -> std::ops::ControlFlow<Self::Residual, T> {
+        use std::ops::ControlFlow;
+        match self {
+            RSome(v) => ControlFlow::Continue(v),
+            RNone => ControlFlow::Break(RNone),
+        }
+    }
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T> std::ops::Residual<T> for ROption<std::convert::Infallible> {
+    type TryType = ROption<T>;
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T> std::ops::FromResidual<ROption<std::convert::Infallible>> for ROption<T> {
+    #[inline]
+    fn from_residual(_: ROption<std::convert::Infallible>) -> Self {
+        RNone
+    }
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T> std::ops::FromResidual<Option<std::convert::Infallible>> for ROption<T> {
+    #[inline]
+    fn from_residual(_: Option<std::convert::Infallible>) -> Self {
+        RNone
+    }
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T> std::ops::FromResidual<ROption<std::convert::Infallible>> for Option<T> {
+    #[inline]
+    fn from_residual(_: ROption<std::convert::Infallible>) -> Self {
+        None
+    }
+}
+
+/////////////////////////////////////////////////////////////////////
+
 #[cfg(all(test, not(feature = "only_new_tests")))]
 // #[cfg(test)]
 mod test {
     use super::*;
 
+    use crate::std_types::RString;
+
+    #[test]
+    fn serde_matches_std() {
+        let cases: [(ROption<RString>, Option<RString>); 2] =
+            [(RSome("hello".into()), Some("hello".into())), (RNone, None)];
+
+        for (roption, option) in cases {
+            let json = serde_json::to_string(&option).unwrap();
+            assert_eq!(serde_json::to_string(&roption).unwrap(), json);
+            assert_eq!(
+                serde_json::from_str::<ROption<RString>>(&json).unwrap(),
+                roption
+            );
+
+            let bytes = bincode::serialize(&option).unwrap();
+            assert_eq!(bincode::serialize(&roption).unwrap(), bytes);
+            assert_eq!(
+                bincode::deserialize::<ROption<RString>>(&bytes).unwrap(),
+                roption
+            );
+        }
+
+        assert_eq!(serde_json::to_string(&RSome(5)).unwrap(), "5");
+        assert_eq!(serde_json::to_string(&RNone::<u32>).unwrap(), "null");
+    }
+
     #[test]
     fn from_into() {
         assert_eq!(ROption::from(Some(10)), RSome(10));
@@ -808,4 +919,27 @@ mod test {
         assert_eq!(RSome(10).into_option(), Some(10));
         assert_eq!(RNone::<u32>.into_option(), None);
     }
+
+    #[cfg(feature = "nightly_try")]
+    #[test]
+    fn question_mark() {
+        fn roption_to_option(x: ROption<u32>) -> Option<u32> {
+            Some(x? + 1)
+        }
+        fn option_to_roption(x: Option<u32>) -> ROption<u32> {
+            RSome(x? + 1)
+        }
+        fn roption_to_roption(x: ROption<u32>) -> ROption<u32> {
+            RSome(x? + 1)
+        }
+
+        assert_eq!(roption_to_option(RSome(3)), Some(4));
+        assert_eq!(roption_to_option(RNone), None);
+
+        assert_eq!(option_to_roption(Some(3)), RSome(4));
+        assert_eq!(option_to_roption(None), RNone);
+
+        assert_eq!(roption_to_roption(RSome(3)), RSome(4));
+        assert_eq!(roption_to_roption(RNone), RNone);
+    }
 }
@@ -0,0 +1,619 @@
+//! Contains ffi-safe equivalents of `&'a OsStr` and `OsString`.
+
+use std::{
+    ffi::{OsStr, OsString},
+    fmt::{self, Debug},
+    str,
+};
+
+use crate::std_types::{RSlice, RVec};
+
+/// Ffi-safe equivalent of `&'a OsStr`.
+///
+/// # Encoding
+///
+/// `ROsStr` and [`ROsString`] store the string as bytes in a platform-specific encoding:
+///
+/// - On Unix: the raw bytes of the `OsStr`, which may be any sequence of bytes.
+///
+/// - On Windows: [WTF-8](https://simonsapin.github.io/wtf-8/),
+///   a superset of UTF-8 that can also encode the unpaired surrogates
+///   that Windows strings may contain.
+///
+/// - On other platforms: UTF-8,
+///   non-UTF-8 strings are converted lossily when constructing the ffi-safe types.
+///
+/// Both sides of an ffi boundary in the same process share the platform,
+/// which makes passing these types between dynamic libraries lossless.
+/// The encoded bytes are not portable across platforms,
+/// they must not be persisted or sent to a process running on a different platform
+/// unless they're valid UTF-8 (which all of the encodings agree on).
+///
+/// Constructing these types from arbitrary bytes is safe,
+/// on Windows invalid WTF-8 sequences are replaced with `U+FFFD`
+/// when converting back to an `OsString`.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::{ROsStr, ROsString};
+///
+/// use std::ffi::OsString;
+///
+/// let string = ROsString::from(OsString::from("hello"));
+/// let str: ROsStr<'_> = string.as_ros_str();
+///
+/// assert_eq!(str.as_encoded_bytes(), b"hello");
+/// assert_eq!(str.to_str(), Some("hello"));
+/// assert_eq!(str.to_os_string(), OsString::from("hello"));
+///
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, StableAbi)]
+pub struct ROsStr<'a> {
+    inner: RSlice<'a, u8>,
+}
+
+impl<'a> ROsStr<'a> {
+    /// An empty `ROsStr`.
+    pub const EMPTY: Self = ROsStr {
+        inner: RSlice::EMPTY,
+    };
+
+    /// Constructs an `ROsStr` from bytes in the platform-specific encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsStr;
+    ///
+    /// let str = ROsStr::from_encoded_bytes(b"world");
+    ///
+    /// assert_eq!(str.to_str(), Some("world"));
+    ///
+    /// ```
+    pub const fn from_encoded_bytes(bytes: &'a [u8]) -> Self {
+        Self {
+            inner: RSlice::from_slice(bytes),
+        }
+    }
+
+    /// Gets the bytes of this `ROsStr`, in the platform-specific encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsStr;
+    ///
+    /// let str = ROsStr::from_encoded_bytes(b"foo");
+    ///
+    /// assert_eq!(str.as_encoded_bytes(), b"foo");
+    ///
+    /// ```
+    pub fn as_encoded_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Gets the string as a `&str`,
+    /// returning None if it isn't valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsStr;
+    ///
+    /// assert_eq!(ROsStr::from_encoded_bytes(b"bar").to_str(), Some("bar"));
+    /// assert_eq!(ROsStr::from_encoded_bytes(b"\xFF").to_str(), None);
+    ///
+    /// ```
+    pub fn to_str(&self) -> Option<&'a str> {
+        str::from_utf8(self.as_encoded_bytes()).ok()
+    }
+
+    /// Whether the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsStr;
+    ///
+    /// assert!(ROsStr::EMPTY.is_empty());
+    /// assert!(!ROsStr::from_encoded_bytes(b"a").is_empty());
+    ///
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Converts this `ROsStr` into an `OsString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsStr;
+    ///
+    /// use std::ffi::OsString;
+    ///
+    /// let str = ROsStr::from_encoded_bytes(b"qux");
+    ///
+    /// assert_eq!(str.to_os_string(), OsString::from("qux"));
+    ///
+    /// ```
+    pub fn to_os_string(&self) -> OsString {
+        encoding::decode(self.as_encoded_bytes())
+    }
+
+    /// Copies this `ROsStr` into an `ROsString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsStr;
+    ///
+    /// let str = ROsStr::from_encoded_bytes(b"qux");
+    ///
+    /// assert_eq!(str.to_ros_string().as_encoded_bytes(), b"qux");
+    ///
+    /// ```
+    pub fn to_ros_string(&self) -> ROsString {
+        ROsString::from_encoded_bytes(self.inner.to_rvec())
+    }
+}
+
+impl Default for ROsStr<'_> {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl Debug for ROsStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.to_os_string(), f)
+    }
+}
+
+/// Only available on Unix, since the encoding of an `OsStr` on other platforms
+/// differs from the encoding of `ROsStr`.
+#[cfg(unix)]
+#[cfg_attr(feature = "docsrs", doc(cfg(unix)))]
+impl<'a> From<&'a OsStr> for ROsStr<'a> {
+    fn from(str: &'a OsStr) -> Self {
+        use std::os::unix::ffi::OsStrExt;
+
+        Self::from_encoded_bytes(str.as_bytes())
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////////
+
+/// Ffi-safe equivalent of `OsString`,
+/// the encoding of the bytes is described in the [`ROsStr` docs](ROsStr#encoding).
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::{
+///     sabi_extern_fn,
+///     std_types::{ROsStr, ROsString},
+/// };
+///
+/// use std::ffi::OsString;
+///
+/// #[sabi_extern_fn]
+/// fn with_suffix(name: ROsStr<'_>) -> ROsString {
+///     let mut name = name.to_os_string();
+///     name.push(".txt");
+///     name.into()
+/// }
+///
+/// let name = ROsString::from(OsString::from("hello"));
+///
+/// assert_eq!(
+///     with_suffix(name.as_ros_str()).into_os_string(),
+///     OsString::from("hello.txt"),
+/// );
+///
+/// ```
+#[repr(C)]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, StableAbi)]
+pub struct ROsString {
+    inner: RVec<u8>,
+}
+
+impl ROsString {
+    /// Constructs an empty `ROsString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsString;
+    ///
+    /// let string = ROsString::new();
+    ///
+    /// assert!(string.is_empty());
+    ///
+    /// ```
+    pub const fn new() -> Self {
+        Self { inner: RVec::new() }
+    }
+
+    /// Constructs an `ROsString` from bytes in the platform-specific encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec, std_types::ROsString};
+    ///
+    /// let string = ROsString::from_encoded_bytes(rvec![b'h', b'i']);
+    ///
+    /// assert_eq!(string.to_str(), Some("hi"));
+    ///
+    /// ```
+    pub const fn from_encoded_bytes(bytes: RVec<u8>) -> Self {
+        Self { inner: bytes }
+    }
+
+    /// Gets the bytes of this `ROsString`, in the platform-specific encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsString;
+    ///
+    /// use std::ffi::OsStr;
+    ///
+    /// let string = ROsString::from(OsStr::new("foo"));
+    ///
+    /// assert_eq!(string.as_encoded_bytes(), b"foo");
+    ///
+    /// ```
+    pub fn as_encoded_bytes(&self) -> &[u8] {
+        self.inner.as_slice()
+    }
+
+    /// Unwraps this `ROsString` into the bytes in the platform-specific encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec, std_types::ROsString};
+    ///
+    /// use std::ffi::OsStr;
+    ///
+    /// let string = ROsString::from(OsStr::new("foo"));
+    ///
+    /// assert_eq!(string.into_encoded_bytes(), rvec![b'f', b'o', b'o']);
+    ///
+    /// ```
+    pub fn into_encoded_bytes(self) -> RVec<u8> {
+        self.inner
+    }
+
+    /// Borrows this `ROsString` as an `ROsStr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsString;
+    ///
+    /// use std::ffi::OsStr;
+    ///
+    /// let string = ROsString::from(OsStr::new("foo"));
+    ///
+    /// assert_eq!(string.as_ros_str().to_str(), Some("foo"));
+    ///
+    /// ```
+    pub fn as_ros_str(&self) -> ROsStr<'_> {
+        ROsStr::from_encoded_bytes(self.as_encoded_bytes())
+    }
+
+    /// Gets the string as a `&str`,
+    /// returning None if it isn't valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec, std_types::ROsString};
+    ///
+    /// assert_eq!(ROsString::from_encoded_bytes(rvec![b'a']).to_str(), Some("a"));
+    /// assert_eq!(ROsString::from_encoded_bytes(rvec![0xFF]).to_str(), None);
+    ///
+    /// ```
+    pub fn to_str(&self) -> Option<&str> {
+        str::from_utf8(self.as_encoded_bytes()).ok()
+    }
+
+    /// Whether the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsString;
+    ///
+    /// use std::ffi::OsStr;
+    ///
+    /// assert!(ROsString::new().is_empty());
+    /// assert!(!ROsString::from(OsStr::new("a")).is_empty());
+    ///
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Converts this `ROsString` into an `OsString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::ROsString;
+    ///
+    /// use std::ffi::{OsStr, OsString};
+    ///
+    /// let string = ROsString::from(OsStr::new("bar"));
+    ///
+    /// assert_eq!(string.into_os_string(), OsString::from("bar"));
+    ///
+    /// ```
+    pub fn into_os_string(self) -> OsString {
+        self.into()
+    }
+}
+
+impl Debug for ROsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.as_ros_str(), f)
+    }
+}
+
+impl_from_rust_repr! {
+    impl From<OsString> for ROsString {
+        fn(this){
+            ROsString::from_encoded_bytes(encoding::encode_owned(this))
+        }
+    }
+}
+
+impl From<&OsStr> for ROsString {
+    fn from(this: &OsStr) -> Self {
+        ROsString::from_encoded_bytes(encoding::encode(this))
+    }
+}
+
+impl_into_rust_repr! {
+    impl Into<OsString> for ROsString {
+        fn(this){
+            encoding::decode_owned(this.inner)
+        }
+    }
+}
+
+impl<'a> From<&'a ROsString> for ROsStr<'a> {
+    fn from(this: &'a ROsString) -> Self {
+        this.as_ros_str()
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(unix)]
+mod encoding {
+    use super::*;
+
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    pub(super) fn encode(str: &OsStr) -> RVec<u8> {
+        str.as_bytes().into()
+    }
+
+    pub(super) fn encode_owned(string: OsString) -> RVec<u8> {
+        string.into_vec().into()
+    }
+
+    pub(super) fn decode(bytes: &[u8]) -> OsString {
+        OsStr::from_bytes(bytes).to_os_string()
+    }
+
+    pub(super) fn decode_owned(bytes: RVec<u8>) -> OsString {
+        OsString::from_vec(bytes.into_vec())
+    }
+}
+
+#[cfg(windows)]
+mod encoding {
+    use super::*;
+
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+    pub(super) fn encode(str: &OsStr) -> RVec<u8> {
+        super::wtf8::encode_wide(str.encode_wide()).into()
+    }
+
+    pub(super) fn encode_owned(string: OsString) -> RVec<u8> {
+        encode(&string)
+    }
+
+    pub(super) fn decode(bytes: &[u8]) -> OsString {
+        OsString::from_wide(&super::wtf8::decode_wide(bytes))
+    }
+
+    pub(super) fn decode_owned(bytes: RVec<u8>) -> OsString {
+        decode(&bytes)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod encoding {
+    use super::*;
+
+    pub(super) fn encode(str: &OsStr) -> RVec<u8> {
+        str.to_string_lossy().as_bytes().into()
+    }
+
+    pub(super) fn encode_owned(string: OsString) -> RVec<u8> {
+        match string.into_string() {
+            Ok(x) => x.into_bytes().into(),
+            Err(x) => encode(&x),
+        }
+    }
+
+    pub(super) fn decode(bytes: &[u8]) -> OsString {
+        String::from_utf8_lossy(bytes).into_owned().into()
+    }
+
+    pub(super) fn decode_owned(bytes: RVec<u8>) -> OsString {
+        match String::from_utf8(bytes.into_vec()) {
+            Ok(x) => x.into(),
+            Err(e) => decode(e.as_bytes()),
+        }
+    }
+}
+
+/// Conversions between UTF-16 (with unpaired surrogates allowed) and WTF-8.
+#[cfg(any(windows, all(test, not(feature = "only_new_tests"))))]
+mod wtf8 {
+    use std::char;
+
+    pub(super) fn encode_wide<I>(iter: I) -> Vec<u8>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let iter = iter.into_iter();
+        let mut out = Vec::with_capacity(iter.size_hint().0);
+        for res in char::decode_utf16(iter) {
+            match res {
+                Ok(c) => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                Err(e) => {
+                    let s = e.unpaired_surrogate();
+                    out.extend_from_slice(&[
+                        0xE0 | (s >> 12) as u8,
+                        0x80 | ((s >> 6) & 0x3F) as u8,
+                        0x80 | (s & 0x3F) as u8,
+                    ]);
+                }
+            }
+        }
+        out
+    }
+
+    /// Decodes WTF-8 into UTF-16,
+    /// replacing invalid sequences with `U+FFFD`.
+    pub(super) fn decode_wide(bytes: &[u8]) -> Vec<u16> {
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while let Some(&first) = bytes.get(i) {
+            let (len, min, init) = match first {
+                0x00..=0x7F => (1, 0, first as u32),
+                0xC0..=0xDF => (2, 0x80, (first & 0x1F) as u32),
+                0xE0..=0xEF => (3, 0x800, (first & 0x0F) as u32),
+                0xF0..=0xF7 => (4, 0x10000, (first & 0x07) as u32),
+                _ => (0, 0, 0),
+            };
+
+            let code_point = bytes
+                .get(i + 1..i + len)
+                .filter(|_| len != 0)
+                .and_then(|cont| {
+                    cont.iter().try_fold(init, |acc, &b| {
+                        if b & 0xC0 == 0x80 {
+                            Some((acc << 6) | (b & 0x3F) as u32)
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .filter(|&cp| min <= cp && cp <= 0x10FFFF);
+
+            match code_point {
+                Some(cp) if cp >= 0x10000 => {
+                    let cp = cp - 0x10000;
+                    out.push(0xD800 | (cp >> 10) as u16);
+                    out.push(0xDC00 | (cp & 0x3FF) as u16);
+                    i += len;
+                }
+                Some(cp) => {
+                    out.push(cp as u16);
+                    i += len;
+                }
+                None => {
+                    out.push(0xFFFD);
+                    i += 1;
+                }
+            }
+        }
+        out
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn utf8_roundtrip() {
+        let os = OsString::from("hello wörld");
+
+        let string = ROsString::from(os.clone());
+        assert_eq!(string.as_encoded_bytes(), "hello wörld".as_bytes());
+        assert_eq!(string.to_str(), Some("hello wörld"));
+        assert_eq!(string.as_ros_str().to_os_string(), os);
+        assert_eq!(string.as_ros_str().to_ros_string(), string);
+        assert_eq!(string.into_os_string(), os);
+
+        assert_eq!(ROsString::from(&*os).into_os_string(), os);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_roundtrip() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let bytes: &[u8] = b"foo\xFF\xFEbar";
+        let os = OsStr::from_bytes(bytes);
+
+        let str = ROsStr::from(os);
+        assert_eq!(str.as_encoded_bytes(), bytes);
+        assert_eq!(str.to_str(), None);
+        assert_eq!(str.to_os_string(), os);
+
+        let string = ROsString::from(os.to_os_string());
+        assert_eq!(string.as_encoded_bytes(), bytes);
+        assert_eq!(string.to_str(), None);
+        assert_eq!(string.as_ros_str(), str);
+        assert_eq!(string.into_os_string(), os);
+    }
+
+    #[test]
+    fn wtf8_roundtrip() {
+        let wide: &[u16] = &[
+            b'a' as u16,
+            0xD800, // unpaired high surrogate
+            0x00E9,
+            0xD83D, // surrogate pair
+            0xDE00,
+            0xDC00, // unpaired low surrogate
+            0x4E2D,
+        ];
+
+        let bytes = wtf8::encode_wide(wide.iter().copied());
+        assert_eq!(
+            bytes,
+            b"a\xED\xA0\x80\xC3\xA9\xF0\x9F\x98\x80\xED\xB0\x80\xE4\xB8\xAD"
+        );
+        assert_eq!(wtf8::decode_wide(&bytes), wide);
+    }
+
+    #[test]
+    fn wtf8_decode_invalid() {
+        let invalid = |bytes: &[u8], expected: &[u16]| {
+            assert_eq!(wtf8::decode_wide(bytes), expected);
+        };
+
+        invalid(b"a\xFFb", &[b'a' as u16, 0xFFFD, b'b' as u16]);
+        invalid(b"\xC3", &[0xFFFD]);
+        invalid(b"\xC0\x80", &[0xFFFD, 0xFFFD]);
+        invalid(b"\xE4\xB8", &[0xFFFD, 0xFFFD]);
+        invalid(b"\xF4\x90\x80\x80", &[0xFFFD; 4]);
+    }
+}
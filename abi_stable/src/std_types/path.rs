@@ -0,0 +1,370 @@
+//! Contains ffi-safe equivalents of `&'a Path` and `PathBuf`.
+
+use std::{
+    fmt::{self, Debug},
+    path::{Path, PathBuf},
+};
+
+use crate::std_types::{ROsStr, ROsString};
+
+/// Ffi-safe equivalent of `&'a Path`.
+///
+/// The path is stored in the platform-specific encoding described in the
+/// [`ROsStr` docs](crate::std_types::ROsStr#encoding).
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::{
+///     sabi_extern_fn,
+///     std_types::{RPath, RPathBuf},
+/// };
+///
+/// use std::path::{Path, PathBuf};
+///
+/// #[sabi_extern_fn]
+/// fn file_name_len(path: RPath<'_>) -> usize {
+///     let path = path.to_path_buf();
+///     path.file_name().map_or(0, |name| name.len())
+/// }
+///
+/// let path = RPathBuf::from(Path::new("foo/bar.txt"));
+///
+/// assert_eq!(file_name_len(path.as_rpath()), 7);
+///
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, StableAbi)]
+pub struct RPath<'a> {
+    inner: ROsStr<'a>,
+}
+
+impl<'a> RPath<'a> {
+    /// Constructs an `RPath` from an `ROsStr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{ROsStr, RPath};
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPath::new(ROsStr::from_encoded_bytes(b"foo/bar"));
+    ///
+    /// assert_eq!(path.to_path_buf(), Path::new("foo/bar"));
+    ///
+    /// ```
+    pub const fn new(str: ROsStr<'a>) -> Self {
+        Self { inner: str }
+    }
+
+    /// Gets the path as an `ROsStr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{ROsStr, RPath};
+    ///
+    /// let path = RPath::new(ROsStr::from_encoded_bytes(b"foo/bar"));
+    ///
+    /// assert_eq!(path.as_ros_str().as_encoded_bytes(), b"foo/bar");
+    ///
+    /// ```
+    pub const fn as_ros_str(&self) -> ROsStr<'a> {
+        self.inner
+    }
+
+    /// Gets the path as a `&str`,
+    /// returning None if it isn't valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{ROsStr, RPath};
+    ///
+    /// let path = RPath::new(ROsStr::from_encoded_bytes(b"foo/bar"));
+    ///
+    /// assert_eq!(path.to_str(), Some("foo/bar"));
+    ///
+    /// ```
+    pub fn to_str(&self) -> Option<&'a str> {
+        self.inner.to_str()
+    }
+
+    /// Converts this `RPath` into a `PathBuf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RPathBuf;
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPathBuf::from(Path::new("baz/qux"));
+    ///
+    /// assert_eq!(path.as_rpath().to_path_buf(), Path::new("baz/qux"));
+    ///
+    /// ```
+    pub fn to_path_buf(&self) -> PathBuf {
+        self.inner.to_os_string().into()
+    }
+
+    /// Copies this `RPath` into an `RPathBuf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{ROsStr, RPath};
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPath::new(ROsStr::from_encoded_bytes(b"foo"));
+    ///
+    /// assert_eq!(path.to_rpath_buf().into_path_buf(), Path::new("foo"));
+    ///
+    /// ```
+    pub fn to_rpath_buf(&self) -> RPathBuf {
+        RPathBuf::new(self.inner.to_ros_string())
+    }
+}
+
+impl Debug for RPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.to_path_buf(), f)
+    }
+}
+
+/// Only available on Unix, since the encoding of a `Path` on other platforms
+/// differs from the encoding of `RPath`.
+#[cfg(unix)]
+#[cfg_attr(feature = "docsrs", doc(cfg(unix)))]
+impl<'a> From<&'a Path> for RPath<'a> {
+    fn from(path: &'a Path) -> Self {
+        Self::new(path.as_os_str().into())
+    }
+}
+
+impl<'a> From<&'a RPathBuf> for RPath<'a> {
+    fn from(path: &'a RPathBuf) -> Self {
+        path.as_rpath()
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////////
+
+/// Ffi-safe equivalent of `PathBuf`.
+///
+/// The path is stored in the platform-specific encoding described in the
+/// [`ROsStr` docs](crate::std_types::ROsStr#encoding).
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::{sabi_extern_fn, std_types::RPathBuf};
+///
+/// use std::path::{Path, PathBuf};
+///
+/// #[sabi_extern_fn]
+/// fn config_path(dir: RPathBuf) -> RPathBuf {
+///     dir.into_path_buf().join("config.toml").into()
+/// }
+///
+/// let dir = RPathBuf::from(PathBuf::from("app"));
+///
+/// assert_eq!(
+///     config_path(dir).into_path_buf(),
+///     Path::new("app").join("config.toml"),
+/// );
+///
+/// ```
+#[repr(C)]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, StableAbi)]
+pub struct RPathBuf {
+    inner: ROsString,
+}
+
+impl RPathBuf {
+    /// Constructs an `RPathBuf` from an `ROsString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{
+    ///     rvec,
+    ///     std_types::{ROsString, RPathBuf},
+    /// };
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPathBuf::new(ROsString::from_encoded_bytes(rvec![b'a']));
+    ///
+    /// assert_eq!(path.into_path_buf(), Path::new("a"));
+    ///
+    /// ```
+    pub const fn new(string: ROsString) -> Self {
+        Self { inner: string }
+    }
+
+    /// Borrows this `RPathBuf` as an `RPath`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RPathBuf;
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPathBuf::from(Path::new("foo/bar"));
+    ///
+    /// assert_eq!(path.as_rpath().to_str(), Some("foo/bar"));
+    ///
+    /// ```
+    pub fn as_rpath(&self) -> RPath<'_> {
+        RPath::new(self.inner.as_ros_str())
+    }
+
+    /// Gets the path as an `ROsStr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RPathBuf;
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPathBuf::from(Path::new("foo/bar"));
+    ///
+    /// assert_eq!(path.as_ros_str().as_encoded_bytes(), b"foo/bar");
+    ///
+    /// ```
+    pub fn as_ros_str(&self) -> ROsStr<'_> {
+        self.inner.as_ros_str()
+    }
+
+    /// Unwraps this `RPathBuf` into an `ROsString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RPathBuf;
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPathBuf::from(Path::new("foo/bar"));
+    ///
+    /// assert_eq!(path.into_ros_string().to_str(), Some("foo/bar"));
+    ///
+    /// ```
+    pub fn into_ros_string(self) -> ROsString {
+        self.inner
+    }
+
+    /// Gets the path as a `&str`,
+    /// returning None if it isn't valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RPathBuf;
+    ///
+    /// use std::path::Path;
+    ///
+    /// let path = RPathBuf::from(Path::new("foo/bar"));
+    ///
+    /// assert_eq!(path.to_str(), Some("foo/bar"));
+    ///
+    /// ```
+    pub fn to_str(&self) -> Option<&str> {
+        self.inner.to_str()
+    }
+
+    /// Converts this `RPathBuf` into a `PathBuf`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RPathBuf;
+    ///
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let path = RPathBuf::from(PathBuf::from("foo/bar"));
+    ///
+    /// assert_eq!(path.into_path_buf(), Path::new("foo/bar"));
+    ///
+    /// ```
+    pub fn into_path_buf(self) -> PathBuf {
+        self.into()
+    }
+}
+
+impl Debug for RPathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.as_rpath(), f)
+    }
+}
+
+impl_from_rust_repr! {
+    impl From<PathBuf> for RPathBuf {
+        fn(this){
+            RPathBuf::new(this.into_os_string().into())
+        }
+    }
+}
+
+impl From<&Path> for RPathBuf {
+    fn from(this: &Path) -> Self {
+        RPathBuf::new(this.as_os_str().into())
+    }
+}
+
+impl_into_rust_repr! {
+    impl Into<PathBuf> for RPathBuf {
+        fn(this){
+            this.inner.into_os_string().into()
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn utf8_roundtrip() {
+        let path = Path::new("foo").join("bär.txt");
+
+        let rpath = RPathBuf::from(path.clone());
+        assert_eq!(rpath.to_str(), path.to_str());
+        assert_eq!(rpath.as_rpath().to_path_buf(), path);
+        assert_eq!(rpath.as_rpath().to_rpath_buf(), rpath);
+        assert_eq!(rpath.into_path_buf(), path);
+
+        assert_eq!(RPathBuf::from(&*path).into_path_buf(), path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_roundtrip() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let bytes: &[u8] = b"/tmp/\xC3\x28/file\xFF.txt";
+        let path = Path::new(OsStr::from_bytes(bytes));
+        assert_eq!(path.to_str(), None);
+
+        let borrowed = RPath::from(path);
+        assert_eq!(borrowed.as_ros_str().as_encoded_bytes(), bytes);
+        assert_eq!(borrowed.to_str(), None);
+        assert_eq!(borrowed.to_path_buf(), path);
+
+        let owned = RPathBuf::from(path.to_path_buf());
+        assert_eq!(owned.as_ros_str().as_encoded_bytes(), bytes);
+        assert_eq!(owned.as_rpath(), borrowed);
+        assert_eq!(owned.clone().into_path_buf(), path);
+        assert_eq!(
+            owned.into_path_buf().file_name(),
+            Some(OsStr::from_bytes(b"file\xFF.txt"))
+        );
+    }
+}
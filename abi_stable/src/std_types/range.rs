@@ -1,6 +1,6 @@
 //! Contains the ffi-safe equivalent of `std::ops::Range*` types.
 
-use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use std::ops::{Bound, Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
 
 ////////////////////////////////////////////////////////////////
 
@@ -24,15 +24,47 @@ macro_rules! impl_into_iterator {
 ////////////////////////////////////////////////////////////////
 
 /// Ffi-safe equivalent of `::std::ops::Range`
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::{RRange, RVec};
+///
+/// let range = RRange::from(1..3);
+///
+/// let mut list = RVec::from(vec![3, 5, 8, 13]);
+/// assert_eq!(list.slice(range), [5, 8]);
+///
+/// let drained = list.drain(range).collect::<Vec<_>>();
+/// assert_eq!(drained, [5, 8]);
+/// assert_eq!(list, [3, 13]);
+///
+/// assert_eq!(range.into_iter().collect::<Vec<_>>(), [1, 2]);
+///
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 #[repr(C)]
 #[derive(StableAbi)]
 pub struct RRange<T> {
+    /// The lower bound of the range (inclusive).
     pub start: T,
+    /// The upper bound of the range (exclusive).
     pub end: T,
 }
 
 impl RRange<usize> {
+    /// Converts a `std::ops::Range<usize>` to an `RRange<usize>`, usable in const contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRange;
+    ///
+    /// const RANGE: RRange<usize> = RRange::from_std(3..5);
+    ///
+    /// assert_eq!(RANGE, RRange { start: 3, end: 5 });
+    ///
+    /// ```
     pub const fn from_std(v: Range<usize>) -> Self {
         Self {
             start: v.start,
@@ -65,14 +97,45 @@ impl_into_rust_repr! {
 
 impl_into_iterator! { RRange, Range }
 
+impl<T> RangeBounds<T> for RRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Excluded(&self.end)
+    }
+}
+
 ////////////////////////////////////////////////////////////////
 
 /// Ffi-safe equivalent of `::std::ops::RangeInclusive`
+///
+/// Unlike `RangeInclusive`, this doesn't track whether iteration is exhausted,
+/// it's only a pair of bounds.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::{RRangeInclusive, RVec};
+///
+/// let range = RRangeInclusive::from(1..=2);
+///
+/// let mut list = RVec::from(vec![3, 5, 8, 13]);
+/// assert_eq!(list.slice(range), [5, 8]);
+///
+/// let drained = list.drain(range).collect::<Vec<_>>();
+/// assert_eq!(drained, [5, 8]);
+/// assert_eq!(list, [3, 13]);
+///
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 #[repr(C)]
 #[derive(StableAbi)]
 pub struct RRangeInclusive<T> {
+    /// The lower bound of the range (inclusive).
     pub start: T,
+    /// The upper bound of the range (inclusive).
     pub end: T,
 }
 
@@ -95,6 +158,16 @@ impl_into_rust_repr! {
 
 impl_into_iterator! { RRangeInclusive, RangeInclusive }
 
+impl<T> RangeBounds<T> for RRangeInclusive<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.end)
+    }
+}
+
 ////////////////////////////////////////////////////////////////
 
 /// Ffi-safe equivalent of `::std::ops::RangeFrom`
@@ -102,6 +175,7 @@ impl_into_iterator! { RRangeInclusive, RangeInclusive }
 #[repr(C)]
 #[derive(StableAbi)]
 pub struct RRangeFrom<T> {
+    /// The lower bound of the range (inclusive).
     pub start: T,
 }
 
@@ -123,6 +197,16 @@ impl_into_rust_repr! {
 
 impl_into_iterator! { RRangeFrom, RangeFrom }
 
+impl<T> RangeBounds<T> for RRangeFrom<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Unbounded
+    }
+}
+
 ////////////////////////////////////////////////////////////////
 
 /// Ffi-safe equivalent of `::std::ops::RangeTo`
@@ -130,6 +214,7 @@ impl_into_iterator! { RRangeFrom, RangeFrom }
 #[repr(C)]
 #[derive(StableAbi)]
 pub struct RRangeTo<T> {
+    /// The upper bound of the range (exclusive).
     pub end: T,
 }
 
@@ -149,6 +234,16 @@ impl_into_rust_repr! {
     }
 }
 
+impl<T> RangeBounds<T> for RRangeTo<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Excluded(&self.end)
+    }
+}
+
 ////////////////////////////////////////////////////////////////
 
 /// Ffi-safe equivalent of `::std::ops::RangeToInclusive`
@@ -156,6 +251,7 @@ impl_into_rust_repr! {
 #[repr(C)]
 #[derive(StableAbi)]
 pub struct RRangeToInclusive<T> {
+    /// The upper bound of the range (inclusive).
     pub end: T,
 }
 
@@ -175,4 +271,100 @@ impl_into_rust_repr! {
     }
 }
 
+impl<T> RangeBounds<T> for RRangeToInclusive<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.end)
+    }
+}
+
 ////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    use crate::{
+        std_types::{RSlice, RVec},
+        traits::{IntoReprC, IntoReprRust},
+    };
+
+    fn bounds<R: RangeBounds<usize>>(range: &R) -> (Bound<&usize>, Bound<&usize>) {
+        (range.start_bound(), range.end_bound())
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!((1..3).into_c(), RRange { start: 1, end: 3 });
+        assert_eq!(RRange { start: 1, end: 3 }.into_rust(), 1..3);
+
+        assert_eq!((1..=3).into_c(), RRangeInclusive { start: 1, end: 3 });
+        assert_eq!(RRangeInclusive { start: 1, end: 3 }.into_rust(), 1..=3);
+
+        assert_eq!((1..).into_c(), RRangeFrom { start: 1 });
+        assert_eq!(RRangeFrom { start: 1 }.into_rust(), 1..);
+
+        assert_eq!((..3).into_c(), RRangeTo { end: 3 });
+        assert_eq!(RRangeTo { end: 3 }.into_rust(), ..3);
+
+        assert_eq!((..=3).into_c(), RRangeToInclusive { end: 3 });
+        assert_eq!(RRangeToInclusive { end: 3 }.into_rust(), ..=3);
+    }
+
+    #[test]
+    fn range_bounds() {
+        assert_eq!(bounds(&RRange::from(1..3)), bounds(&(1..3)));
+        assert_eq!(bounds(&RRangeInclusive::from(1..=3)), bounds(&(1..=3)));
+        assert_eq!(bounds(&RRangeFrom::from(1..)), bounds(&(1..)));
+        assert_eq!(bounds(&RRangeTo::from(..3)), bounds(&(..3)));
+        assert_eq!(bounds(&RRangeToInclusive::from(..=3)), bounds(&(..=3)));
+
+        assert!(RRange::from(1..3).contains(&2));
+        assert!(!RRange::from(1..3).contains(&3));
+        assert!(RRangeInclusive::from(1..=3).contains(&3));
+    }
+
+    #[test]
+    fn indexing() {
+        let arr = [3, 5, 8, 13, 21];
+        let slice = RSlice::from_slice(&arr);
+
+        assert_eq!(slice.slice(RRange::from(1..3).into_rust()), rslice![5, 8]);
+        assert_eq!(
+            slice.slice(RRangeInclusive::from(1..=3).into_rust()),
+            rslice![5, 8, 13]
+        );
+        assert_eq!(
+            slice.slice(RRangeFrom::from(3..).into_rust()),
+            rslice![13, 21]
+        );
+        assert_eq!(slice.slice(RRangeTo::from(..2).into_rust()), rslice![3, 5]);
+        assert_eq!(
+            slice.slice(RRangeToInclusive::from(..=0).into_rust()),
+            rslice![3]
+        );
+
+        let list = RVec::from_slice(&arr);
+        assert_eq!(list.slice(RRange::from(1..3)), rslice![5, 8]);
+        assert_eq!(list.slice(RRangeInclusive::from(1..=3)), rslice![5, 8, 13]);
+        assert_eq!(list.slice(RRangeFrom::from(3..)), rslice![13, 21]);
+        assert_eq!(list.slice(RRangeTo::from(..2)), rslice![3, 5]);
+        assert_eq!(list.slice(RRangeToInclusive::from(..=0)), rslice![3]);
+    }
+
+    #[test]
+    fn draining() {
+        let mut list = RVec::from(vec![3, 5, 8, 13, 21]);
+
+        let drained = list.drain(RRangeInclusive::from(1..=2)).collect::<Vec<_>>();
+        assert_eq!(drained, [5, 8]);
+        assert_eq!(list, [3, 13, 21]);
+
+        let drained = list.drain(RRangeFrom::from(1..)).collect::<Vec<_>>();
+        assert_eq!(drained, [13, 21]);
+        assert_eq!(list, [3]);
+    }
+}
@@ -0,0 +1,173 @@
+//! Implementations of `rayon` traits for the ffi-safe std types,
+//! enabled by the "rayon" feature.
+//!
+//! These expose the elements as a slice (or a `Vec`) to `rayon`,
+//! so the work is only parallelized within the current process.
+
+use rayon::{
+    iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator},
+    slice::{Iter, IterMut},
+    vec::IntoIter,
+};
+
+use crate::std_types::{RSlice, RSliceMut, RVec};
+
+impl<T> IntoParallelIterator for RVec<T>
+where
+    T: Send,
+{
+    type Iter = IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_vec().into_par_iter()
+    }
+}
+
+impl<'a, T> IntoParallelIterator for &'a RVec<T>
+where
+    T: Sync,
+{
+    type Iter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T> IntoParallelIterator for &'a mut RVec<T>
+where
+    T: Send,
+{
+    type Iter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().into_par_iter()
+    }
+}
+
+impl<T> FromParallelIterator<T> for RVec<T>
+where
+    T: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        Vec::from_par_iter(par_iter).into()
+    }
+}
+
+impl<T> ParallelExtend<T> for RVec<T>
+where
+    T: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let list = par_iter.into_par_iter().collect::<Vec<T>>();
+        self.extend(list);
+    }
+}
+
+impl<'a, T> IntoParallelIterator for RSlice<'a, T>
+where
+    T: Sync,
+{
+    type Iter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T> IntoParallelIterator for &RSlice<'a, T>
+where
+    T: Sync,
+{
+    type Iter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T> IntoParallelIterator for RSliceMut<'a, T>
+where
+    T: Send,
+{
+    type Iter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_mut_slice().into_par_iter()
+    }
+}
+
+impl<'a, T> IntoParallelIterator for &'a mut RSliceMut<'_, T>
+where
+    T: Send,
+{
+    type Iter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().into_par_iter()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(all(test, not(feature = "only_new_tests")))]
+mod tests {
+    use super::*;
+
+    use rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator};
+
+    use crate::std_types::RString;
+
+    #[test]
+    fn sum_rvec() {
+        let list = (1..=10_000u64).collect::<RVec<u64>>();
+        let expected = 10_000 * 10_001 / 2;
+
+        assert_eq!(list.par_iter().sum::<u64>(), expected);
+        assert_eq!(list.as_rslice().par_iter().sum::<u64>(), expected);
+        assert_eq!(list.as_rslice().into_par_iter().sum::<u64>(), expected);
+        assert_eq!(list.into_par_iter().sum::<u64>(), expected);
+    }
+
+    #[test]
+    fn mutate_in_parallel() {
+        let mut list = (0..1000u32).collect::<RVec<u32>>();
+
+        list.par_iter_mut().for_each(|x| *x *= 2);
+        assert!(list.iter().copied().eq((0..1000).map(|x| x * 2)));
+
+        let mut slice = list.as_mut_rslice();
+        slice.par_iter_mut().for_each(|x| *x += 1);
+        slice.into_par_iter().for_each(|x| *x *= 3);
+        assert!(list.iter().copied().eq((0..1000).map(|x| (x * 2 + 1) * 3)));
+    }
+
+    #[test]
+    fn collect_and_extend() {
+        let list = (0..100u32)
+            .into_par_iter()
+            .map(|x| RString::from(x.to_string()))
+            .collect::<RVec<RString>>();
+        assert!(list
+            .iter()
+            .map(|s| s.as_str())
+            .eq((0..100).map(|x| x.to_string())));
+
+        let mut list = RVec::from(vec![0u32, 1]);
+        list.par_extend((2..50u32).into_par_iter());
+        assert!(list.iter().copied().eq(0..50));
+    }
+}
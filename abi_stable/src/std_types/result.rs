@@ -2,18 +2,20 @@
 
 use std::fmt::Debug;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::std_types::{RNone, ROption, RSome};
 
 /// Ffi-safe equivalent of `Result<T, E>`.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+///
+/// This is serialized and deserialized the same way as `Result<T, E>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[repr(u8)]
 #[derive(StableAbi)]
 pub enum RResult<T, E> {
     ///
-    #[serde(rename = "Ok")]
     ROk(T),
     ///
-    #[serde(rename = "Err")]
     RErr(E),
 }
 
@@ -185,6 +187,80 @@ impl<T, E> RResult<T, E> {
         }
     }
 
+    /// Calls the `op` closure with a reference to the value in `ROk`, if there is one,
+    /// returning the `RResult<T, E>` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RErr, ROk, RResult, RString};
+    ///
+    /// fn send_command(command: &str) -> RResult<RString, RString> {
+    ///     match command {
+    ///         "reverse" => ROk(RString::from("done")),
+    ///         _ => RErr(format!("unknown command: {}", command).into()),
+    ///     }
+    /// }
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let res = send_command("reverse").inspect(|x| log.push(format!("response: {}", x)));
+    /// assert_eq!(res, ROk(RString::from("done")));
+    ///
+    /// let res = send_command("foo").inspect(|x| log.push(format!("response: {}", x)));
+    /// assert_eq!(res, RErr(RString::from("unknown command: foo")));
+    ///
+    /// assert_eq!(log, ["response: done"]);
+    ///
+    /// ```
+    #[inline]
+    pub fn inspect<F>(self, op: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let ROk(t) = &self {
+            op(t);
+        }
+        self
+    }
+
+    /// Calls the `op` closure with a reference to the value in `RErr`, if there is one,
+    /// returning the `RResult<T, E>` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RErr, ROk, RResult, RString};
+    ///
+    /// fn send_command(command: &str) -> RResult<RString, RString> {
+    ///     match command {
+    ///         "reverse" => ROk(RString::from("done")),
+    ///         _ => RErr(format!("unknown command: {}", command).into()),
+    ///     }
+    /// }
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let res = send_command("reverse").inspect_err(|e| log.push(format!("error: {}", e)));
+    /// assert_eq!(res, ROk(RString::from("done")));
+    ///
+    /// let res = send_command("foo").inspect_err(|e| log.push(format!("error: {}", e)));
+    /// assert_eq!(res, RErr(RString::from("unknown command: foo")));
+    ///
+    /// assert_eq!(log, ["error: unknown command: foo"]);
+    ///
+    /// ```
+    #[inline]
+    pub fn inspect_err<F>(self, op: F) -> Self
+    where
+        F: FnOnce(&E),
+    {
+        if let RErr(e) = &self {
+            op(e);
+        }
+        self
+    }
+
     /// Converts the `RResult<T, E>` to a `U` by
     /// transforming the value in `ROk` using the `with_ok` closure,
     /// otherwise transforming the value in RErr using the `with_err` closure,
@@ -527,6 +603,169 @@ impl_into_rust_repr! {
     }
 }
 
+/// Collects an iterator of `RResult`s into an `RResult` of a collection,
+/// stopping at the first `RErr`, like the equivalent impl for `Result` does.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::{
+///     rvec,
+///     std_types::{RErr, ROk, RResult, RVec},
+/// };
+///
+/// let parse = |s: &str| -> RResult<u32, String> { s.parse().map_err(|_| s.to_string()).into() };
+///
+/// let parsed = ["3", "5", "8"].iter().map(|s| parse(s));
+/// assert_eq!(parsed.collect::<RResult<RVec<u32>, _>>(), ROk(rvec![3, 5, 8]));
+///
+/// let parsed = ["3", "five", "8", "thirteen"].iter().map(|s| parse(s));
+/// assert_eq!(parsed.collect::<RResult<RVec<u32>, _>>(), RErr("five".to_string()));
+///
+/// ```
+impl<T, E, C> FromIterator<RResult<T, E>> for RResult<C, E>
+where
+    C: FromIterator<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = RResult<T, E>>,
+    {
+        iter.into_iter()
+            .map(RResult::into_result)
+            .collect::<Result<C, E>>()
+            .into()
+    }
+}
+
+impl<'de, T, E> Deserialize<'de> for RResult<T, E>
+where
+    T: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Result::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T, E> Serialize for RResult<T, E>
+where
+    T: Serialize,
+    E: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_ref().into_result().serialize(serializer)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////
+
+/// Allows using the `?` operator on `RResult`,
+/// converting the error with `From` like `Result` does.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::{RErr, ROk, RResult};
+///
+/// fn parse(s: &str) -> RResult<u32, String> {
+///     s.parse::<u32>().map_err(|e| e.to_string()).into()
+/// }
+///
+/// fn sum(l: &str, r: &str) -> Result<u32, String> {
+///     Ok(parse(l)? + parse(r)?)
+/// }
+///
+/// fn rsum(l: &str, r: &str) -> RResult<u32, String> {
+///     let l: u32 = l.parse().map_err(|e: std::num::ParseIntError| e.to_string())?;
+///     ROk(l + parse(r)?)
+/// }
+///
+/// assert_eq!(sum("3", "5"), Ok(8));
+/// assert!(sum("3", "?").is_err());
+///
+/// assert_eq!(rsum("3", "5"), ROk(8));
+/// assert!(rsum("?", "5").is_err());
+///
+/// ```
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T, E> std::ops::Try for RResult<T, E> {
+    type Output = T;
+    type Residual = RResult<std::convert::Infallible, E>;
+
+    #[inline]
+    fn from_output(output: T) -> Self {
+        ROk(output)
+    }
+
+    #[inline]
+    fn branch(self) -> std::ops::ControlFlow<Self::Residual, T> {
+        use std::ops::ControlFlow;
+        match self {
+            ROk(v) => ControlFlow::Continue(v),
+            RErr(e) => ControlFlow::Break(RErr(e)),
+        }
+    }
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T, E> std::ops::Residual<T> for RResult<std::convert::Infallible, E> {
+    type TryType = RResult<T, E>;
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T, E, F> std::ops::FromResidual<RResult<std::convert::Infallible, E>> for RResult<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: RResult<std::convert::Infallible, E>) -> Self {
+        match residual {
+            ROk(x) => match x {},
+            RErr(e) => RErr(From::from(e)),
+        }
+    }
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T, E, F> std::ops::FromResidual<Result<std::convert::Infallible, E>> for RResult<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Result<std::convert::Infallible, E>) -> Self {
+        match residual {
+            Ok(x) => match x {},
+            Err(e) => RErr(From::from(e)),
+        }
+    }
+}
+
+#[cfg(feature = "nightly_try")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "nightly_try")))]
+impl<T, E, F> std::ops::FromResidual<RResult<std::convert::Infallible, E>> for Result<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: RResult<std::convert::Infallible, E>) -> Self {
+        match residual {
+            ROk(x) => match x {},
+            RErr(e) => Err(From::from(e)),
+        }
+    }
+}
+
 /////////////////////////////////////////////////////////////////////
 
 //#[cfg(test)]
@@ -534,6 +773,33 @@ impl_into_rust_repr! {
 mod test {
     use super::*;
 
+    #[test]
+    fn serde_matches_std() {
+        let cases: [(RResult<u32, String>, Result<u32, String>); 2] =
+            [(ROk(3), Ok(3)), (RErr("hello".into()), Err("hello".into()))];
+
+        for (rresult, result) in cases {
+            let json = serde_json::to_string(&result).unwrap();
+            assert_eq!(serde_json::to_string(&rresult).unwrap(), json);
+            assert_eq!(
+                serde_json::from_str::<RResult<u32, String>>(&json).unwrap(),
+                rresult
+            );
+
+            let bytes = bincode::serialize(&result).unwrap();
+            assert_eq!(bincode::serialize(&rresult).unwrap(), bytes);
+            assert_eq!(
+                bincode::deserialize::<RResult<u32, String>>(&bytes).unwrap(),
+                rresult
+            );
+        }
+
+        assert_eq!(
+            serde_json::to_string(&ROk::<u32, ()>(5)).unwrap(),
+            r#"{"Ok":5}"#
+        );
+    }
+
     #[test]
     fn from_into() {
         assert_eq!(RResult::from(Ok::<u32, u32>(10)), ROk(10));
@@ -542,4 +808,57 @@ mod test {
         assert_eq!(ROk::<u32, u32>(10).into_result(), Ok(10));
         assert_eq!(RErr::<u32, u32>(4).into_result(), Err(4));
     }
+
+    #[test]
+    fn collect() {
+        use crate::std_types::RVec;
+
+        use std::cell::Cell;
+
+        let yielded = Cell::new(0);
+        let iter = |list: &'static [RResult<u32, u32>]| {
+            yielded.set(0);
+            list.iter().map(|x| {
+                yielded.set(yielded.get() + 1);
+                *x
+            })
+        };
+
+        let res = iter(&[ROk(3), ROk(5), ROk(8)]).collect::<RResult<RVec<u32>, u32>>();
+        assert_eq!(res, ROk(RVec::from(vec![3, 5, 8])));
+        assert_eq!(yielded.get(), 3);
+
+        let res = iter(&[]).collect::<RResult<RVec<u32>, u32>>();
+        assert_eq!(res, ROk(RVec::new()));
+
+        let res = iter(&[ROk(3), RErr(5), ROk(8), RErr(13)]).collect::<RResult<RVec<u32>, u32>>();
+        assert_eq!(res, RErr(5));
+        assert_eq!(yielded.get(), 2);
+
+        let res = iter(&[RErr(1)]).collect::<RResult<Vec<u32>, u32>>();
+        assert_eq!(res, RErr(1));
+    }
+
+    #[cfg(feature = "nightly_try")]
+    #[test]
+    fn question_mark() {
+        fn rresult_to_result(x: RResult<u32, u8>) -> Result<u32, u16> {
+            Ok(x? + 1)
+        }
+        fn result_to_rresult(x: Result<u32, u8>) -> RResult<u32, u16> {
+            ROk(x? + 1)
+        }
+        fn rresult_to_rresult(x: RResult<u32, u8>) -> RResult<u32, u16> {
+            ROk(x? + 1)
+        }
+
+        assert_eq!(rresult_to_result(ROk(3)), Ok(4));
+        assert_eq!(rresult_to_result(RErr(5)), Err(5));
+
+        assert_eq!(result_to_rresult(Ok(3)), ROk(4));
+        assert_eq!(result_to_rresult(Err(5)), RErr(5));
+
+        assert_eq!(rresult_to_rresult(ROk(3)), ROk(4));
+        assert_eq!(rresult_to_rresult(RErr(5)), RErr(5));
+    }
 }
@@ -330,6 +330,39 @@ impl<'a, T> RSliceMut<'a, T> {
         self.as_mut_slice().index_mut(i).into()
     }
 
+    /// Divides the slice into two mutable `RSliceMut`s at the `mid` index.
+    ///
+    /// The first slice contains the elements in `[0, mid)`,
+    /// the second slice contains the elements in `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSliceMut;
+    ///
+    /// let slic = &mut [0, 1, 2, 3];
+    /// let mut slic = RSliceMut::from_mut_slice(slic);
+    ///
+    /// {
+    ///     let (mut left, mut right) = slic.split_at_mut(1);
+    ///     left[0] = 10;
+    ///     right[2] = 13;
+    ///     assert_eq!(left, RSliceMut::from_mut_slice(&mut [10]));
+    ///     assert_eq!(right, RSliceMut::from_mut_slice(&mut [1, 2, 13]));
+    /// }
+    ///
+    /// assert_eq!(slic.as_slice(), &[10, 1, 2, 13]);
+    ///
+    /// ```
+    pub fn split_at_mut(&mut self, mid: usize) -> (RSliceMut<'_, T>, RSliceMut<'_, T>) {
+        let (left, right) = self.as_mut_slice().split_at_mut(mid);
+        (left.into(), right.into())
+    }
+
     /// Creates a new `RVec<T>` and clones all the elements of this slice into it.
     ///
     /// # Example
@@ -656,6 +689,8 @@ shared_impls! {
 mod test {
     use super::*;
 
+    use crate::test_utils::must_panic;
+
     #[test]
     fn from_to_slice() {
         let a = b"what the hell".to_vec();
@@ -705,4 +740,40 @@ mod test {
         assert_eq!(s.index_mut(1..2), &mut [2]);
         assert_eq!(s.index_mut(3..), &mut [4, 5]);
     }
+
+    #[test]
+    fn split_at_mut() {
+        let mut v = [1, 2, 3];
+        let mut s = RSliceMut::from_mut_slice(&mut v[..]);
+
+        {
+            let (left, right) = s.split_at_mut(0);
+            assert_eq!(left, RSliceMut::from_mut_slice(&mut []));
+            assert_eq!(right, RSliceMut::from_mut_slice(&mut [1, 2, 3]));
+        }
+        {
+            let (mut left, mut right) = s.split_at_mut(2);
+            left[1] = 20;
+            right[0] = 30;
+            assert_eq!(left, RSliceMut::from_mut_slice(&mut [1, 20]));
+            assert_eq!(right, RSliceMut::from_mut_slice(&mut [30]));
+        }
+        {
+            let (left, right) = s.split_at_mut(3);
+            assert_eq!(left, RSliceMut::from_mut_slice(&mut [1, 20, 30]));
+            assert_eq!(right, RSliceMut::from_mut_slice(&mut []));
+        }
+
+        must_panic(|| {
+            s.split_at_mut(4);
+        })
+        .unwrap();
+
+        let mut empty = RSliceMut::<u8>::default();
+        assert_eq!(empty.split_at_mut(0).1.len(), 0);
+        must_panic(|| {
+            empty.split_at_mut(1);
+        })
+        .unwrap();
+    }
 }
@@ -285,6 +285,76 @@ impl<'a, T> RSlice<'a, T> {
         self.as_slice().index(i).into()
     }
 
+    /// Returns the first element and an `RSlice` of the rest of the elements,
+    /// or `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic = RSlice::from_slice(&[0, 1, 2, 3]);
+    ///
+    /// assert_eq!(slic.split_first(), Some((&0, RSlice::from_slice(&[1, 2, 3]))));
+    /// assert_eq!(RSlice::from_slice(&[5]).split_first(), Some((&5, RSlice::empty())));
+    /// assert_eq!(RSlice::<u8>::empty().split_first(), None);
+    ///
+    /// ```
+    pub fn split_first(&self) -> Option<(&'a T, RSlice<'a, T>)> {
+        match self.as_slice() {
+            [first, rest @ ..] => Some((first, rest.into())),
+            [] => None,
+        }
+    }
+
+    /// Returns the last element and an `RSlice` of the rest of the elements,
+    /// or `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic = RSlice::from_slice(&[0, 1, 2, 3]);
+    ///
+    /// assert_eq!(slic.split_last(), Some((&3, RSlice::from_slice(&[0, 1, 2]))));
+    /// assert_eq!(RSlice::from_slice(&[5]).split_last(), Some((&5, RSlice::empty())));
+    /// assert_eq!(RSlice::<u8>::empty().split_last(), None);
+    ///
+    /// ```
+    pub fn split_last(&self) -> Option<(&'a T, RSlice<'a, T>)> {
+        match self.as_slice() {
+            [rest @ .., last] => Some((last, rest.into())),
+            [] => None,
+        }
+    }
+
+    /// Divides the slice into two `RSlice`s at the `mid` index.
+    ///
+    /// The first slice contains the elements in `[0, mid)`,
+    /// the second slice contains the elements in `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic = RSlice::from_slice(&[0, 1, 2, 3]);
+    ///
+    /// assert_eq!(slic.split_at(0), (RSlice::empty(), RSlice::from_slice(&[0, 1, 2, 3])));
+    /// assert_eq!(slic.split_at(1), (RSlice::from_slice(&[0]), RSlice::from_slice(&[1, 2, 3])));
+    /// assert_eq!(slic.split_at(4), (RSlice::from_slice(&[0, 1, 2, 3]), RSlice::empty()));
+    ///
+    /// ```
+    pub fn split_at(&self, mid: usize) -> (RSlice<'a, T>, RSlice<'a, T>) {
+        let (left, right) = self.as_slice().split_at(mid);
+        (left.into(), right.into())
+    }
+
     /// Creates a new `RVec<T>` and clones all the elements of this slice into it.
     ///
     /// # Example
@@ -307,6 +377,54 @@ impl<'a, T> RSlice<'a, T> {
         self.to_vec().into()
     }
 
+    /// Returns whether the slice contains an element equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic = RSlice::from_slice(&[3, 5, 8]);
+    ///
+    /// assert!(slic.contains(&3));
+    /// assert!(slic.contains(&8));
+    /// assert!(!slic.contains(&4));
+    /// assert!(!RSlice::<u8>::empty().contains(&0));
+    ///
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns false,
+    /// assuming that the slice is partitioned so that
+    /// all the elements for which `pred` returns true come first.
+    ///
+    /// This delegates to [`<[T]>::partition_point`](slice::partition_point).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic = RSlice::from_slice(&[1, 2, 3, 3, 5, 8]);
+    ///
+    /// assert_eq!(slic.partition_point(|&x| x < 3), 2);
+    /// assert_eq!(slic.partition_point(|&x| x <= 3), 4);
+    /// assert_eq!(slic.partition_point(|&x| x < 100), 6);
+    /// assert_eq!(slic.partition_point(|_| false), 0);
+    ///
+    /// ```
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().partition_point(pred)
+    }
+
     /// Transmutes n `RSlice<'a, T>` to a `RSlice<'a, U>`
     ///
     /// # Safety
@@ -495,6 +613,8 @@ shared_impls! {
 mod test {
     use super::*;
 
+    use crate::test_utils::must_panic;
+
     #[test]
     fn from_to_slice() {
         let a = "what the hell".as_bytes();
@@ -523,4 +643,58 @@ mod test {
         assert_eq!(s.index(1..2), rslice![2]);
         assert_eq!(s.index(3..), rslice![4, 5]);
     }
+
+    #[test]
+    fn split_first_last() {
+        let s = rslice![1, 2, 3];
+
+        assert_eq!(s.split_first(), Some((&1, rslice![2, 3])));
+        assert_eq!(s.split_last(), Some((&3, rslice![1, 2])));
+
+        let single = rslice![8];
+        assert_eq!(single.split_first(), Some((&8, RSlice::empty())));
+        assert_eq!(single.split_last(), Some((&8, RSlice::empty())));
+
+        let empty = RSlice::<u8>::empty();
+        assert_eq!(empty.split_first(), None);
+        assert_eq!(empty.split_last(), None);
+    }
+
+    #[test]
+    fn partition_point() {
+        let list = [2, 3, 5, 7, 11, 13, 17, 19];
+        let s = RSlice::from_slice(&list);
+
+        for (i, &x) in list.iter().enumerate() {
+            assert_eq!(s.partition_point(|&y| y < x), i);
+            assert_eq!(s.partition_point(|&y| y <= x), i + 1);
+            assert_eq!(s.partition_point(|&y| y < x + 1), i + 1);
+        }
+        assert_eq!(s.partition_point(|&y| y < 0), 0);
+        assert_eq!(s.partition_point(|&y| y < 100), list.len());
+
+        // the range of elements in the 5..=13 range
+        let start = s.partition_point(|&y| y < 5);
+        let end = s.partition_point(|&y| y <= 13);
+        assert_eq!(s.slice(start..end), rslice![5, 7, 11, 13]);
+
+        let empty = RSlice::<u8>::empty();
+        assert_eq!(empty.partition_point(|_| true), 0);
+        assert_eq!(empty.partition_point(|_| false), 0);
+    }
+
+    #[test]
+    fn split_at() {
+        let s = rslice![1, 2, 3];
+
+        assert_eq!(s.split_at(0), (RSlice::empty(), rslice![1, 2, 3]));
+        assert_eq!(s.split_at(2), (rslice![1, 2], rslice![3]));
+        assert_eq!(s.split_at(3), (rslice![1, 2, 3], RSlice::empty()));
+
+        let empty = RSlice::<u8>::empty();
+        assert_eq!(empty.split_at(0), (RSlice::empty(), RSlice::empty()));
+
+        must_panic(|| s.split_at(4)).unwrap();
+        must_panic(|| empty.split_at(1)).unwrap();
+    }
 }
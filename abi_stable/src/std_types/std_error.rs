@@ -285,6 +285,7 @@ impl<M> RBoxError_<M> {
         DebugDisplay {
             debug: format!("{:#?}", value),
             display: format!("{:#}", value),
+            source: None,
         }
         .piped(Self::from_debug_display)
     }
@@ -312,6 +313,7 @@ impl<M> RBoxError_<M> {
         DebugDisplay {
             debug: format!("{:#?}", value),
             display: format!("{:#?}", value),
+            source: None,
         }
         .piped(Self::from_debug_display)
     }
@@ -350,19 +352,81 @@ impl<M> RBoxError_<M> {
     // unload libraries right now.
     ///
     pub fn to_formatted_error<N>(&self) -> RBoxError_<N> {
-        if let Some(dd) = self.as_debug_display() {
-            RBoxError_::from_debug_display(DebugDisplay {
-                debug: dd.debug.into(),
-                display: dd.display.into(),
-            })
-        } else {
-            RBoxError_::from_fmt(self)
+        RBoxError_::from_debug_display(self.to_debug_display())
+    }
+
+    fn to_debug_display(&self) -> DebugDisplay {
+        let (debug, display) = match self.as_debug_display() {
+            Some(dd) => (dd.debug.into(), dd.display.into()),
+            None => (format!("{:#?}", self), format!("{:#}", self)),
+        };
+        DebugDisplay {
+            debug,
+            display,
+            source: self
+                .sabi_source()
+                .into_option()
+                .map(|source| Box::new(source.to_debug_display())),
         }
     }
 
     fn as_debug_display(&self) -> Option<DebugDisplayRef<'_>> {
         unsafe { self.vtable.as_debug_display()(self.value.as_rref()).into_option() }
     }
+
+    /// Gets the lower-level source of this error,
+    /// the ffi-safe equivalent of `std::error::Error::source`.
+    ///
+    /// The source is read in the dynamic library that constructed this `RBoxError_`,
+    /// and returned as a formatted error
+    /// (like the ones constructed with [`from_fmt`](#method.from_fmt)),
+    /// which can't be downcasted to the original source type.
+    ///
+    /// Calling `sabi_source` on the returned error gets the next error in the chain.
+    ///
+    /// This returns `RNone` if the error has no source,
+    /// or if it was constructed with a version of `abi_stable` that didn't support this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RBoxError, RSome};
+    ///
+    /// use std::{error::Error, fmt};
+    ///
+    /// #[derive(Debug)]
+    /// struct ConfigError(std::num::ParseIntError);
+    ///
+    /// impl fmt::Display for ConfigError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("invalid config")
+    ///     }
+    /// }
+    ///
+    /// impl Error for ConfigError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let err = RBoxError::new(ConfigError("foo".parse::<u32>().unwrap_err()));
+    ///
+    /// let mut chain = vec![err.to_string()];
+    /// let mut source = err.sabi_source();
+    /// while let RSome(x) = source {
+    ///     chain.push(x.to_string());
+    ///     source = x.sabi_source();
+    /// }
+    ///
+    /// assert_eq!(chain, ["invalid config", "invalid digit found in string"]);
+    ///
+    /// ```
+    pub fn sabi_source(&self) -> ROption<RBoxError> {
+        match self.vtable.source() {
+            Some(source) => unsafe { source(self.value.as_rref()) },
+            None => ROption::RNone,
+        }
+    }
 }
 
 impl<M> RBoxError_<M> {
@@ -702,6 +766,8 @@ struct RErrorVTable {
 
     #[sabi(last_prefix_field)]
     type_id: extern "C" fn() -> UTypeId,
+
+    source: unsafe extern "C" fn(RRef<'_, ErasedObject>) -> ROption<RBoxError>,
 }
 
 ///////////////////
@@ -717,6 +783,7 @@ where
         display: display_impl::<T>,
         as_debug_display: not_as_debug_display,
         type_id: new_utypeid::<T>,
+        source: source_impl::<T>,
     };
 
     const VALUE_MD: &'static WithMetadata<RErrorVTable> = &WithMetadata::new(Self::VALUE);
@@ -731,6 +798,7 @@ impl MakeRErrorVTable<DebugDisplay> {
             display: display_impl::<DebugDisplay>,
             as_debug_display,
             type_id: new_utypeid::<DebugDisplay>,
+            source: debug_display_source,
         })
     };
 
@@ -751,6 +819,7 @@ where
         display: display_impl::<Box<T>>,
         as_debug_display: not_as_debug_display,
         type_id: new_utypeid::<Box<T>>,
+        source: boxed_source_impl::<T>,
     };
 
     const WM_VTABLE: &'static WithMetadata<RErrorVTable> = &WithMetadata::new(Self::VALUE);
@@ -765,6 +834,19 @@ where
 struct DebugDisplay {
     debug: String,
     display: String,
+    source: Option<Box<DebugDisplay>>,
+}
+
+impl DebugDisplay {
+    fn from_error(err: &(dyn ErrorTrait + 'static)) -> Self {
+        DebugDisplay {
+            debug: format!("{:#?}", err),
+            display: format!("{:#}", err),
+            source: err
+                .source()
+                .map(|source| Box::new(Self::from_error(source))),
+        }
+    }
 }
 
 impl Display for DebugDisplay {
@@ -809,3 +891,37 @@ unsafe extern "C" fn not_as_debug_display(
 ) -> ROption<DebugDisplayRef<'_>> {
     ROption::RNone
 }
+
+unsafe extern "C" fn source_impl<T>(this: RRef<'_, ErasedObject>) -> ROption<RBoxError>
+where
+    T: ErrorTrait + 'static,
+{
+    extern_fn_panic_handling! {
+        let this = unsafe{ this.transmute_into_ref::<T>() };
+        this.source()
+            .map(|source| RBoxError::from_debug_display(DebugDisplay::from_error(source)))
+            .into()
+    }
+}
+
+unsafe extern "C" fn boxed_source_impl<T>(this: RRef<'_, ErasedObject>) -> ROption<RBoxError>
+where
+    T: ?Sized + ErrorTrait + 'static,
+{
+    extern_fn_panic_handling! {
+        let this = unsafe{ this.transmute_into_ref::<Box<T>>() };
+        (**this).source()
+            .map(|source| RBoxError::from_debug_display(DebugDisplay::from_error(source)))
+            .into()
+    }
+}
+
+unsafe extern "C" fn debug_display_source(this: RRef<'_, ErasedObject>) -> ROption<RBoxError> {
+    extern_fn_panic_handling! {
+        let this = unsafe{ this.transmute_into_ref::<DebugDisplay>() };
+        this.source
+            .as_ref()
+            .map(|source| RBoxError::from_debug_display((**source).clone()))
+            .into()
+    }
+}
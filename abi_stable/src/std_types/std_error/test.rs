@@ -177,3 +177,79 @@ fn from_fmt_or_debug() {
         assert_eq!(format!("{:#?}", str_err), format!("{}", rerr));
     }
 }
+
+#[derive(Debug, Clone)]
+struct Chained {
+    msg: &'static str,
+    source: Option<Box<Chained>>,
+}
+
+impl Chained {
+    fn new(msg: &'static str, source: Option<Chained>) -> Self {
+        Self {
+            msg,
+            source: source.map(Box::new),
+        }
+    }
+}
+
+impl Display for Chained {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.msg)
+    }
+}
+
+impl ErrorTrait for Chained {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        self.source.as_ref().map(|x| &**x as _)
+    }
+}
+
+fn source_chain<M>(err: &RBoxError_<M>) -> Vec<String> {
+    let mut list = vec![err.to_string()];
+    let mut source = err.sabi_source();
+    while let ROption::RSome(x) = source {
+        list.push(x.to_string());
+        source = x.sabi_source();
+    }
+    list
+}
+
+#[test]
+fn source_chain_two_levels() {
+    let err = Chained::new(
+        "top",
+        Some(Chained::new("middle", Some(Chained::new("bottom", None)))),
+    );
+    let expected = ["top", "middle", "bottom"];
+
+    let rerr = RBoxError::new(err.clone());
+    assert_eq!(source_chain(&rerr), expected);
+
+    let middle = rerr.sabi_source().unwrap();
+    assert_eq!(
+        format!("{:#?}", middle),
+        format!("{:#?}", err.source.as_ref().unwrap())
+    );
+    assert!(middle.downcast_ref::<Chained>().is_none());
+
+    let boxed: Box<dyn ErrorTrait + Send + Sync> = Box::new(err.clone());
+    assert_eq!(source_chain(&RBoxError::from_box(boxed)), expected);
+
+    let unsync = UnsyncRBoxError::new(err.clone());
+    assert_eq!(source_chain(&unsync), expected);
+
+    // formatted errors keep the source chain
+    assert_eq!(
+        source_chain(&rerr.to_formatted_error::<SyncSend>()),
+        expected
+    );
+
+    // errors without a source
+    assert_eq!(
+        source_chain(&RBoxError::new(Chained::new("top", None))),
+        ["top"]
+    );
+    assert_eq!(source_chain(&RBoxError::from_fmt(&err)), ["top"]);
+    assert_eq!(source_chain(&RBoxError::from_debug(&err)).len(), 1);
+}
@@ -3,8 +3,8 @@
 use std::{
     borrow::{Borrow, Cow},
     fmt::{self, Display},
-    ops::{Deref, Index},
-    str,
+    ops::{Bound, Deref, Index, RangeBounds},
+    str::{self, FromStr},
 };
 
 #[allow(unused_imports)]
@@ -12,7 +12,7 @@ use core_extensions::SelfOps;
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::std_types::{RSlice, RString};
+use crate::std_types::{ROption, RSlice, RString};
 
 /// Ffi-safe equivalent of `&'a str`
 ///
@@ -138,6 +138,50 @@ impl<'a> RStr<'a> {
         self.as_str().index(i).into()
     }
 
+    /// Gets a subslice of the `RStr`, returning `RNone` instead of panicking
+    /// if the range is out of bounds or isn't on `char` boundaries.
+    ///
+    /// This is the non-panicking equivalent of [`slice`](#method.slice),
+    /// behaving like `str::get`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone, RSome, RStr};
+    ///
+    /// let str = RStr::from("año nuevo");
+    ///
+    /// assert_eq!(str.get(..), RSome(str));
+    /// assert_eq!(str.get(..4), RSome(RStr::from("año")));
+    /// assert_eq!(str.get(5..=9), RSome(RStr::from("nuevo")));
+    ///
+    /// // Index 2 is in the middle of the 'ñ' character
+    /// assert_eq!(str.get(..2), RNone);
+    /// assert_eq!(str.get(5..11), RNone);
+    ///
+    /// ```
+    pub fn get<R>(&self, range: R) -> ROption<RStr<'a>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Unbounded => Some(0),
+            Bound::Included(&n) => Some(n),
+            Bound::Excluded(&n) => n.checked_add(1),
+        };
+        let end = match range.end_bound() {
+            Bound::Unbounded => Some(self.len()),
+            Bound::Included(&n) => n.checked_add(1),
+            Bound::Excluded(&n) => Some(n),
+        };
+        let s: &'a str = self.as_str();
+        start
+            .zip(end)
+            .and_then(|(start, end)| s.get(start..end))
+            .map(RStr::from)
+            .into()
+    }
+
     /// Accesses the underlying byte slice.
     ///
     /// # Example
@@ -214,6 +258,167 @@ impl<'a> RStr<'a> {
     pub const fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Returns a sub-slice of this `RStr<'a>` with leading and trailing whitespace removed.
+    ///
+    /// This is named differently than [`str::trim`] (accessible through `Deref`)
+    /// so that it doesn't shadow it.
+    ///
+    /// Whitespace is defined the same way as [`str::trim`],
+    /// by the Unicode `White_Space` property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str = RStr::from("\u{3000} What is that.\n\t");
+    ///
+    /// assert_eq!(str.trim_rstr(), RStr::from("What is that."));
+    ///
+    /// ```
+    pub fn trim_rstr(&self) -> RStr<'a> {
+        self.as_str().trim().into()
+    }
+
+    /// Returns a sub-slice of this `RStr<'a>` with leading whitespace removed.
+    ///
+    /// Whitespace is defined the same way as [`str::trim_start`],
+    /// by the Unicode `White_Space` property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str = RStr::from("\u{3000} What is that.\n\t");
+    ///
+    /// assert_eq!(str.trim_start_rstr(), RStr::from("What is that.\n\t"));
+    ///
+    /// ```
+    pub fn trim_start_rstr(&self) -> RStr<'a> {
+        self.as_str().trim_start().into()
+    }
+
+    /// Returns a sub-slice of this `RStr<'a>` with trailing whitespace removed.
+    ///
+    /// Whitespace is defined the same way as [`str::trim_end`],
+    /// by the Unicode `White_Space` property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str = RStr::from("\u{3000} What is that.\n\t");
+    ///
+    /// assert_eq!(str.trim_end_rstr(), RStr::from("\u{3000} What is that."));
+    ///
+    /// ```
+    pub fn trim_end_rstr(&self) -> RStr<'a> {
+        self.as_str().trim_end().into()
+    }
+
+    /// Returns the lowercase equivalent of this string,
+    /// as defined by the Unicode `Lowercase` derived property.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// let str = RStr::from("ΑΒΓ Straße");
+    ///
+    /// assert_eq!(str.to_lowercase(), RString::from("αβγ straße"));
+    ///
+    /// ```
+    pub fn to_lowercase(&self) -> RString {
+        self.as_str().to_lowercase().into()
+    }
+
+    /// Returns the uppercase equivalent of this string,
+    /// as defined by the Unicode `Uppercase` derived property.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// let str = RStr::from("αβγ Straße");
+    ///
+    /// assert_eq!(str.to_uppercase(), RString::from("ΑΒΓ STRASSE"));
+    ///
+    /// ```
+    pub fn to_uppercase(&self) -> RString {
+        self.as_str().to_uppercase().into()
+    }
+
+    /// Returns a copy of this string where each ASCII letter is mapped to its
+    /// lowercase equivalent, leaving non-ASCII characters unchanged.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// let str = RStr::from("ÑANDÚ Hello");
+    ///
+    /// assert_eq!(str.to_ascii_lowercase(), RString::from("ÑandÚ hello"));
+    ///
+    /// ```
+    pub fn to_ascii_lowercase(&self) -> RString {
+        self.as_str().to_ascii_lowercase().into()
+    }
+
+    /// Returns a copy of this string where each ASCII letter is mapped to its
+    /// uppercase equivalent, leaving non-ASCII characters unchanged.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// let str = RStr::from("ñandú Hello");
+    ///
+    /// assert_eq!(str.to_ascii_uppercase(), RString::from("ñANDú HELLO"));
+    ///
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> RString {
+        self.as_str().to_ascii_uppercase().into()
+    }
+
+    /// Parses this string into another type, with the type's [`FromStr`] impl.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// assert_eq!(RStr::from("42").parse::<u32>(), Ok(42));
+    /// assert_eq!(RStr::from("-2.5").parse::<f64>(), Ok(-2.5));
+    /// assert_eq!(RStr::from("hello").parse::<RString>().unwrap(), "hello");
+    ///
+    /// assert!(RStr::from("4 2").parse::<u32>().is_err());
+    ///
+    /// ```
+    pub fn parse<F>(&self) -> Result<F, F::Err>
+    where
+        F: FromStr,
+    {
+        self.as_str().parse::<F>()
+    }
 }
 
 unsafe impl<'a> Send for RStr<'a> {}
@@ -5,9 +5,9 @@ use std::{
     fmt::{self, Display, Formatter},
     iter::{FromIterator, FusedIterator},
     marker::PhantomData,
-    ops::{Deref, Index, Range},
+    ops::{Deref, Index, Range, RangeBounds},
     ptr,
-    str::{from_utf8, Chars, FromStr, Utf8Error},
+    str::{from_utf8, from_utf8_unchecked_mut, Chars, FromStr, Utf8Error},
     string::FromUtf16Error,
 };
 
@@ -16,7 +16,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 #[allow(unused_imports)]
 use core_extensions::{SelfOps, SliceExt, StringExt};
 
-use crate::std_types::{RStr, RVec};
+use crate::std_types::{ROption, RStr, RVec};
 
 mod iters;
 
@@ -117,6 +117,31 @@ impl RString {
         (&self[i]).into()
     }
 
+    /// Gets a subslice of the `RString`, returning `RNone` instead of panicking
+    /// if the range is out of bounds or isn't on `char` boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone, RSome, RStr, RString};
+    ///
+    /// let str = RString::from("año nuevo");
+    ///
+    /// assert_eq!(str.get(..4), RSome(RStr::from("año")));
+    /// assert_eq!(str.get(5..), RSome(RStr::from("nuevo")));
+    ///
+    /// assert_eq!(str.get(..2), RNone);
+    /// assert_eq!(str.get(5..11), RNone);
+    ///
+    /// ```
+    #[inline]
+    pub fn get<R>(&self, range: R) -> ROption<RStr<'_>>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.as_rstr().get(range)
+    }
+
     conditionally_const! {
         feature = "rust_1_64"
         /// Creates a `&str` with access to all the characters of the `RString`.
@@ -188,6 +213,184 @@ impl RString {
         self.inner.is_empty()
     }
 
+    /// Returns a sub-slice of this `RString` with leading and trailing whitespace removed.
+    ///
+    /// This is named differently than [`str::trim`] (accessible through `Deref`)
+    /// so that it doesn't shadow it.
+    ///
+    /// Whitespace is defined the same way as [`str::trim`],
+    /// by the Unicode `White_Space` property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// let str = RString::from("\u{3000} What is that.\n\t");
+    ///
+    /// assert_eq!(str.trim_rstr(), RStr::from("What is that."));
+    ///
+    /// ```
+    pub fn trim_rstr(&self) -> RStr<'_> {
+        self.as_str().trim().into()
+    }
+
+    /// Returns a sub-slice of this `RString` with leading whitespace removed.
+    ///
+    /// Whitespace is defined the same way as [`str::trim_start`],
+    /// by the Unicode `White_Space` property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// let str = RString::from("\u{3000} What is that.\n\t");
+    ///
+    /// assert_eq!(str.trim_start_rstr(), RStr::from("What is that.\n\t"));
+    ///
+    /// ```
+    pub fn trim_start_rstr(&self) -> RStr<'_> {
+        self.as_str().trim_start().into()
+    }
+
+    /// Returns a sub-slice of this `RString` with trailing whitespace removed.
+    ///
+    /// Whitespace is defined the same way as [`str::trim_end`],
+    /// by the Unicode `White_Space` property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr, RString};
+    ///
+    /// let str = RString::from("\u{3000} What is that.\n\t");
+    ///
+    /// assert_eq!(str.trim_end_rstr(), RStr::from("\u{3000} What is that."));
+    ///
+    /// ```
+    pub fn trim_end_rstr(&self) -> RStr<'_> {
+        self.as_str().trim_end().into()
+    }
+
+    /// Returns the lowercase equivalent of this string,
+    /// as defined by the Unicode `Lowercase` derived property.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let str = RString::from("ΑΒΓ Straße");
+    ///
+    /// assert_eq!(str.to_lowercase(), RString::from("αβγ straße"));
+    ///
+    /// ```
+    pub fn to_lowercase(&self) -> RString {
+        self.as_str().to_lowercase().into()
+    }
+
+    /// Returns the uppercase equivalent of this string,
+    /// as defined by the Unicode `Uppercase` derived property.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let str = RString::from("αβγ Straße");
+    ///
+    /// assert_eq!(str.to_uppercase(), RString::from("ΑΒΓ STRASSE"));
+    ///
+    /// ```
+    pub fn to_uppercase(&self) -> RString {
+        self.as_str().to_uppercase().into()
+    }
+
+    /// Returns a copy of this string where each ASCII letter is mapped to its
+    /// lowercase equivalent, leaving non-ASCII characters unchanged.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let str = RString::from("ÑANDÚ Hello");
+    ///
+    /// assert_eq!(str.to_ascii_lowercase(), RString::from("ÑandÚ hello"));
+    ///
+    /// ```
+    pub fn to_ascii_lowercase(&self) -> RString {
+        self.as_str().to_ascii_lowercase().into()
+    }
+
+    /// Returns a copy of this string where each ASCII letter is mapped to its
+    /// uppercase equivalent, leaving non-ASCII characters unchanged.
+    ///
+    /// This shadows the `str` method accessible through `Deref`,
+    /// which returns a `String` instead of an `RString`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let str = RString::from("ñandú Hello");
+    ///
+    /// assert_eq!(str.to_ascii_uppercase(), RString::from("ñANDú HELLO"));
+    ///
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> RString {
+        self.as_str().to_ascii_uppercase().into()
+    }
+
+    /// Converts this string to its ASCII lowercase equivalent in-place,
+    /// leaving non-ASCII characters unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let mut str = RString::from("ÑANDÚ Hello");
+    /// str.make_ascii_lowercase();
+    ///
+    /// assert_eq!(str, "ÑandÚ hello");
+    ///
+    /// ```
+    pub fn make_ascii_lowercase(&mut self) {
+        // Changing the case of ASCII bytes can't produce invalid UTF-8.
+        self.inner.make_ascii_lowercase()
+    }
+
+    /// Converts this string to its ASCII uppercase equivalent in-place,
+    /// leaving non-ASCII characters unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let mut str = RString::from("ñandú Hello");
+    /// str.make_ascii_uppercase();
+    ///
+    /// assert_eq!(str, "ñANDú HELLO");
+    ///
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        // Changing the case of ASCII bytes can't produce invalid UTF-8.
+        self.inner.make_ascii_uppercase()
+    }
+
     /// Gets a raw pointer to the start of this RString's buffer.
     pub const fn as_ptr(&self) -> *const u8 {
         self.inner.as_ptr()
@@ -336,6 +539,54 @@ impl RString {
     pub fn into_string(self) -> String {
         unsafe { String::from_utf8_unchecked(self.inner.into_vec()) }
     }
+
+    /// Converts this `RString` into a `String` without reallocating,
+    /// returning the `RString` back if that isn't possible.
+    ///
+    /// This only succeeds if the `RString` was created in the
+    /// dynamic library/binary that calls this method,
+    /// since those are the only `RString`s known to use the same allocator as `String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let str = RString::from("hello");
+    /// let ptr = str.as_ptr();
+    ///
+    /// let str: String = str.into_string_same_allocator().unwrap();
+    /// assert_eq!(str, "hello");
+    /// assert_eq!(str.as_ptr(), ptr);
+    ///
+    /// ```
+    pub fn into_string_same_allocator(self) -> Result<String, Self> {
+        match self.inner.into_vec_same_allocator() {
+            Ok(vec) => unsafe { Ok(String::from_utf8_unchecked(vec)) },
+            Err(inner) => Err(Self { inner }),
+        }
+    }
+
+    /// Consumes and leaks the `RString`, returning a mutable reference to its contents.
+    ///
+    /// The memory is never deallocated by the dynamic library/binary that allocated it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let str: &'static mut str = RString::from("hello").leak();
+    /// str.make_ascii_uppercase();
+    ///
+    /// assert_eq!(str, "HELLO");
+    ///
+    /// # // deallocating the leaked memory, so that miri doesn't report it.
+    /// # unsafe { drop(Vec::from_raw_parts(str.as_mut_ptr(), 5, 5)) }
+    /// ```
+    pub fn leak<'a>(self) -> &'a mut str {
+        unsafe { from_utf8_unchecked_mut(self.inner.leak().into_mut_slice()) }
+    }
     /// Copies the `RString` into a `String`.
     ///
     /// # Example
@@ -742,6 +993,21 @@ impl<'a> From<Cow<'a, str>> for RString {
 
 ////////////////////
 
+/// Allows parsing strings into `RString`s, which never fails.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::RString;
+///
+/// assert_eq!("42".parse::<RString>().unwrap(), "42");
+///
+/// // Parsing an `RString` into other types uses `str::parse` through `Deref`
+/// let number = RString::from("1.5");
+/// assert_eq!(number.parse::<f32>(), Ok(1.5));
+/// assert_eq!(RString::from("42").parse::<u8>(), Ok(42));
+///
+/// ```
 impl FromStr for RString {
     type Err = <String as FromStr>::Err;
 
@@ -917,6 +1183,35 @@ impl<'a> FromIterator<&'a char> for RString {
     }
 }
 
+impl Extend<char> for RString {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|ch| self.push(ch));
+    }
+}
+
+impl<'a> Extend<&'a char> for RString {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a char>,
+    {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<'a> Extend<&'a str> for RString {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        iter.into_iter().for_each(|s| self.push_str(s));
+    }
+}
+
 //////////////////////////////////////////////////////
 
 /// Error that happens when attempting to convert an `RVec<u8>` into an `RString`.
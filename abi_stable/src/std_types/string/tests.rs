@@ -1,6 +1,9 @@
 use super::*;
 
-use crate::test_utils::must_panic;
+use crate::{
+    std_types::{RNone, RSome},
+    test_utils::must_panic,
+};
 
 #[allow(unused_imports)]
 use core_extensions::{SelfOps, SliceExt};
@@ -55,6 +58,35 @@ fn push() {
     }
 }
 
+#[test]
+fn extend() {
+    {
+        let mut rstr = RString::from("foo");
+        rstr.extend(TEST_STR.chars());
+        assert_eq!(rstr, format!("foo{}", TEST_STR));
+    }
+    {
+        let chars = TEST_STR.chars().collect::<Vec<char>>();
+        let mut rstr = RString::new();
+        rstr.extend(&chars);
+        assert_eq!(rstr, TEST_STR);
+    }
+    {
+        let mut rstr = RString::new();
+        rstr.extend(TEST_STR.split('.'));
+        assert_eq!(rstr, TEST_STR.replace('.', ""));
+
+        rstr.extend(vec!["", "💔", "bar"]);
+        assert!(rstr.ends_with("💔bar"), "{}", rstr);
+    }
+    {
+        let mut rstr = RString::new();
+        rstr.extend(std::iter::empty::<char>());
+        rstr.extend(std::iter::empty::<&str>());
+        assert_eq!(rstr, "");
+    }
+}
+
 #[test]
 fn insert_str() {
     // '💔' is 4 bytes long
@@ -230,6 +262,23 @@ fn retain() {
     }
 }
 
+#[test]
+fn leak() {
+    let rstr = RString::from(TEST_STR);
+    let (ptr, capacity) = (rstr.as_ptr(), rstr.capacity());
+
+    let leaked: &'static mut str = rstr.leak();
+    assert_eq!(leaked.as_ptr(), ptr);
+    assert_eq!(leaked, TEST_STR);
+
+    leaked.make_ascii_uppercase();
+    assert_eq!(*leaked, *TEST_STR.to_ascii_uppercase());
+
+    // deallocating the leaked memory, so that miri doesn't report it.
+    let len = leaked.len();
+    unsafe { drop(Vec::from_raw_parts(leaked.as_mut_ptr(), len, capacity)) }
+}
+
 #[test]
 fn into_iter() {
     static TEST_STR: &str = "hello_world.cáscara.ñ.🎊🍕👏😊😀😄😉😉😛😮🙁🙂💔👻😎.";
@@ -300,3 +349,140 @@ fn drain() {
     assert_eq!(rstr.len(), 0);
     assert_eq!(rstr.capacity(), rstr_cap);
 }
+
+#[test]
+fn fmt_write() {
+    use std::fmt::Write;
+
+    let mut rstr = RString::new();
+
+    write!(rstr, "{}", 42).unwrap();
+    assert_eq!(&*rstr, "42");
+
+    write!(rstr, "-{:?}-", "ñ").unwrap();
+    rstr.write_char('🎊').unwrap();
+    assert_eq!(&*rstr, "42-\"ñ\"-🎊");
+    assert_eq!(rstr.as_bytes(), "42-\"ñ\"-🎊".as_bytes());
+}
+
+#[test]
+fn trim_rstr() {
+    let whitespace = [
+        "", " ", "\t\n\r", "\u{85}", "\u{A0}", "\u{2003}", "\u{2028}", "\u{3000}",
+    ];
+    for ws in whitespace {
+        for content in ["", "a", "hello world", "\u{200B}x\u{200B}"] {
+            let std_str = format!("{0}{1}{0}", ws, content);
+            let rstring = RString::from(&*std_str);
+            let rstr = rstring.as_rstr();
+
+            assert_eq!(rstring.trim_rstr(), std_str.trim());
+            assert_eq!(rstring.trim_start_rstr(), std_str.trim_start());
+            assert_eq!(rstring.trim_end_rstr(), std_str.trim_end());
+            assert_eq!(rstr.trim_rstr(), std_str.trim());
+            assert_eq!(rstr.trim_start_rstr(), std_str.trim_start());
+            assert_eq!(rstr.trim_end_rstr(), std_str.trim_end());
+        }
+    }
+
+    // The returned `RStr` borrows from the original string
+    let rstr = RStr::from("  foo  ");
+    let trimmed: RStr<'static> = rstr.trim_rstr();
+    assert_eq!(trimmed, "foo");
+    assert_eq!(trimmed.as_ptr(), rstr.slice(2..).as_ptr());
+}
+
+#[test]
+fn case_conversion() {
+    let cases = [
+        "",
+        "Hello, World!",
+        "ΑΒΓ αβγ",
+        "Straße",
+        "İstanbul",
+        "ǅungla",
+        "ὈΔΥΣΣΕΎΣ",
+        "ÑANDÚ ñandú 🎊",
+    ];
+    for case in cases {
+        let rstring = RString::from(case);
+        let rstr = rstring.as_rstr();
+
+        assert_eq!(rstring.to_lowercase(), case.to_lowercase());
+        assert_eq!(rstring.to_uppercase(), case.to_uppercase());
+        assert_eq!(rstring.to_ascii_lowercase(), case.to_ascii_lowercase());
+        assert_eq!(rstring.to_ascii_uppercase(), case.to_ascii_uppercase());
+        assert_eq!(rstr.to_lowercase(), case.to_lowercase());
+        assert_eq!(rstr.to_uppercase(), case.to_uppercase());
+        assert_eq!(rstr.to_ascii_lowercase(), case.to_ascii_lowercase());
+        assert_eq!(rstr.to_ascii_uppercase(), case.to_ascii_uppercase());
+
+        let mut lower = rstring.clone();
+        lower.make_ascii_lowercase();
+        assert_eq!(lower, case.to_ascii_lowercase());
+
+        let mut upper = rstring.clone();
+        upper.make_ascii_uppercase();
+        assert_eq!(upper, case.to_ascii_uppercase());
+    }
+
+    // Case conversion can change the length of the string.
+    assert_eq!(RStr::from("ß").to_uppercase(), "SS");
+    assert_eq!(RStr::from("İ").to_lowercase(), "i\u{307}");
+    assert_eq!(RStr::from("ὈΔΥΣΣΕΎΣ").to_lowercase(), "ὀδυσσεύς");
+}
+
+#[test]
+fn get_ranges() {
+    use std::ops::Bound;
+
+    let rstring = RString::from(TEST_STR);
+    let rstr = rstring.as_rstr();
+    let len = TEST_STR.len();
+
+    fn check<R>(rstr: RStr<'_>, rstring: &RString, range: R, expected: Option<&str>)
+    where
+        R: std::ops::RangeBounds<usize> + Clone + std::fmt::Debug,
+    {
+        let expected: ROption<RStr<'_>> = expected.map(RStr::from).into();
+        assert_eq!(rstr.get(range.clone()), expected, "{:?}", range);
+        assert_eq!(rstring.get(range.clone()), expected, "{:?}", range);
+    }
+
+    // Every start and end index, including ones that aren't on `char` boundaries,
+    // or are past the end of the string.
+    for start in 0..=len + 2 {
+        for end in 0..=len + 2 {
+            check(rstr, &rstring, start..end, TEST_STR.get(start..end));
+            check(rstr, &rstring, start..=end, TEST_STR.get(start..=end));
+        }
+        check(rstr, &rstring, start.., TEST_STR.get(start..));
+        check(rstr, &rstring, ..start, TEST_STR.get(..start));
+        check(rstr, &rstring, ..=start, TEST_STR.get(..=start));
+    }
+    check(rstr, &rstring, .., Some(TEST_STR));
+
+    let s = RStr::from("ñ💔a");
+    assert_eq!(s.get(..), RSome(s));
+    assert_eq!(s.get(0..2), RSome(RStr::from("ñ")));
+    assert_eq!(s.get(2..6), RSome(RStr::from("💔")));
+    assert_eq!(s.get(6..=6), RSome(RStr::from("a")));
+    assert_eq!(s.get(7..), RSome(RStr::from("")));
+
+    assert_eq!(s.get(1..), RNone);
+    assert_eq!(s.get(..3), RNone);
+    assert_eq!(s.get(2..=4), RNone);
+    assert_eq!(s.get(8..), RNone);
+    assert_eq!(s.get(..8), RNone);
+    assert_eq!(s.get(..=usize::MAX), RNone);
+
+    assert_eq!(
+        s.get((Bound::Excluded(1), Bound::Unbounded)),
+        RSome(RStr::from("💔a"))
+    );
+    assert_eq!(s.get((Bound::Excluded(0), Bound::Unbounded)), RNone);
+    assert_eq!(
+        s.get((Bound::Excluded(usize::MAX), Bound::Unbounded)),
+        RNone
+    );
+}
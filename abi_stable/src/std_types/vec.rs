@@ -3,10 +3,12 @@
 use std::{
     borrow::{Borrow, BorrowMut, Cow},
     cmp::Ordering,
+    collections::HashSet,
+    hash::Hash,
     io,
     iter::FromIterator,
     marker::PhantomData,
-    mem::{self, ManuallyDrop},
+    mem::{self, ManuallyDrop, MaybeUninit},
     ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds},
     ptr::{self, NonNull},
     slice::SliceIndex,
@@ -22,8 +24,9 @@ use crate::{
     sabi_types::RMut,
     std_types::{
         utypeid::{new_utypeid, UTypeId},
-        RSlice, RSliceMut,
+        RSlice, RSliceMut, RString,
     },
+    DynTrait,
 };
 
 #[cfg(test)]
@@ -34,7 +37,7 @@ mod iters;
 
 use self::iters::{DrainFilter, RawValIter};
 
-pub use self::iters::{Drain, IntoIter};
+pub use self::iters::{Drain, IntoIter, IterMut, MutIterInterface};
 
 mod private {
     use super::*;
@@ -382,6 +385,223 @@ impl<T> RVec<T> {
         self.as_mut_slice().into()
     }
 
+    /// Creates an ffi-safe iterator over mutable references to the elements of
+    /// the `RVec<T>`,
+    /// which can be passed to (or returned from) functions in other dynamic libraries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{vec::IterMut, RVec};
+    ///
+    /// extern "C" fn double_all(iter: IterMut<'_, u32>) {
+    ///     for x in iter {
+    ///         *x *= 2;
+    ///     }
+    /// }
+    ///
+    /// let mut list = RVec::from(vec![3, 5, 8]);
+    ///
+    /// double_all(list.sabi_iter_mut());
+    ///
+    /// assert_eq!(list, [6, 10, 16]);
+    ///
+    /// ```
+    pub fn sabi_iter_mut(&mut self) -> IterMut<'_, T> {
+        DynTrait::from_borrowing_value(self.as_mut_slice().iter_mut())
+            .interface(MutIterInterface::NEW)
+    }
+
+    /// Returns whether the vector contains an element equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec, std_types::RVec};
+    ///
+    /// let list = rvec![3, 5, 8];
+    ///
+    /// assert!(list.contains(&3));
+    /// assert!(list.contains(&8));
+    /// assert!(!list.contains(&4));
+    /// assert!(!RVec::<u8>::new().contains(&0));
+    ///
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns false,
+    /// assuming that the vector is partitioned so that
+    /// all the elements for which `pred` returns true come first.
+    ///
+    /// This delegates to [`<[T]>::partition_point`](slice::partition_point).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec, std_types::RVec};
+    ///
+    /// let list = rvec![1, 2, 3, 3, 5, 8];
+    ///
+    /// assert_eq!(list.partition_point(|&x| x < 3), 2);
+    /// assert_eq!(list.partition_point(|&x| x <= 3), 4);
+    /// assert_eq!(list.partition_point(|&x| x < 100), 6);
+    /// assert_eq!(RVec::<u8>::new().partition_point(|_| true), 0);
+    ///
+    /// ```
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// Rotates the vector in-place such that the first `mid` elements
+    /// move to the end, and the element at index `mid` becomes the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![1, 2, 3, 4, 5];
+    ///
+    /// list.rotate_left(2);
+    /// assert_eq!(list.as_slice(), &[3, 4, 5, 1, 2]);
+    ///
+    /// list.rotate_left(5);
+    /// assert_eq!(list.as_slice(), &[3, 4, 5, 1, 2]);
+    ///
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    /// Rotates the vector in-place such that the last `k` elements
+    /// move to the start.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![1, 2, 3, 4, 5];
+    ///
+    /// list.rotate_right(2);
+    /// assert_eq!(list.as_slice(), &[4, 5, 1, 2, 3]);
+    ///
+    /// list.rotate_right(0);
+    /// assert_eq!(list.as_slice(), &[4, 5, 1, 2, 3]);
+    ///
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    /// Reverses the order of the elements in the vector, in-place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![1, 2, 3, 4, 5];
+    ///
+    /// list.reverse();
+    /// assert_eq!(list.as_slice(), &[5, 4, 3, 2, 1]);
+    ///
+    /// ```
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Overwrites every element of the vector with clones of `value`,
+    /// without changing its length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![1, 2, 3, 4, 5];
+    ///
+    /// list.fill(8);
+    /// assert_eq!(list.as_slice(), &[8, 8, 8, 8, 8]);
+    ///
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.as_mut_slice().fill(value);
+    }
+
+    /// Overwrites every element of the vector with values returned by calling `f`,
+    /// without changing its length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![0, 0, 0, 0];
+    ///
+    /// let mut next = 1;
+    /// list.fill_with(|| {
+    ///     next *= 3;
+    ///     next
+    /// });
+    /// assert_eq!(list.as_slice(), &[3, 9, 27, 81]);
+    ///
+    /// ```
+    pub fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.as_mut_slice().fill_with(f);
+    }
+
+    /// Consumes and leaks the `RVec<T>`, returning a mutable slice of its contents.
+    ///
+    /// The elements are never dropped,
+    /// and the memory is never deallocated by the dynamic library/binary
+    /// that allocated it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RSliceMut, RVec};
+    ///
+    /// let list = RVec::from(vec![3, 5, 8]);
+    ///
+    /// let mut slice: RSliceMut<'static, u32> = list.leak();
+    /// slice[0] = 13;
+    ///
+    /// assert_eq!(slice.as_slice(), &[13, 5, 8]);
+    ///
+    /// # // deallocating the leaked memory, so that miri doesn't report it.
+    /// # unsafe { drop(Vec::from_raw_parts(slice.as_mut_ptr(), 3, 3)) }
+    /// ```
+    pub fn leak<'a>(self) -> RSliceMut<'a, T>
+    where
+        T: 'a,
+    {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { RSliceMut::from_raw_parts_mut(this.as_mut_ptr(), this.len()) }
+    }
+
     /// Returns the amount of elements of the `RVec<T>`.
     ///
     /// # Example
@@ -437,6 +657,41 @@ impl<T> RVec<T> {
         self.length = new_len;
     }
 
+    /// Returns the spare capacity of the `RVec<T>`, as a slice of `MaybeUninit<T>`.
+    ///
+    /// This can be used to write into the uninitialized tail of the buffer
+    /// (eg: reading bytes directly into an `RVec<u8>`),
+    /// after which [`set_len`](#method.set_len) must be called to
+    /// mark the written elements as initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list = RVec::<u8>::with_capacity(8);
+    /// list.push(3);
+    ///
+    /// let spare = list.spare_capacity_mut();
+    /// assert!(spare.len() >= 7);
+    ///
+    /// spare[0].write(5);
+    /// spare[1].write(8);
+    ///
+    /// unsafe { list.set_len(3) };
+    ///
+    /// assert_eq!(list.as_slice(), &[3, 5, 8]);
+    ///
+    /// ```
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.buffer_mut().add(self.length).cast::<MaybeUninit<T>>(),
+                self.capacity() - self.length,
+            )
+        }
+    }
+
     /// Shrinks the capacity of the `RVec` to match its length.
     ///
     /// # Example
@@ -461,6 +716,45 @@ impl<T> RVec<T> {
         }
     }
 
+    /// Shrinks the capacity of the `RVec` to be at least
+    /// the larger of its length and `min_capacity`.
+    ///
+    /// This does nothing if the capacity is already at most `min_capacity`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list = RVec::<u32>::with_capacity(16);
+    /// list.extend(0..4);
+    ///
+    /// list.shrink_to(10);
+    /// assert!(list.capacity() >= 10);
+    /// assert!(list.capacity() < 16);
+    ///
+    /// list.shrink_to(0);
+    /// assert_eq!(list.capacity(), 4);
+    /// assert_eq!(list, [0, 1, 2, 3]);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.capacity() <= min_capacity {
+            return;
+        }
+        let vtable = self.vtable();
+        unsafe {
+            match vtable.shrink_to() {
+                Some(shrink_to) => shrink_to(RMut::new(self).transmute_element_(), min_capacity),
+                // Vtables from libraries built with older versions of abi_stable
+                // can only shrink the capacity down to the length.
+                None if min_capacity <= self.len() => {
+                    vtable.shrink_to_fit()(RMut::new(self).transmute_element_())
+                }
+                None => {}
+            }
+        }
+    }
+
     /// Whether the length of the `RVec<T>` is 0.
     ///
     /// # Example
@@ -509,11 +803,7 @@ impl<T> RVec<T> {
         let mut this = ManuallyDrop::new(self);
 
         unsafe {
-            let this_vtable = this.vtable();
-            let other_vtable = VTableGetter::<T>::LIB_VTABLE;
-            if ::std::ptr::eq(this_vtable.0.to_raw_ptr(), other_vtable.0.to_raw_ptr())
-                || this_vtable.type_id()() == other_vtable.type_id()()
-            {
+            if this.is_from_this_allocator() {
                 Vec::from_raw_parts(this.buffer_mut(), this.len(), this.capacity())
             } else {
                 let len = this.length;
@@ -527,6 +817,50 @@ impl<T> RVec<T> {
         }
     }
 
+    /// Converts this `RVec<T>` into a `Vec<T>` without reallocating,
+    /// returning the `RVec<T>` back if that isn't possible.
+    ///
+    /// This only succeeds if the `RVec<T>` was created in the
+    /// dynamic library/binary that calls this method,
+    /// since those are the only `RVec`s known to use the same allocator as `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let list = rvec![3, 5, 8];
+    /// let ptr = list.as_ptr();
+    ///
+    /// let list: Vec<u8> = list.into_vec_same_allocator().unwrap();
+    /// assert_eq!(list, [3, 5, 8]);
+    /// assert_eq!(list.as_ptr(), ptr);
+    ///
+    /// ```
+    pub fn into_vec_same_allocator(self) -> Result<Vec<T>, Self> {
+        if self.is_from_this_allocator() {
+            let mut this = ManuallyDrop::new(self);
+            unsafe {
+                Ok(Vec::from_raw_parts(
+                    this.buffer_mut(),
+                    this.len(),
+                    this.capacity(),
+                ))
+            }
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Whether the buffer of this `RVec<T>` was allocated by the
+    /// dynamic library/binary that calls this method.
+    fn is_from_this_allocator(&self) -> bool {
+        let this_vtable = self.vtable();
+        let other_vtable = VTableGetter::<T>::LIB_VTABLE;
+        ::std::ptr::eq(this_vtable.0.to_raw_ptr(), other_vtable.0.to_raw_ptr())
+            || this_vtable.type_id()() == other_vtable.type_id()()
+    }
+
     /// Creates a `Vec<T>`, copying all the elements of this `RVec<T>`.
     ///
     /// # Example
@@ -905,6 +1239,117 @@ impl<T> RVec<T> {
         };
     }
 
+    /// Removes consecutive elements that `same_bucket` considers equal,
+    /// keeping the first element of each run.
+    ///
+    /// `same_bucket` is passed the current element and the last element that was kept,
+    /// in that order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec!["foo", "FOO", "bar", "Bar", "foo"];
+    ///
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(list.as_slice(), &["foo", "bar", "foo"]);
+    ///
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+
+        let slice = self.as_mut_slice();
+        // The amount of elements that are kept, all of which are before `read`
+        let mut write = 1;
+        for read in 1..len {
+            let (kept, rem) = slice.split_at_mut(read);
+            if !same_bucket(&mut rem[0], &mut kept[write - 1]) {
+                slice.swap(read, write);
+                write += 1;
+            }
+        }
+        self.truncate(write);
+    }
+
+    /// Removes consecutive elements that map to the same key,
+    /// keeping the first element of each run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![10, 13, 21, 25, 28, 30];
+    ///
+    /// list.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(list.as_slice(), &[10, 21, 30]);
+    ///
+    /// ```
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes consecutive equal elements, keeping the first element of each run.
+    ///
+    /// To remove all duplicates, not just consecutive ones, use [`unique`](#method.unique).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![1, 1, 2, 3, 3, 3, 1];
+    ///
+    /// list.dedup();
+    /// assert_eq!(list.as_slice(), &[1, 2, 3, 1]);
+    ///
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Removes all duplicate elements, keeping the first occurrence of each one,
+    /// and preserving the order of the kept elements.
+    ///
+    /// Unlike [`dedup`](#method.dedup), this removes duplicates that aren't consecutive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::rvec;
+    ///
+    /// let mut list = rvec![3, 1, 3, 2, 1, 3, 4];
+    ///
+    /// list.unique();
+    /// assert_eq!(list.as_slice(), &[3, 1, 2, 4]);
+    ///
+    /// ```
+    pub fn unique(&mut self)
+    where
+        T: Hash + Eq,
+    {
+        let keep = {
+            let mut seen = HashSet::with_capacity(self.len());
+            self.iter().map(|x| seen.insert(x)).collect::<Vec<bool>>()
+        };
+        let mut keep = keep.into_iter();
+        self.retain(|_| keep.next() == Some(true));
+    }
+
     fn truncate_inner(&mut self, to: usize) {
         let old_length = self.length;
         self.length = to;
@@ -970,6 +1415,15 @@ impl<T> RVec<T> {
         }
     }
 
+    fn assert_insert_from_slice_index(&self, index: usize) {
+        assert!(
+            index <= self.length,
+            "index out of bounds, index={} len={} ",
+            index,
+            self.length
+        );
+    }
+
     fn resize_capacity(&mut self, to: usize, exactness: Exactness) {
         let vtable = self.vtable();
         if self.capacity() < to {
@@ -1049,6 +1503,47 @@ where
             self.push(elem.clone());
         }
     }
+
+    /// Inserts clones of the elements of the slice at `index` position,
+    /// shifting all the elements after it to the right.
+    ///
+    /// If cloning an element panics,
+    /// the elements from `index` onwards are leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.len() < index`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RString, RVec};
+    ///
+    /// let mut list = RVec::from(vec![RString::from("foo"), RString::from("qux")]);
+    ///
+    /// list.insert_from_slice(1, &["bar".into(), "baz".into()]);
+    ///
+    /// assert_eq!(list.as_slice(), &["foo", "bar", "baz", "qux"]);
+    /// ```
+    pub fn insert_from_slice(&mut self, index: usize, slic_: &[T]) {
+        self.assert_insert_from_slice_index(index);
+        self.reserve(slic_.len());
+        let old_len = self.length;
+        unsafe {
+            let buffer = self.buffer_mut();
+            ptr::copy(
+                buffer.offset(index as isize),
+                buffer.offset((index + slic_.len()) as isize),
+                old_len - index,
+            );
+            // The elements after `index` are leaked if `clone` panics.
+            self.length = index;
+            for (i, elem) in slic_.iter().enumerate() {
+                ptr::write(buffer.offset((index + i) as isize), elem.clone());
+            }
+            self.length = old_len + slic_.len();
+        }
+    }
 }
 
 impl<T> RVec<T>
@@ -1084,6 +1579,112 @@ where
             self.length = old_len + slic_.len();
         }
     }
+
+    /// Inserts copies of the elements of the slice at `index` position,
+    /// shifting all the elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.len() < index`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list = RVec::from(vec![0, 1, 2, 3]);
+    ///
+    /// list.insert_from_copy_slice(2, &[10, 11]);
+    /// assert_eq!(list.as_slice(), &[0, 1, 10, 11, 2, 3]);
+    ///
+    /// list.insert_from_copy_slice(6, &[20]);
+    /// assert_eq!(list.as_slice(), &[0, 1, 10, 11, 2, 3, 20]);
+    ///
+    /// ```
+    pub fn insert_from_copy_slice(&mut self, index: usize, slic_: &[T]) {
+        self.assert_insert_from_slice_index(index);
+        self.reserve(slic_.len());
+        let old_len = self.length;
+        unsafe {
+            let buffer = self.buffer_mut();
+            ptr::copy(
+                buffer.offset(index as isize),
+                buffer.offset((index + slic_.len()) as isize),
+                old_len - index,
+            );
+            ptr::copy_nonoverlapping(slic_.as_ptr(), buffer.offset(index as isize), slic_.len());
+            self.length = old_len + slic_.len();
+        }
+    }
+}
+
+impl<T> RVec<RVec<T>>
+where
+    T: Clone,
+{
+    /// Flattens this `RVec` of `RVec`s into a single `RVec`,
+    /// cloning all the elements.
+    ///
+    /// This is named differently than the [`concat`](slice::concat) slice method
+    /// (accessible through `Deref`, returning a `Vec<T>`) so that it doesn't shadow it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec, std_types::RVec};
+    ///
+    /// let list = rvec![rvec![0, 1], RVec::new(), rvec![2], rvec![3, 4, 5]];
+    ///
+    /// assert_eq!(list.concat_rvec(), rvec![0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(RVec::<RVec<u8>>::new().concat_rvec(), RVec::<u8>::new());
+    ///
+    /// ```
+    pub fn concat_rvec(&self) -> RVec<T> {
+        let len = self.iter().map(|x| x.len()).sum();
+        let mut ret = RVec::with_capacity(len);
+        for list in self {
+            ret.extend_from_slice(list);
+        }
+        ret
+    }
+}
+
+impl RVec<RString> {
+    /// Concatenates all the strings in this `RVec` into a single `RString`,
+    /// with `sep` between each one.
+    ///
+    /// This is named differently than the [`join`](slice::join) slice method
+    /// (accessible through `Deref`, returning a `String`) so that it doesn't shadow it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{
+    ///     rvec,
+    ///     std_types::{RString, RVec},
+    /// };
+    ///
+    /// let list: RVec<RString> = rvec!["foo".into(), "bar".into(), "baz".into()];
+    ///
+    /// assert_eq!(list.join_rstring(", "), "foo, bar, baz");
+    /// assert_eq!(list.join_rstring(""), "foobarbaz");
+    ///
+    /// assert_eq!(RVec::<RString>::new().join_rstring(", "), "");
+    ///
+    /// ```
+    pub fn join_rstring(&self, sep: &str) -> RString {
+        let len =
+            self.iter().map(|x| x.len()).sum::<usize>() + sep.len() * self.len().saturating_sub(1);
+        let mut ret = RString::with_capacity(len);
+        for (i, string) in self.iter().enumerate() {
+            if i != 0 {
+                ret.push_str(sep);
+            }
+            ret.push_str(string);
+        }
+        ret
+    }
 }
 
 impl<T> Clone for RVec<T>
@@ -1263,6 +1864,12 @@ impl<T> RVec<T> {
     /// The elements in the range will be removed even if the iterator
     /// was dropped before yielding them.
     ///
+    /// # Leaking
+    ///
+    /// If the returned iterator is leaked (eg: with `std::mem::forget`),
+    /// the `RVec<T>` is left with only the elements before the range,
+    /// leaking the rest, like `Vec::drain` does.
+    ///
     /// # Example
     ///
     /// ```
@@ -1296,24 +1903,38 @@ impl<T> RVec<T> {
     where
         R: RangeBounds<usize>,
     {
+        let slice_start = match range.start_bound() {
+            Bound::Unbounded => 0,
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.saturating_add(1),
+        };
+        let slice_end = match range.end_bound() {
+            Bound::Unbounded => self.length,
+            Bound::Included(&n) => n.saturating_add(1),
+            Bound::Excluded(&n) => n,
+        };
+        assert!(
+            slice_start <= slice_end,
+            "drain range starts at {} but ends at {}",
+            slice_start,
+            slice_end,
+        );
+        assert!(
+            slice_end <= self.length,
+            "drain range end {} is out of bounds for an RVec of length {}",
+            slice_end,
+            self.length,
+        );
+
         unsafe {
-            let slice_start = match range.start_bound() {
-                Bound::Unbounded => 0,
-                Bound::Included(&n) => n,
-                Bound::Excluded(&n) => n.saturating_add(1),
-            };
-            let slice_end = match range.end_bound() {
-                Bound::Unbounded => self.length,
-                Bound::Included(&n) => n.saturating_add(1),
-                Bound::Excluded(&n) => n,
-            };
             let slice_len = slice_end - slice_start;
 
             let allocation_start = self.buffer.as_ptr();
             let removed_start = allocation_start.add(slice_start);
             let iter = RawValIter::new(removed_start, slice_len);
             let old_length = self.length;
-            self.length = 0;
+            // Leaking the Drain leaves the elements before the range in the RVec.
+            self.length = slice_start;
 
             Drain {
                 removed_start,
@@ -1363,6 +1984,9 @@ impl<'a, T> IntoIterator for &'a mut RVec<T> {
     }
 }
 
+/// Collecting a `Vec<T>` that was converted into an iterator
+/// reuses its allocation,
+/// to do the same with an `RVec<T>` use [`IntoIter::into_rvec`].
 impl<T> FromIterator<T> for RVec<T> {
     fn from_iter<I>(iter: I) -> Self
     where
@@ -1464,6 +2088,7 @@ impl<'a, T: 'a> VTableGetter<'a, T> {
         destructor: destructor_vec::<T>,
         grow_capacity_to: grow_capacity_to_vec::<T>,
         shrink_to_fit: shrink_to_fit_vec::<T>,
+        shrink_to: shrink_to_vec::<T>,
     };
 
     staticref! {
@@ -1497,6 +2122,8 @@ struct VecVTable {
     grow_capacity_to: unsafe extern "C" fn(RMut<'_, ()>, usize, Exactness),
     #[sabi(last_prefix_field)]
     shrink_to_fit: unsafe extern "C" fn(RMut<'_, ()>),
+    #[sabi(missing_field(option))]
+    shrink_to: unsafe extern "C" fn(RMut<'_, ()>, usize),
 }
 
 unsafe extern "C" fn destructor_vec<T>(this: RMut<'_, ()>) {
@@ -1531,3 +2158,12 @@ unsafe extern "C" fn shrink_to_fit_vec<T>(this: RMut<'_, ()>) {
         })
     }}
 }
+
+unsafe extern "C" fn shrink_to_vec<T>(this: RMut<'_, ()>, min_capacity: usize) {
+    extern_fn_panic_handling! {no_early_return; unsafe {
+        let this = this.transmute_into_mut::<RVec<T>>();
+        this.with_vec(|list| {
+            list.shrink_to(min_capacity);
+        })
+    }}
+}
@@ -1,9 +1,31 @@
 use super::*;
 
-use crate::utils::distance_from;
+use crate::{erased_types::IteratorItem, std_types::RBox, utils::distance_from};
 
 use std::slice;
 
+/// The `InterfaceType` of the [`IterMut`] iterator returned by
+/// [`RVec::sabi_iter_mut`](super::RVec::sabi_iter_mut).
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(DoubleEndedIterator))]
+pub struct MutIterInterface<T>(PhantomData<T>);
+
+impl<T> MutIterInterface<T> {
+    /// Constructs this type.
+    pub const NEW: Self = Self(PhantomData);
+}
+
+impl<'a, T: 'a> IteratorItem<'a> for MutIterInterface<T> {
+    type Item = &'a mut T;
+}
+
+/// An ffi-safe iterator over mutable references to the elements of an `RVec<T>`,
+/// returned by [`RVec::sabi_iter_mut`](super::RVec::sabi_iter_mut).
+pub type IterMut<'a, T> = DynTrait<'a, RBox<()>, MutIterInterface<T>>;
+
+///////////////////////////////////////////////////////////////////////////////
+
 pub(super) struct RawValIter<T> {
     pub(super) start: *const T,
     pub(super) end: *const T,
@@ -142,6 +164,42 @@ impl<T> IntoIter<T> {
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         self.iter.as_mut_slice()
     }
+
+    /// Converts the remainder of the `RVec<T>` that is being iterated over
+    /// back into an `RVec<T>`, reusing its allocation.
+    ///
+    /// Collecting this iterator with `.collect::<RVec<T>>()` allocates a new buffer,
+    /// because stable Rust doesn't allow specializing `FromIterator` for this iterator,
+    /// this method can be used instead to avoid that allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let list = RVec::from(vec![0, 1, 2, 3]);
+    /// let ptr = list.as_ptr();
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(iter.next(), Some(0));
+    ///
+    /// let list = iter.into_rvec();
+    /// assert_eq!(list, RVec::from(vec![1, 2, 3]));
+    /// assert_eq!(list.as_ptr(), ptr);
+    ///
+    /// ```
+    pub fn into_rvec(self) -> RVec<T> {
+        let mut this = ManuallyDrop::new(self);
+        let len = this.iter.calculate_length();
+        unsafe {
+            let mut vec = ManuallyDrop::take(&mut this._buf);
+            if mem::size_of::<T>() != 0 {
+                ptr::copy(this.iter.start, vec.as_mut_ptr(), len);
+            }
+            vec.length = len;
+            vec
+        }
+    }
 }
 
 impl<T> Iterator for IntoIter<T> {
@@ -1,11 +1,12 @@
 use super::*;
 
-use std::{iter, sync::Arc};
+use std::{iter, mem, sync::Arc};
 
 #[allow(unused_imports)]
 use core_extensions::SelfOps;
 
 use crate::{
+    std_types::RString,
     test_utils::{must_panic, ShouldHavePanickedAt},
     traits::IntoReprC,
 };
@@ -96,6 +97,64 @@ fn vec_drain() {
     assert_eq_drain!(3..5, vec![b'a', b'b', b'c', b'f', b'g', b'h', b'i', b'j']);
 }
 
+#[test]
+fn vec_drain_middle() {
+    let mut list = (0..10).collect::<RVec<u32>>();
+    {
+        let mut drain = list.drain(3..=6);
+        assert_eq!(drain.size_hint(), (4, Some(4)));
+        assert_eq!(drain.next(), Some(3));
+        assert_eq!(drain.next_back(), Some(6));
+    }
+    assert_eq!(list.as_slice(), &[0, 1, 2, 7, 8, 9]);
+
+    assert_eq!(list.drain(1..1).count(), 0);
+    assert_eq!(list.as_slice(), &[0, 1, 2, 7, 8, 9]);
+
+    assert_eq!(list.drain(2..4).collect::<Vec<_>>(), vec![2, 7]);
+    assert_eq!(list.as_slice(), &[0, 1, 8, 9]);
+}
+
+#[test]
+fn vec_drain_out_of_bounds() {
+    let mut list = (0..10).collect::<RVec<u32>>();
+
+    must_panic(|| list.clone().drain(..11).count()).unwrap();
+    must_panic(|| list.clone().drain(5..=10).count()).unwrap();
+    must_panic(|| list.clone().drain(11..).count()).unwrap();
+    #[allow(clippy::reversed_empty_ranges)]
+    must_panic(|| list.clone().drain(6..5).count()).unwrap();
+    must_panic(|| list.clone().drain(..=usize::MAX).count()).unwrap();
+
+    assert_eq!(list.drain(10..).count(), 0);
+    assert_eq!(list.len(), 10);
+}
+
+#[test]
+fn vec_drain_forget() {
+    let pointer = Arc::new(());
+    let make = || iter::repeat(pointer.clone()).take(10);
+
+    let mut std_list = make().collect::<Vec<_>>();
+    let mut list = make().collect::<RVec<_>>();
+    assert_eq!(Arc::strong_count(&pointer), 21);
+
+    let mut std_drain = std_list.drain(3..6);
+    let mut drain = list.drain(3..6);
+    drop((std_drain.next(), drain.next()));
+    mem::forget(std_drain);
+    mem::forget(drain);
+
+    assert_eq!(list.len(), std_list.len());
+    assert_eq!(list.len(), 3);
+    assert_eq!(Arc::strong_count(&pointer), 1 + 9 + 9);
+
+    drop(std_list);
+    drop(list);
+    // the elements from index 4 onwards were leaked by both vectors.
+    assert_eq!(Arc::strong_count(&pointer), 1 + 6 + 6);
+}
+
 #[test]
 fn insert_remove() {
     let (original, list) = typical_list(b'd');
@@ -151,6 +210,60 @@ fn swap_remove() {
     assert_eq!(&*list, &*vec![14, 13, 12]);
 }
 
+#[test]
+fn swap_remove_panics() -> Result<(), ShouldHavePanickedAt> {
+    let mut list = RVec::new();
+    for (i, elem) in (10..20).enumerate() {
+        must_panic(|| list.swap_remove(i))?;
+        list.push(elem);
+        list.swap_remove(i);
+        list.push(elem);
+    }
+    Ok(())
+}
+
+#[test]
+fn remove_owned_elements() {
+    let arcs: Vec<Arc<i32>> = (0..5).map(Arc::new).collect();
+    let mut list: RVec<Arc<i32>> = arcs.iter().cloned().collect();
+    let mut std_list: Vec<Arc<i32>> = arcs.to_vec();
+    for arc in &arcs {
+        assert_eq!(Arc::strong_count(arc), 3);
+    }
+
+    let mut removed = Vec::new();
+    for i in [3, 1] {
+        removed.push((list.remove(i), std_list.remove(i)));
+        removed.push((list.swap_remove(0), std_list.swap_remove(0)));
+    }
+    removed.push((list.remove(0), std_list.remove(0)));
+    assert_eq!(list.len(), 0);
+    assert_eq!(std_list.len(), 0);
+
+    for ((elem, std_elem), expected) in removed.iter().zip([3, 0, 1, 4, 2]) {
+        assert!(Arc::ptr_eq(elem, &arcs[expected]));
+        assert!(Arc::ptr_eq(std_elem, &arcs[expected]));
+    }
+    drop(removed);
+    for arc in &arcs {
+        assert_eq!(Arc::strong_count(arc), 1);
+    }
+
+    let mut list: RVec<RString> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|&x| RString::from(x))
+        .collect();
+    let mut std_list: Vec<RString> = list.to_vec();
+    for (i, j) in [(3, 1), (0, 1), (0, 0)] {
+        assert_eq!(list.remove(i), std_list.remove(i));
+        assert_eq!(list, std_list);
+        if !list.is_empty() {
+            assert_eq!(list.swap_remove(j), std_list.swap_remove(j));
+            assert_eq!(list, std_list);
+        }
+    }
+}
+
 #[test]
 fn push_pop() {
     let mut list = RVec::<u32>::new();
@@ -197,7 +310,7 @@ fn truncate() {
         let length = 10;
         let mut list = iter::repeat(pointer.clone())
             .take(length)
-            .collect::<Vec<_>>();
+            .collect::<RVec<_>>();
 
         assert_eq!(Arc::strong_count(&pointer), 1 + length);
         for i in (0..list.len()).rev() {
@@ -208,6 +321,155 @@ fn truncate() {
     }
 }
 
+#[test]
+fn truncate_clear_drops() {
+    use std::cell::Cell;
+
+    struct CountDrops<'a> {
+        index: usize,
+        drop_counts: &'a [Cell<u32>],
+    }
+
+    impl Drop for CountDrops<'_> {
+        fn drop(&mut self) {
+            let count = &self.drop_counts[self.index];
+            count.set(count.get() + 1);
+        }
+    }
+
+    let drop_counts = (0..8).map(|_| Cell::new(0)).collect::<Vec<Cell<u32>>>();
+    let make_list = || {
+        (0..drop_counts.len())
+            .map(|index| CountDrops {
+                index,
+                drop_counts: &drop_counts,
+            })
+            .collect::<RVec<_>>()
+    };
+    let counts = || drop_counts.iter().map(Cell::get).collect::<Vec<u32>>();
+
+    for mut list in [make_list(), make_list().set_vtable_for_testing()] {
+        let capacity = list.capacity();
+
+        list.truncate(10);
+        assert_eq!(counts(), [0, 0, 0, 0, 0, 0, 0, 0]);
+
+        list.truncate(5);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.capacity(), capacity);
+        assert_eq!(counts(), [0, 0, 0, 0, 0, 1, 1, 1]);
+
+        list.truncate(5);
+        assert_eq!(counts(), [0, 0, 0, 0, 0, 1, 1, 1]);
+
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.capacity(), capacity);
+        assert_eq!(counts(), [1, 1, 1, 1, 1, 1, 1, 1]);
+
+        list.clear();
+        assert_eq!(list.capacity(), capacity);
+        assert_eq!(counts(), [1, 1, 1, 1, 1, 1, 1, 1]);
+
+        // reusing the buffer after clearing it doesn't reallocate
+        let buffer_ptr = list.as_ptr();
+        list.extend((0..capacity).map(|index| CountDrops {
+            index: index % drop_counts.len(),
+            drop_counts: &drop_counts,
+        }));
+        assert_eq!(list.as_ptr(), buffer_ptr);
+        assert_eq!(list.capacity(), capacity);
+
+        drop(list);
+        let total_drops = counts().iter().sum::<u32>() as usize;
+        assert_eq!(total_drops, drop_counts.len() + capacity);
+        drop_counts.iter().for_each(|c| c.set(0));
+    }
+}
+
+#[test]
+fn partition_point() {
+    let list: RVec<(u32, &str)> = rvec![(1, "a"), (3, "b"), (3, "c"), (4, "d"), (9, "e")];
+
+    assert_eq!(list.partition_point(|&(k, _)| k < 1), 0);
+    assert_eq!(list.partition_point(|&(k, _)| k < 3), 1);
+    assert_eq!(list.partition_point(|&(k, _)| k <= 3), 3);
+    assert_eq!(list.partition_point(|&(k, _)| k < 9), 4);
+    assert_eq!(list.partition_point(|&(k, _)| k <= 9), 5);
+
+    let start = list.partition_point(|&(k, _)| k < 3);
+    let end = list.partition_point(|&(k, _)| k <= 4);
+    assert_eq!(list[start..end], [(3, "b"), (3, "c"), (4, "d")]);
+
+    let list = list.set_vtable_for_testing();
+    assert_eq!(list.partition_point(|&(k, _)| k < 4), 3);
+}
+
+#[test]
+fn dedup() {
+    let mut list = RVec::<u32>::new();
+    list.dedup();
+    assert!(list.is_empty());
+
+    let mut list = rvec![1, 1, 1, 2, 2, 3, 1, 1, 4, 4];
+    list.dedup();
+    assert_eq!(list, [1, 2, 3, 1, 4]);
+
+    let mut list = rvec![1, 2, 3];
+    list.dedup();
+    assert_eq!(list, [1, 2, 3]);
+}
+
+#[test]
+fn dedup_by_drops() {
+    use std::cell::Cell;
+
+    struct CountDrops<'a> {
+        key: usize,
+        drop_count: &'a Cell<u32>,
+    }
+
+    impl Drop for CountDrops<'_> {
+        fn drop(&mut self) {
+            self.drop_count.set(self.drop_count.get() + 1);
+        }
+    }
+
+    let drop_counts = (0..6).map(|_| Cell::new(0)).collect::<Vec<Cell<u32>>>();
+    let mut list = drop_counts
+        .iter()
+        .enumerate()
+        .map(|(i, drop_count)| CountDrops {
+            key: i / 2,
+            drop_count,
+        })
+        .collect::<RVec<_>>();
+
+    list.dedup_by_key(|x| x.key);
+    assert_eq!(list.iter().map(|x| x.key).collect::<Vec<_>>(), [0, 1, 2]);
+
+    let counts = drop_counts.iter().map(Cell::get).collect::<Vec<u32>>();
+    assert_eq!(counts, [0, 1, 0, 1, 0, 1]);
+}
+
+#[test]
+fn unique() {
+    let mut list = RVec::<RString>::new();
+    list.unique();
+    assert!(list.is_empty());
+
+    let mut list = ["b", "a", "b", "c", "a", "a", "d", "c", "b"]
+        .iter()
+        .map(|s| RString::from(*s))
+        .collect::<RVec<RString>>();
+    list.unique();
+    assert_eq!(list, ["b", "a", "c", "d"]);
+
+    let mut list = rvec![3, 2, 1];
+    list.unique();
+    assert_eq!(list, [3, 2, 1]);
+}
+
 #[test]
 fn retain() {
     let orig = vec![2, 3, 4, 5, 6, 7, 8];
@@ -354,6 +616,32 @@ fn append() {
     }
 }
 
+#[test]
+fn append_moves_elements() {
+    let arc = Arc::new(0);
+
+    let mut into = rvec![arc.clone(), arc.clone()];
+    let mut from = rvec![arc.clone(), arc.clone(), arc.clone()];
+    let from_capacity = from.capacity();
+    assert_eq!(Arc::strong_count(&arc), 6);
+
+    into.append(&mut from);
+    assert_eq!(into.len(), 5);
+    assert!(from.is_empty());
+    // The buffer of `from` is kept, for reuse.
+    assert_eq!(from.capacity(), from_capacity);
+    // No elements were cloned or dropped.
+    assert_eq!(Arc::strong_count(&arc), 6);
+
+    from.push(arc.clone());
+    assert_eq!(Arc::strong_count(&arc), 7);
+
+    drop(into);
+    assert_eq!(Arc::strong_count(&arc), 2);
+    drop(from);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
 #[test]
 fn into_iter() {
     assert_eq!(RVec::<()>::new().into_iter().next(), None);
@@ -392,6 +680,51 @@ fn into_iter_as_str() {
     }
 }
 
+#[test]
+fn into_rvec_reuses_allocation() {
+    {
+        let orig = vec![3, 5, 8, 13];
+        let ptr = orig.as_ptr();
+        let list = orig.into_iter().collect::<RVec<u32>>();
+        assert_eq!(list.as_ptr(), ptr);
+        assert_eq!(list, [3, 5, 8, 13][..]);
+    }
+    {
+        let list = RVec::from(vec![3, 5, 8, 13]);
+        let ptr = list.as_ptr();
+        let list = list.into_iter().into_rvec();
+        assert_eq!(list.as_ptr(), ptr);
+        assert_eq!(list, [3, 5, 8, 13][..]);
+    }
+
+    let arc = Arc::new(0);
+    let list = RVec::from(vec![arc.clone(), arc.clone(), arc.clone(), arc.clone()]);
+    let (ptr, capacity) = (list.as_ptr(), list.capacity());
+
+    let mut iter = list.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    let list = iter.into_rvec();
+    assert_eq!(Arc::strong_count(&arc), 3);
+    assert_eq!(list.as_ptr(), ptr);
+    assert_eq!(list.capacity(), capacity);
+    assert_eq!(list.len(), 2);
+
+    drop(list);
+    assert_eq!(Arc::strong_count(&arc), 1);
+
+    let zsts = RVec::from(vec![(), (), ()])
+        .into_iter()
+        .skip(1)
+        .collect::<RVec<()>>();
+    assert_eq!(zsts.len(), 2);
+    let mut iter = RVec::from(vec![(), (), ()]).into_iter();
+    iter.next();
+    assert_eq!(iter.into_rvec().len(), 2);
+}
+
 #[test]
 fn clone() {
     let orig = vec![10, 11, 12, 13];
@@ -442,6 +775,138 @@ fn into_vec() {
     }
 }
 
+#[test]
+fn into_vec_same_allocator() {
+    let orig = vec![3, 5, 8, 13];
+    {
+        let list = RVec::from(orig.clone());
+        let list_ptr = list.as_ptr();
+        let list_1 = list.into_vec_same_allocator().unwrap();
+        assert_eq!(list_ptr, list_1.as_ptr());
+        assert_eq!(orig, list_1);
+    }
+    {
+        let list = RVec::from(orig.clone()).set_vtable_for_testing();
+        let list_ptr = list.as_ptr();
+        let list_1 = list.into_vec_same_allocator().unwrap_err();
+        assert_eq!(list_ptr, list_1.as_ptr());
+        assert_eq!(orig, list_1);
+    }
+}
+
+#[test]
+fn insert_from_slice() {
+    let strs = |list: &[&str]| list.iter().map(|s| RString::from(*s)).collect::<Vec<_>>();
+
+    let mut list = RVec::from(strs(&["c", "d"]));
+
+    list.insert_from_slice(0, &strs(&["a", "b"]));
+    assert_eq!(list.as_slice(), &strs(&["a", "b", "c", "d"])[..]);
+
+    list.insert_from_slice(2, &strs(&["x", "y", "z"]));
+    assert_eq!(
+        list.as_slice(),
+        &strs(&["a", "b", "x", "y", "z", "c", "d"])[..]
+    );
+
+    list.insert_from_slice(7, &strs(&["e"]));
+    assert_eq!(
+        list.as_slice(),
+        &strs(&["a", "b", "x", "y", "z", "c", "d", "e"])[..]
+    );
+
+    list.insert_from_slice(3, &[]);
+    assert_eq!(list.len(), 8);
+
+    must_panic(|| list.clone().insert_from_slice(9, &strs(&["f"]))).unwrap();
+}
+
+#[test]
+fn insert_from_copy_slice() {
+    let mut list = RVec::from(vec![5u8, 6]);
+
+    list.insert_from_copy_slice(0, &[1, 2]);
+    assert_eq!(list.as_slice(), &[1, 2, 5, 6]);
+
+    list.insert_from_copy_slice(2, &[3, 4]);
+    assert_eq!(list.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+    list.insert_from_copy_slice(6, &[7, 8, 9]);
+    assert_eq!(list.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    list.insert_from_copy_slice(9, &[]);
+    assert_eq!(list.len(), 9);
+
+    must_panic(|| list.clone().insert_from_copy_slice(10, &[10])).unwrap();
+}
+
+#[test]
+fn leak() {
+    let arc = Arc::new(0);
+    let list = RVec::from(vec![arc.clone(), arc.clone(), arc.clone()]);
+    let (ptr, capacity) = (list.as_ptr(), list.capacity());
+
+    let mut slice: RSliceMut<'static, Arc<i32>> = list.leak();
+    assert_eq!(slice.as_ptr(), ptr);
+    assert_eq!(slice.len(), 3);
+    assert!(slice.iter().all(|x| Arc::ptr_eq(x, &arc)));
+    assert_eq!(Arc::strong_count(&arc), 4);
+
+    // deallocating the leaked memory, so that miri doesn't report it.
+    unsafe { drop(Vec::from_raw_parts(slice.as_mut_ptr(), 3, capacity)) }
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+fn spare_capacity_mut() {
+    let mut list = RVec::<u8>::with_capacity(16);
+    list.extend_from_slice(b"abc");
+    let (ptr, capacity) = (list.as_ptr(), list.capacity());
+
+    let spare = list.spare_capacity_mut();
+    assert_eq!(spare.len(), capacity - 3);
+    for (elem, byte) in spare.iter_mut().zip(b"defgh") {
+        elem.write(*byte);
+    }
+    unsafe { list.set_len(8) };
+
+    assert_eq!(list.as_ptr(), ptr);
+    assert_eq!(list.as_slice(), b"abcdefgh");
+
+    list.extend_from_slice(&vec![0; capacity - 8]);
+    assert!(list.spare_capacity_mut().is_empty());
+}
+
+#[test]
+fn shrink_to() {
+    let make_list = || (0..10).collect::<RVec<u32>>();
+
+    for mut list in [make_list(), make_list().set_vtable_for_testing()] {
+        list.reserve_exact(90);
+        assert_eq!(list.capacity(), 100);
+
+        list.shrink_to(40);
+        assert!(list.capacity() >= 40, "{}", list.capacity());
+        assert!(list.capacity() < 100, "{}", list.capacity());
+        assert_eq!(list, make_list());
+
+        let capacity = list.capacity();
+        list.shrink_to(capacity + 10);
+        assert_eq!(list.capacity(), capacity);
+
+        list.shrink_to(5);
+        assert_eq!(list.capacity(), 10);
+        assert_eq!(list, make_list());
+
+        list.clear();
+        list.shrink_to(0);
+        assert_eq!(list.capacity(), 0);
+
+        list.push(3);
+        assert_eq!(list, [3]);
+    }
+}
+
 #[test]
 fn rvec_macro() {
     assert_eq!(RVec::<u32>::new(), rvec![]);
@@ -549,3 +1014,203 @@ fn test_slice_mut() {
     assert_eq!(s.slice_mut(1..2), RSliceMut::from_mut_slice(&mut [2]));
     assert_eq!(s.slice_mut(3..), RSliceMut::from_mut_slice(&mut [4, 5]));
 }
+
+#[test]
+fn io_write() {
+    use std::io::Write;
+
+    let mut list = RVec::<u8>::new();
+
+    write!(list, "{}", 42).unwrap();
+    assert_eq!(&list[..], b"42");
+
+    assert_eq!(list.write(&[0, 1, 2]).unwrap(), 3);
+    list.write_all(&[3, 4]).unwrap();
+    list.flush().unwrap();
+    assert_eq!(&list[..], &[b'4', b'2', 0, 1, 2, 3, 4][..]);
+}
+
+#[test]
+fn sorting() {
+    // (key, original index) pairs, with many repeated keys
+    let make_list = || {
+        (0..1000_u32)
+            .map(|i| (i.wrapping_mul(7919) % 37, i))
+            .collect::<RVec<(u32, u32)>>()
+    };
+    let assert_stable = |list: &[(u32, u32)]| {
+        for pair in list.windows(2) {
+            let ((lkey, lindex), (rkey, rindex)) = (pair[0], pair[1]);
+            assert!(lkey < rkey || lkey == rkey && lindex < rindex, "{:?}", pair);
+        }
+    };
+    let mut sorted = make_list().into_vec();
+    sorted.sort();
+
+    // The scratch space that the stable sorts allocate is freed before they return,
+    // so sorting an `RVec` from another dynamic library through `Deref` is fine.
+    let lists = || vec![make_list(), make_list().set_vtable_for_testing()];
+
+    for mut list in lists() {
+        list.sort_by_key(|x| x.0);
+        assert_stable(&list);
+    }
+    for mut list in lists() {
+        list.sort_by(|l, r| l.0.partial_cmp(&r.0).unwrap());
+        assert_stable(&list);
+    }
+    for mut list in lists() {
+        list.sort_unstable_by_key(|x| x.0);
+        assert!(list.windows(2).all(|x| x[0].0 <= x[1].0));
+    }
+    for mut list in lists() {
+        list.sort();
+        assert_eq!(list, sorted);
+    }
+    for mut list in lists() {
+        list.sort_unstable();
+        assert_eq!(list, sorted);
+    }
+}
+
+#[test]
+fn sabi_iter_mut() {
+    fn add_index(iter: IterMut<'_, RString>) {
+        for (i, s) in iter.enumerate() {
+            s.push_str(&i.to_string());
+        }
+    }
+
+    let mut list = vec!["a", "b", "c"]
+        .into_iter()
+        .map(RString::from)
+        .collect::<RVec<_>>();
+
+    add_index(list.sabi_iter_mut());
+    assert_eq!(list, ["a0", "b1", "c2"]);
+
+    {
+        let mut iter = list.sabi_iter_mut();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next_back().unwrap().push('!');
+        *iter.next().unwrap() = RString::from("first");
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+    }
+    assert_eq!(list, ["first", "b1", "c2!"]);
+
+    assert_eq!(RVec::<u8>::new().sabi_iter_mut().next(), None);
+}
+
+#[test]
+fn fill() {
+    let mut list = RVec::from(vec![String::new(); 4]);
+    list.reserve(10);
+    let capacity = list.capacity();
+
+    list.fill("hello".to_string());
+    assert_eq!(list, vec!["hello".to_string(); 4]);
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.capacity(), capacity);
+
+    let mut empty = RVec::<String>::new();
+    empty.fill("hello".to_string());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn fill_with() {
+    let mut list = RVec::from(vec![0u32; 5]);
+
+    let mut next = 0;
+    list.fill_with(|| {
+        next += 2;
+        next
+    });
+    assert_eq!(list, vec![2, 4, 6, 8, 10]);
+    assert_eq!(list.len(), 5);
+
+    let mut empty = RVec::<u32>::new();
+    empty.fill_with(|| unreachable!());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn rotate_and_reverse() {
+    let orig = (0..7).map(|x| x.to_string()).collect::<Vec<String>>();
+
+    for mid in 0..=orig.len() {
+        let mut list = orig.iter().cloned().collect::<RVec<String>>();
+        let mut std_list = orig.clone();
+        list.rotate_left(mid);
+        std_list.rotate_left(mid);
+        assert_eq!(list, std_list);
+
+        list.rotate_right(mid);
+        assert_eq!(list, orig);
+    }
+
+    {
+        let mut list = orig.iter().cloned().collect::<RVec<String>>();
+        list.rotate_left(orig.len());
+        assert_eq!(list, orig);
+        list.rotate_right(orig.len());
+        assert_eq!(list, orig);
+
+        must_panic(|| list.rotate_left(orig.len() + 1)).unwrap();
+        must_panic(|| list.rotate_right(orig.len() + 1)).unwrap();
+    }
+    {
+        let mut empty = RVec::<String>::new();
+        empty.rotate_left(0);
+        empty.rotate_right(0);
+        empty.reverse();
+        assert_eq!(empty, RVec::<String>::new());
+
+        must_panic(|| empty.rotate_left(1)).unwrap();
+        must_panic(|| empty.rotate_right(1)).unwrap();
+    }
+    {
+        let mut list = orig.iter().cloned().collect::<RVec<String>>();
+        list.reverse();
+        assert_eq!(list, orig.iter().rev().cloned().collect::<Vec<String>>());
+        list.reverse();
+        assert_eq!(list, orig);
+    }
+}
+
+#[test]
+fn concat_and_join() {
+    {
+        let nested: RVec<RVec<String>> = (0..5)
+            .map(|i| (0..i).map(|j| format!("{}-{}", i, j)).collect())
+            .collect();
+        let flat = nested.concat_rvec();
+        assert_eq!(
+            flat,
+            nested.iter().flatten().cloned().collect::<Vec<String>>()
+        );
+        assert_eq!(flat.len(), 10);
+
+        // the slice method isn't shadowed
+        let std_flat: Vec<String> = nested.concat();
+        assert_eq!(flat, std_flat);
+    }
+    {
+        let nested: RVec<RVec<u8>> = rvec![RVec::new(), RVec::new()];
+        assert_eq!(nested.concat_rvec(), RVec::<u8>::new());
+    }
+    {
+        let strings: RVec<RString> = "a,bc,,💔,d".split(',').map(RString::from).collect();
+        for sep in ["", ", ", "💔"] {
+            let joined = strings.join_rstring(sep);
+            assert_eq!(joined, ["a", "bc", "", "💔", "d"].join(sep));
+        }
+        let single: RVec<RString> = rvec![RString::from("hello")];
+        assert_eq!(single.join_rstring(", "), "hello");
+        assert_eq!(RVec::<RString>::new().join_rstring(", "), "");
+
+        // the slice method isn't shadowed
+        let std_joined: String = strings.join(", ");
+        assert_eq!(std_joined, "a, bc, , 💔, d");
+    }
+}
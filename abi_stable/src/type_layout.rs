@@ -296,6 +296,66 @@ impl TypeLayout {
         self.size
     }
 
+    /// Gets the offsets of the fields of a `#[repr(C)]` or `#[repr(transparent)]` struct,
+    /// in the order that they were declared.
+    ///
+    /// The offsets are computed from the size and alignment of each field's `TypeLayout`.
+    ///
+    /// # Return value
+    ///
+    /// This returns `None` if the type is not a `#[repr(C)]`/`#[repr(transparent)]` struct,
+    /// or if it is a `#[repr(C, packed)]` struct whose packing changes
+    /// the offsets of its fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{std_types::Tuple3, StableAbi};
+    ///
+    /// let layout = <Tuple3<u8, u32, u16> as StableAbi>::LAYOUT;
+    ///
+    /// assert_eq!(layout.field_offsets(), Some(vec![0, 4, 8]));
+    /// assert_eq!(layout.size(), 12);
+    ///
+    /// assert_eq!(<u32 as StableAbi>::LAYOUT.field_offsets(), None);
+    ///
+    /// ```
+    pub fn field_offsets(&self) -> Option<Vec<usize>> {
+        fn round_up(offset: usize, align: usize) -> usize {
+            (offset + align - 1) / align * align
+        }
+
+        match (self.repr_attr(), self.data()) {
+            (ReprAttr::C | ReprAttr::Transparent, TLData::Struct { fields }) => {
+                let mut offset = 0;
+                let mut max_align = 1;
+                let offsets = fields
+                    .iter()
+                    .map(|field| {
+                        let layout = field.layout();
+                        let align = layout.alignment();
+                        max_align = max_align.max(align);
+                        offset = round_up(offset, align);
+                        let field_offset = offset;
+                        offset += layout.size();
+                        field_offset
+                    })
+                    .collect();
+
+                // `#[repr(C, packed)]` structs are recorded as `ReprAttr::C`.
+                // Packing that changes the offsets of fields also lowers the alignment
+                // of the struct below that of its fields, which is detected here.
+                let align = self.alignment();
+                if align < max_align || round_up(offset, align) != self.size() {
+                    return None;
+                }
+
+                Some(offsets)
+            }
+            _ => None,
+        }
+    }
+
     /// Gets the `Tag` associated with a type,
     /// a JSON-like datastructure which is another way to
     /// check extra properties about a type.
@@ -356,6 +416,68 @@ impl TypeLayout {
     pub const fn mono_type_layout(&self) -> &MonoTypeLayout {
         self.mono
     }
+
+    /// Renders this layout as a pseudo-Rust type definition,
+    /// intended for debugging mismatched layouts.
+    ///
+    /// Types are printed the same way as in [`full_type`](Self::full_type),
+    /// and function pointer fields are printed with their signature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use abi_stable::{
+    ///     std_types::{RStr, RString},
+    ///     StableAbi,
+    /// };
+    ///
+    /// #[repr(C)]
+    /// #[derive(StableAbi)]
+    /// struct Entry<'a> {
+    ///     key: RStr<'a>,
+    ///     value: u32,
+    ///     format: extern "C" fn(u32) -> RString,
+    /// }
+    ///
+    /// #[repr(u8)]
+    /// #[derive(StableAbi)]
+    /// enum Shape {
+    ///     Point,
+    ///     Circle(u32),
+    ///     Rectangle { width: u32, height: u32 },
+    /// }
+    ///
+    /// assert_eq!(
+    ///     <Entry<'_> as StableAbi>::LAYOUT.render_definition(),
+    ///     "\
+    ///         #[repr(C)]\n\
+    ///         struct Entry<'a> {\n\
+    ///         \x20   key: RStr<'a>,\n\
+    ///         \x20   value: u32,\n\
+    ///         \x20   format: extern \"C\" fn(u32) -> RString,\n\
+    ///         }\n\
+    ///     ",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     <Shape as StableAbi>::LAYOUT.render_definition(),
+    ///     "\
+    ///         #[repr(u8)]\n\
+    ///         enum Shape {\n\
+    ///         \x20   Point,\n\
+    ///         \x20   Circle(u32),\n\
+    ///         \x20   Rectangle {\n\
+    ///         \x20       width: u32,\n\
+    ///         \x20       height: u32,\n\
+    ///         \x20   },\n\
+    ///         }\n\
+    ///     ",
+    /// );
+    ///
+    /// ```
+    pub fn render_definition(&self) -> String {
+        printing::render_definition(self)
+    }
 }
 
 impl PartialEq for TypeLayout {
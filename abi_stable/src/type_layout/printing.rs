@@ -304,6 +304,215 @@ impl Drop for FmtFullTypeGuard {
 
 ////////////////
 
+/// The implementation of `TypeLayout::render_definition`.
+pub(super) fn render_definition(layout: &TypeLayout) -> String {
+    let mut out = String::new();
+    // Writing into a String can't fail.
+    let _ = write_definition(layout, &mut out);
+    out
+}
+
+fn write_definition(layout: &TypeLayout, out: &mut String) -> fmt::Result {
+    use std::fmt::Write;
+
+    let repr = match layout.repr_attr() {
+        ReprAttr::C => Some("C".to_string()),
+        ReprAttr::CAndInt(discr) => Some(format!("C, {}", discriminant_repr_str(discr))),
+        ReprAttr::Transparent => Some("transparent".to_string()),
+        ReprAttr::Int(discr) => Some(discriminant_repr_str(discr).to_string()),
+        ReprAttr::Packed {
+            alignment_power_of_two,
+        } => Some(format!("C, packed({})", 1_usize << alignment_power_of_two)),
+        ReprAttr::OptionNonZero | ReprAttr::Primitive => None,
+    };
+
+    let header = layout.full_type();
+
+    match layout.data() {
+        TLData::Primitive(_) => {
+            writeln!(out, "// primitive type: {}", header.to_string().trim())?;
+            return Ok(());
+        }
+        TLData::Opaque => {
+            writeln!(
+                out,
+                "// opaque type, size: {} align: {}",
+                layout.size(),
+                layout.alignment(),
+            )?;
+        }
+        _ => {}
+    }
+
+    if let Some(repr) = repr {
+        writeln!(out, "#[repr({})]", repr)?;
+    }
+
+    match layout.data() {
+        TLData::Primitive(_) => {}
+        TLData::Opaque => {
+            writeln!(out, "struct {} {{ .. }}", header)?;
+        }
+        TLData::Struct { fields } => {
+            write!(out, "struct {}", header)?;
+            write_fields(fields.iter(), "", out)?;
+            if is_tuple_like(fields.iter()) {
+                out.push(';');
+            }
+            out.push('\n');
+        }
+        TLData::Union { fields } => {
+            write!(out, "union {}", header)?;
+            write_fields(fields.iter(), "", out)?;
+            out.push('\n');
+        }
+        TLData::Enum(enum_) => {
+            if enum_.exhaustiveness.is_nonexhaustive() {
+                writeln!(out, "// nonexhaustive enum")?;
+            }
+            writeln!(out, "enum {} {{", header)?;
+            let mut fields = enum_.fields.iter();
+            for (name, &field_count) in enum_.variant_names_iter().zip(&*enum_.field_count) {
+                let variant_fields = fields
+                    .by_ref()
+                    .take(field_count as usize)
+                    .collect::<Vec<_>>();
+                write!(out, "    {}", name)?;
+                write_fields(variant_fields.into_iter(), "    ", out)?;
+                writeln!(out, ",")?;
+            }
+            out.push('}');
+            out.push('\n');
+        }
+        TLData::PrefixType(prefix) => {
+            writeln!(out, "#[sabi(kind(Prefix))]")?;
+            writeln!(out, "struct {} {{", header)?;
+            let last_prefix_field = prefix.first_suffix_field as usize;
+            for (i, field) in prefix.fields.iter().enumerate() {
+                if i + 1 == last_prefix_field {
+                    writeln!(out, "    #[sabi(last_prefix_field)]")?;
+                }
+                writeln!(out, "    {}: {},", field.name(), FieldType(&field))?;
+            }
+            out.push('}');
+            out.push('\n');
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the fields of a struct/union/enum variant,
+/// using the tuple syntax for the fields of tuple structs/variants.
+fn write_fields<I>(fields: I, indentation: &str, out: &mut String) -> fmt::Result
+where
+    I: Iterator<Item = TLField> + Clone,
+{
+    use std::fmt::Write;
+
+    if fields.clone().next().is_none() {
+        return Ok(());
+    }
+
+    if is_tuple_like(fields.clone()) {
+        out.push('(');
+        for (i, field) in fields.enumerate() {
+            if i != 0 {
+                out.push_str(", ");
+            }
+            write!(out, "{}", FieldType(&field))?;
+        }
+        out.push(')');
+    } else {
+        out.push_str(" {\n");
+        for field in fields {
+            writeln!(
+                out,
+                "{}    {}: {},",
+                indentation,
+                field.name(),
+                FieldType(&field)
+            )?;
+        }
+        write!(out, "{}}}", indentation)?;
+    }
+    Ok(())
+}
+
+/// Whether the fields are those of a tuple struct/variant,
+/// which the derive macro names `field_0`, `field_1`, etc.
+fn is_tuple_like<I>(fields: I) -> bool
+where
+    I: Iterator<Item = TLField>,
+{
+    fields.enumerate().all(|(i, field)| {
+        field
+            .name()
+            .strip_prefix("field_")
+            .map_or(false, |index| index.parse() == Ok(i))
+    })
+}
+
+fn discriminant_repr_str(discr: DiscriminantRepr) -> &'static str {
+    match discr {
+        DiscriminantRepr::U8 => "u8",
+        DiscriminantRepr::I8 => "i8",
+        DiscriminantRepr::U16 => "u16",
+        DiscriminantRepr::I16 => "i16",
+        DiscriminantRepr::U32 => "u32",
+        DiscriminantRepr::I32 => "i32",
+        DiscriminantRepr::U64 => "u64",
+        DiscriminantRepr::I64 => "i64",
+        DiscriminantRepr::U128 => "u128",
+        DiscriminantRepr::I128 => "i128",
+        DiscriminantRepr::Usize => "usize",
+        DiscriminantRepr::Isize => "isize",
+    }
+}
+
+/// Formats the type of a field,
+/// printing function pointers with their signature.
+struct FieldType<'a>(&'a TLField);
+
+impl Display for FieldType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = self.0;
+        let mut functions = field.function_range().iter();
+        match (functions.next(), functions.next()) {
+            (Some(func), None) if field.layout().name() == "Option" => {
+                write!(f, "Option<{}>", FnSignature(&func))
+            }
+            (Some(func), None) => Display::fmt(&FnSignature(&func), f),
+            _ => Display::fmt(&field.full_type(), f),
+        }
+    }
+}
+
+struct FnSignature<'a>(&'a TLFunction);
+
+impl Display for FnSignature<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let func = self.0;
+        if func.fn_qualifs.is_unsafe() {
+            f.write_str("unsafe ")?;
+        }
+        f.write_str("extern \"C\" fn(")?;
+        for (i, param) in func.get_params().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            Display::fmt(&param.full_type(), f)?;
+        }
+        f.write_str(")")?;
+        if func.return_type_layout().is_some() {
+            write!(f, " -> {}", func.get_return().full_type())?;
+        }
+        Ok(())
+    }
+}
+
+////////////////
+
 struct DecrementLevel;
 
 impl Drop for DecrementLevel {
@@ -109,3 +109,142 @@ fn recursive_debug() {
         }
     }
 }
+
+#[allow(dead_code)]
+mod render {
+    use crate::{
+        std_types::{ROption, RStr, RString, RVec},
+        StableAbi,
+    };
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub(super) struct Named<'a, T> {
+        pub(super) name: RStr<'a>,
+        pub(super) value: T,
+        pub(super) list: RVec<Option<&'a u8>>,
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub(super) struct Buffer<const N: usize> {
+        pub(super) bytes: [u8; N],
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub(super) struct Tuple(pub(super) u32, pub(super) RString);
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub(super) struct Unit;
+
+    #[repr(transparent)]
+    #[derive(StableAbi)]
+    pub(super) struct Wrapper(pub(super) u64);
+
+    #[repr(u8)]
+    #[derive(StableAbi)]
+    pub(super) enum Enum {
+        Empty,
+        Tupled(u8, RString),
+        Braced { left: u16, right: ROption<u16> },
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub(super) union Union {
+        pub(super) int: u32,
+        pub(super) float: f32,
+    }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_ref = Module_Ref)))]
+    pub(super) struct Module {
+        pub(super) first: u8,
+        #[sabi(last_prefix_field)]
+        pub(super) func: extern "C" fn(RStr<'_>, u32) -> RString,
+        pub(super) maybe_func: Option<unsafe extern "C" fn(&mut u32)>,
+    }
+}
+
+#[test]
+fn render_definition() {
+    use crate::abi_stability::PrefixStableAbi;
+
+    let named = <render::Named<'_, u32> as StableAbi>::LAYOUT.render_definition();
+    assert!(
+        named.starts_with("#[repr(C)]\nstruct Named<'a, u32> {\n"),
+        "{}",
+        named
+    );
+    for field in ["name: RStr<'a>,", "value: u32,", "list: RVec<Option<"] {
+        assert!(named.contains(field), "{}", named);
+    }
+
+    let buffer = <render::Buffer<4> as StableAbi>::LAYOUT.render_definition();
+    assert!(
+        buffer.starts_with("#[repr(C)]\nstruct Buffer<4> {\n"),
+        "{}",
+        buffer
+    );
+
+    let list = [
+        (
+            <render::Tuple as StableAbi>::LAYOUT,
+            "#[repr(C)]\nstruct Tuple(u32, RString);\n",
+        ),
+        (
+            <render::Unit as StableAbi>::LAYOUT,
+            "#[repr(C)]\nstruct Unit;\n",
+        ),
+        (
+            <render::Wrapper as StableAbi>::LAYOUT,
+            "#[repr(transparent)]\nstruct Wrapper(u64);\n",
+        ),
+        (
+            <render::Enum as StableAbi>::LAYOUT,
+            "\
+                #[repr(u8)]\n\
+                enum Enum {\n\
+                \x20   Empty,\n\
+                \x20   Tupled(u8, RString),\n\
+                \x20   Braced {\n\
+                \x20       left: u16,\n\
+                \x20       right: ROption<u16>,\n\
+                \x20   },\n\
+                }\n\
+            ",
+        ),
+        (
+            <render::Union as StableAbi>::LAYOUT,
+            "\
+                #[repr(C)]\n\
+                union Union {\n\
+                \x20   int: u32,\n\
+                \x20   float: f32,\n\
+                }\n\
+            ",
+        ),
+        (<u32 as StableAbi>::LAYOUT, "// primitive type: u32\n"),
+    ];
+
+    for (layout, expected) in list {
+        assert_eq!(layout.render_definition(), expected);
+    }
+
+    let module = <render::Module_Prefix as PrefixStableAbi>::LAYOUT.render_definition();
+    assert!(
+        module.starts_with("#[repr(C)]\n#[sabi(kind(Prefix))]\nstruct Module {\n"),
+        "{}",
+        module
+    );
+    for field in [
+        "    first: u8,\n    #[sabi(last_prefix_field)]\n    func: ",
+        "func: extern \"C\" fn(RStr<'a>, u32) -> RString,",
+        "maybe_func: Option<unsafe extern \"C\" fn(",
+    ] {
+        assert!(module.contains(field), "{}", module);
+    }
+}
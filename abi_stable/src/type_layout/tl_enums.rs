@@ -250,6 +250,21 @@ macro_rules! declare_tl_discriminants {
                 }
             }
 
+            /// Formats each discriminant in this `TLDiscriminants`.
+            #[cfg(feature = "serde_json")]
+            pub(crate) fn discriminant_strings(&self)->Vec<String>{
+                match self.inner {
+                    $(
+                        TLDiscrsInner::$variant{discriminants,len}=>unsafe{
+                            std::slice::from_raw_parts(discriminants,len as usize)
+                                .iter()
+                                .map(|x| x.to_string())
+                                .collect()
+                        }
+                    )*
+                }
+            }
+
             /// Compares this `TLDiscriminants` with another,
             ///
             /// # Errors
@@ -139,6 +139,35 @@ pub mod impl_enum {
     /// The `T` type parameter represents the (un)required trait.
     pub type ImplFrom<B, T> = <B as ImplFrom_<T>>::Impl;
 
+    /// Combines two `Implemented<T>`/`Unimplemented<T>`,
+    /// producing `Implemented<T>` if either of them is `Implemented<T>`.
+    ///
+    /// The `T` type parameter represents the (un)required trait.
+    pub trait ImplOr_<B: ?Sized> {
+        /// Either `Unimplemented` or `Implemented`.
+        type Output: Implementability;
+    }
+
+    impl<T: ?Sized> ImplOr_<Implemented<T>> for Implemented<T> {
+        type Output = Implemented<T>;
+    }
+
+    impl<T: ?Sized> ImplOr_<Unimplemented<T>> for Implemented<T> {
+        type Output = Implemented<T>;
+    }
+
+    impl<T: ?Sized> ImplOr_<Implemented<T>> for Unimplemented<T> {
+        type Output = Implemented<T>;
+    }
+
+    impl<T: ?Sized> ImplOr_<Unimplemented<T>> for Unimplemented<T> {
+        type Output = Unimplemented<T>;
+    }
+
+    /// `Implemented<T>` if either `A` or `B` is `Implemented<T>`,
+    /// otherwise `Unimplemented<T>`.
+    pub type ImplOr<A, B> = <A as ImplOr_<B>>::Output;
+
     /// Describes that a trait must be implemented.
     ///
     /// The `T` type parameter represents the required trait.
@@ -1,10 +1,12 @@
 //! Utility functions.
 
 use std::{
+    any::Any,
     cmp::Ord,
     fmt::{self, Debug, Display},
     mem::{self, ManuallyDrop},
     ptr::NonNull,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
 use core_extensions::{strings::LeftPadder, StringExt, TypeIdentity};
@@ -36,6 +38,79 @@ pub fn ffi_panic_message(info: &'static PanicInfo) -> ! {
     std::process::exit(1);
 }
 
+/// The type of the function that [`set_panic_logger`] registers.
+pub type PanicLogger = extern "C" fn(RStr<'_>);
+
+// The address of the registered `PanicLogger`, `0` if none is registered.
+static PANIC_LOGGER: AtomicUsize = AtomicUsize::new(0);
+
+/// Registers the function that is passed the panic message of panics caught by
+/// `#[sabi_extern_fn(log_panic)]` functions
+/// (and `extern_fn_panic_handling!{log_panic; ... }`),
+/// before they abort the process.
+///
+/// Passing `None` unregisters the logger.
+/// This returns the previously registered logger.
+///
+/// Every dynamic library has its own copy of the registered logger,
+/// so to have a dynamic library log panics with a logger provided by the executable,
+/// the executable must pass the logger to the library
+/// (eg: through a function in the root module),
+/// which then calls this function.
+///
+/// # Example
+///
+/// ```rust
+/// use abi_stable::{
+///     std_types::RStr,
+///     utils::{get_panic_logger, set_panic_logger},
+/// };
+///
+/// extern "C" fn log_to_stderr(message: RStr<'_>) {
+///     eprintln!("plugin panicked: {}", message);
+/// }
+///
+/// assert!(set_panic_logger(Some(log_to_stderr)).is_none());
+/// assert!(get_panic_logger().is_some());
+///
+/// assert!(set_panic_logger(None).is_some());
+/// assert!(get_panic_logger().is_none());
+///
+/// ```
+pub fn set_panic_logger(logger: Option<PanicLogger>) -> Option<PanicLogger> {
+    let addr = logger.map_or(0, |logger| logger as usize);
+    let prev = PANIC_LOGGER.swap(addr, Ordering::AcqRel);
+    unsafe { mem::transmute::<usize, Option<PanicLogger>>(prev) }
+}
+
+/// Gets the logger registered with [`set_panic_logger`].
+pub fn get_panic_logger() -> Option<PanicLogger> {
+    let addr = PANIC_LOGGER.load(Ordering::Acquire);
+    unsafe { mem::transmute::<usize, Option<PanicLogger>>(addr) }
+}
+
+/// Passes the message of the panic `payload` to the logger registered with
+/// [`set_panic_logger`] (if any), then aborts the process with [`ffi_panic_message`].
+#[doc(hidden)]
+#[inline(never)]
+#[cold]
+pub fn log_panic_and_abort(payload: Box<dyn Any + Send>, info: &'static PanicInfo) -> ! {
+    if let Some(logger) = get_panic_logger() {
+        logger(RStr::from_str(panic_payload_message(&*payload)));
+    }
+    ffi_panic_message(info)
+}
+
+fn panic_payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&'static str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "<panic payload of an unknown type>"
+    }
+}
+
 //////////////////////////////////
 
 /// Only used inside `PhantomData`,
@@ -363,6 +438,65 @@ pub extern "C" fn get_type_name<T>() -> RStr<'static> {
 mod tests {
     use super::*;
 
+    use crate::sabi_extern_fn;
+
+    use std::{env, process::Command};
+
+    #[sabi_extern_fn(log_panic)]
+    fn halve_even(n: u32) -> u32 {
+        if n % 2 == 1 {
+            return u32::MAX;
+        }
+        if n == 6 {
+            panic!("oh no, {} is too big", n);
+        }
+        n / 2
+    }
+
+    #[test]
+    fn log_panic() {
+        const CHILD_VAR: &str = "ABI_STABLE_LOG_PANIC_TEST_CHILD";
+
+        if env::var_os(CHILD_VAR).is_some() {
+            extern "C" fn logger(message: RStr<'_>) {
+                println!("logged panic: {}", message);
+            }
+            assert!(set_panic_logger(Some(logger)).is_none());
+            halve_even(6);
+            unreachable!("halve_even should have aborted the process");
+        }
+
+        assert_eq!(halve_even(4), 2);
+        assert_eq!(halve_even(5), u32::MAX);
+
+        // Panicking aborts the process, so it's done in a child process
+        let output = Command::new(env::current_exe().unwrap())
+            .args(["--exact", "utils::tests::log_panic", "--nocapture"])
+            .env(CHILD_VAR, "1")
+            .output()
+            .unwrap();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(output.status.code(), Some(1), "{}", stdout);
+        assert!(
+            stdout.contains("logged panic: oh no, 6 is too big\n"),
+            "{}",
+            stdout
+        );
+    }
+
+    #[test]
+    fn panic_payload_messages() {
+        let payloads: Vec<(Box<dyn Any + Send>, &str)> = vec![
+            (Box::new("hello"), "hello"),
+            (Box::new(String::from("world")), "world"),
+            (Box::new(10u32), "<panic payload of an unknown type>"),
+        ];
+        for (payload, expected) in payloads {
+            assert_eq!(panic_payload_message(&*payload), expected);
+        }
+    }
+
     #[test]
     fn distance_from_() {
         let int_array = [0, 1, 2, 3, 4];
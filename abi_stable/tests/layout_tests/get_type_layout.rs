@@ -299,3 +299,108 @@ fn type_layouts_fmt(
         .map(|x| x().full_type().to_string())
         .collect::<Vec<String>>()
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[repr(C)]
+#[derive(abi_stable::StableAbi)]
+struct OffsetsStruct {
+    a: u8,
+    b: u64,
+    c: u16,
+    d: RString,
+    e: u8,
+    f: (),
+}
+
+#[repr(C, packed)]
+#[derive(abi_stable::StableAbi)]
+struct PackedStruct {
+    a: u8,
+    b: u64,
+    c: u16,
+}
+
+#[repr(C, packed(2))]
+#[derive(abi_stable::StableAbi)]
+struct Packed2Struct {
+    a: u8,
+    b: u32,
+}
+
+// Packing that doesn't lower the alignment of any field doesn't change the layout.
+#[repr(C, packed(8))]
+#[derive(abi_stable::StableAbi)]
+struct Packed8Struct {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+#[repr(C, align(16))]
+#[derive(abi_stable::StableAbi)]
+struct AlignedStruct {
+    a: u8,
+    b: u32,
+}
+
+#[test]
+fn size_align_offsets() {
+    use std::mem::{align_of, size_of, MaybeUninit};
+
+    macro_rules! assert_size_align {
+        ($($type:ty),* $(,)?) => ($({
+            let layout = get_type_layout::<$type>();
+            assert_eq!(layout.size(), size_of::<$type>(), "{}", layout.full_type());
+            assert_eq!(layout.alignment(), align_of::<$type>(), "{}", layout.full_type());
+        })*)
+    }
+
+    assert_size_align!(
+        u8,
+        u64,
+        RString,
+        RVec<u16>,
+        ROption<u32>,
+        Tuple3<u8, u32, u16>,
+        OffsetsStruct,
+        PackedStruct,
+        Packed2Struct,
+        Packed8Struct,
+        AlignedStruct,
+    );
+
+    let uninit = MaybeUninit::<OffsetsStruct>::uninit();
+    let base = uninit.as_ptr();
+    macro_rules! offset_of {
+        ($field:ident) => {
+            unsafe { std::ptr::addr_of!((*base).$field) as usize - base as usize }
+        };
+    }
+    let expected = vec![
+        offset_of!(a),
+        offset_of!(b),
+        offset_of!(c),
+        offset_of!(d),
+        offset_of!(e),
+        offset_of!(f),
+    ];
+    assert_eq!(
+        get_type_layout::<OffsetsStruct>().field_offsets(),
+        Some(expected)
+    );
+
+    assert_eq!(get_type_layout::<u64>().field_offsets(), None);
+    assert_eq!(get_type_layout::<ROption<u64>>().field_offsets(), None);
+
+    assert_eq!(get_type_layout::<PackedStruct>().field_offsets(), None);
+    assert_eq!(get_type_layout::<Packed2Struct>().field_offsets(), None);
+    assert_eq!(
+        get_type_layout::<Packed8Struct>().field_offsets(),
+        Some(vec![0, 4, 8])
+    );
+    assert_eq!(
+        get_type_layout::<AlignedStruct>().field_offsets(),
+        Some(vec![0, 4])
+    );
+}
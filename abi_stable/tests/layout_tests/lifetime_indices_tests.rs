@@ -442,3 +442,37 @@ fn test_single_function_lifetime_ranges() {
         }
     }
 }
+
+mod phantom_lifetimes {
+    use std::marker::PhantomData;
+
+    #[repr(C)]
+    #[derive(abi_stable::StableAbi)]
+    #[sabi(phantom_lifetime = 'b)]
+    #[sabi(phantom_lifetime = 'static)]
+    #[sabi(phantom_lifetime = 'a)]
+    pub struct Struct<'a, 'b> {
+        _marker: PhantomData<(&'a (), &'b ())>,
+    }
+}
+
+#[test]
+fn test_phantom_lifetimes() {
+    let layout = <phantom_lifetimes::Struct<'_, '_> as StableAbi>::LAYOUT;
+
+    let phantom_fields = layout.phantom_fields();
+    let expected = [
+        ("_phantom_lifetime_0", LAP::new(LR1, LRN)),
+        ("_phantom_lifetime_1", LAP::new(LRS, LRN)),
+        ("_phantom_lifetime_2", LAP::new(LR0, LRN)),
+    ];
+    assert_eq!(phantom_fields.len(), expected.len());
+
+    for (field, (name, lt_indices)) in phantom_fields.iter().zip(expected) {
+        assert_eq!(field.name(), name);
+        assert_eq!(&field.lifetime_indices()[..], &[lt_indices][..]);
+        assert_eq!(field.layout(), <&() as StableAbi>::LAYOUT);
+    }
+
+    abi_stable::abi_stability::check_layout_compatibility(layout, layout).unwrap();
+}
@@ -104,6 +104,20 @@ mod prefix3 {
     }
 }
 
+mod prefix3_result {
+    #[repr(C)]
+    #[derive(abi_stable::StableAbi)]
+    #[sabi(kind(Prefix))]
+    #[sabi(missing_field(result))]
+    pub struct Prefix {
+        #[sabi(last_prefix_field)]
+        pub field0: u8,
+        pub field1: u16,
+        pub field2: u32,
+        pub field3: u64,
+    }
+}
+
 /// Dereferences the TypeLayout of a `&T` to the layout of `T`
 fn dereference_abi(abi: &'static TypeLayout) -> &'static TypeLayout {
     abi.phantom_fields().get(0).unwrap().layout()
@@ -385,6 +399,34 @@ fn prefix_on_nonexistent_field() {
     }
 }
 
+#[cfg_attr(not(miri), test)]
+fn prefix_result_on_nonexistent_field() {
+    pub const MOD_VAL: &WithMetadata<prefix1::Prefix> = &WithMetadata::new(prefix1::Prefix {
+        field0: 1,
+        field1: 3,
+    });
+
+    let prefix1 = MOD_VAL.static_as_prefix();
+
+    // this transmute is used to test prefix types across library versions
+    let value: prefix3_result::Prefix_Ref = unsafe { std::mem::transmute(prefix1) };
+    assert_eq!(value.field0(), 1);
+    assert_eq!(value.field1().unwrap(), 3);
+
+    for (field_index, field_name, err) in [
+        (2, "field2", value.field2().unwrap_err()),
+        (3, "field3", value.field3().unwrap_err()),
+    ] {
+        assert_eq!(err.field_index(), field_index);
+        assert_eq!(err.field_name().as_str(), field_name);
+        assert_eq!(err.expected_layout().get_field_names().count(), 4);
+        assert_eq!(err.actual_layout().get_field_names().count(), 2);
+
+        let msg = err.to_string();
+        assert!(msg.contains(field_name), "{}", msg);
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////
 
 pub trait EnabledFields {
@@ -66,6 +66,30 @@ declare_int_repr! {
     check=( A=10,B=11,C=12, )
 }
 
+declare_int_repr! {
+    mod=u8_repr_sparse_a
+    repr=u8
+    discriminants(1,5,200)
+    discr_ty=u8,
+    check=( A=1,B=5,C=200, )
+}
+
+declare_int_repr! {
+    mod=u8_repr_sparse_b
+    repr=u8
+    discriminants(1,6,200)
+    discr_ty=u8,
+    check=( A=1,B=6,C=200, )
+}
+
+declare_int_repr! {
+    mod=u8_repr_sparse_c
+    repr=u8
+    discriminants(1,5,)
+    discr_ty=u8,
+    check=( A=1,B=5,C=6, )
+}
+
 declare_int_repr! {
     mod=u16_repr_a
     repr=u16
@@ -185,6 +209,36 @@ fn check_discriminant_repr_enums() {
     check_imcompatible_with_others(list, assert_discr_error)
 }
 
+/// Enums with the same variants and explicit non-contiguous discriminants,
+/// that only differ in the value of one discriminant.
+#[test]
+fn check_sparse_discriminants() {
+    let list = &[
+        <u8_repr_b::What as StableAbi>::LAYOUT,
+        <u8_repr_sparse_a::What as StableAbi>::LAYOUT,
+        <u8_repr_sparse_b::What as StableAbi>::LAYOUT,
+        <u8_repr_sparse_c::What as StableAbi>::LAYOUT,
+    ];
+
+    for (l_i, l_abi) in list.iter().enumerate() {
+        for (r_i, r_abi) in list.iter().enumerate() {
+            let res = check_layout_compatibility(l_abi, r_abi);
+
+            if l_i == r_i {
+                assert_eq!(res, Ok(()));
+            } else {
+                let errs = res.unwrap_err().flatten_errors();
+                assert!(
+                    errs.iter()
+                        .any(|err| matches!(err, AbiInstability::EnumDiscriminant { .. })),
+                    "\nerrors:{:#?}\n",
+                    errs,
+                );
+            }
+        }
+    }
+}
+
 #[cfg(miri)]
 #[test]
 fn check_discriminant_repr_enums() {
@@ -157,6 +157,46 @@ mod one_method_sync_send {
     }
 }
 
+mod one_method_const {
+    use super::*;
+    #[sabi_trait]
+    #[sabi(no_trait_impl)]
+    pub trait Trait {
+        const KIND: u32;
+        #[sabi(last_prefix_field)]
+        fn apply(&self, l: u32, r: u32) -> u32;
+        const OTHER: u64;
+    }
+}
+
+mod two_methods_const {
+    use super::*;
+    #[sabi_trait]
+    #[sabi(no_trait_impl)]
+    pub trait Trait {
+        const KIND: u32;
+        #[sabi(last_prefix_field)]
+        fn apply(&self, l: u32, r: u32) -> u32;
+        const OTHER: u64;
+        fn apply2(&self, l: u32, r: u32) -> u32;
+    }
+}
+
+mod three_methods_const {
+    use super::*;
+    #[sabi_trait]
+    #[sabi(no_trait_impl)]
+    pub trait Trait {
+        const KIND: u32;
+        #[sabi(last_prefix_field)]
+        fn apply(&self, l: u32, r: u32) -> u32;
+        const OTHER: u64;
+        fn apply2(&self, l: u32, r: u32) -> u32;
+        fn apply3(&self, l: u32, r: u32) -> u32;
+        const LAST: u8;
+    }
+}
+
 #[test]
 fn adding_methods_at_the_end() {
     let list = vec![
@@ -172,6 +212,21 @@ fn adding_methods_at_the_end() {
     });
 }
 
+#[test]
+fn adding_methods_after_assoc_consts() {
+    let list = [
+        <one_method_const::Trait_TO<'_, RBox<()>> as StableAbi>::LAYOUT,
+        <two_methods_const::Trait_TO<'_, RBox<()>> as StableAbi>::LAYOUT,
+        <three_methods_const::Trait_TO<'_, RBox<()>> as StableAbi>::LAYOUT,
+    ];
+
+    check_subsets(&list[..], |errs| {
+        assert!(errs
+            .iter()
+            .any(|err| matches!(err, AbiInstability::FieldCountMismatch { .. })));
+    });
+}
+
 #[test]
 fn adding_supertraits() {
     let list = vec![
@@ -59,6 +59,55 @@ pub struct UnsafeSabiOpaqueField0<T, U> {
     world: U,
 }
 
+/// Versions of a type whose private field changes its internal structure,
+/// which is fine as long as its size and alignment are the same.
+mod opaque_field_versions {
+    macro_rules! declare_outer {
+        ($inner:ty) => {
+            #[repr(C)]
+            #[derive(abi_stable::StableAbi)]
+            pub struct Outer {
+                pub public: u32,
+                #[sabi(unsafe_opaque_field)]
+                private: $inner,
+            }
+        };
+    }
+
+    pub mod v0 {
+        #[repr(C)]
+        pub struct Inner {
+            a: u32,
+            b: u32,
+        }
+        declare_outer! {Inner}
+    }
+
+    pub mod same_size {
+        #[repr(C)]
+        pub struct Inner {
+            a: u16,
+            b: [u8; 2],
+            c: f32,
+        }
+        declare_outer! {Inner}
+    }
+
+    pub mod larger {
+        #[repr(C)]
+        pub struct Inner {
+            a: u32,
+            b: u32,
+            c: u32,
+        }
+        declare_outer! {Inner}
+    }
+
+    pub mod less_aligned {
+        declare_outer! {[u16; 4]}
+    }
+}
+
 #[repr(C)]
 #[derive(abi_stable::StableAbi)]
 #[sabi(unsafe_allow_type_macros)]
@@ -166,3 +215,15 @@ fn different_opaque_fields() {
         }
     }
 }
+
+#[test]
+fn opaque_field_internal_change() {
+    use self::opaque_field_versions::{larger, less_aligned, same_size, v0};
+
+    let v0 = <v0::Outer as StableAbi>::LAYOUT;
+
+    check_layout_compatibility(v0, <same_size::Outer as StableAbi>::LAYOUT).unwrap();
+
+    check_layout_compatibility(v0, <larger::Outer as StableAbi>::LAYOUT).unwrap_err();
+    check_layout_compatibility(v0, <less_aligned::Outer as StableAbi>::LAYOUT).unwrap_err();
+}
@@ -248,6 +248,54 @@ fn unsafe_opaque_fields() {
     assert_eq!(field_0_ai.alignment(), mem::align_of::<Vec<u8>>());
 }
 
+/// A foreign type that doesn't implement `StableAbi`, wrapped in a `StableAbi` newtype.
+pub(super) mod foreign_16 {
+    pub struct ForeignId(pub [u8; 16]);
+
+    #[repr(transparent)]
+    #[derive(abi_stable::StableAbi)]
+    pub struct Wrapper {
+        #[sabi(unsafe_opaque_field)]
+        pub inner: ForeignId,
+    }
+}
+
+pub(super) mod foreign_8 {
+    pub struct ForeignId(pub [u8; 8]);
+
+    #[repr(transparent)]
+    #[derive(abi_stable::StableAbi)]
+    pub struct Wrapper {
+        #[sabi(unsafe_opaque_field)]
+        pub inner: ForeignId,
+    }
+}
+
+#[test]
+fn transparent_unsafe_opaque_foreign() {
+    let layout = foreign_16::Wrapper::LAYOUT;
+    assert_eq!(layout.size(), 16);
+    assert_eq!(layout.alignment(), 1);
+
+    let fields = match layout.data() {
+        TLData::Struct { fields } => fields.iter().collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+    assert_eq!(fields.len(), 1);
+
+    let field_layout = fields[0].layout();
+    assert_eq!(field_layout.data(), TLData::Opaque);
+    assert_eq!(field_layout.size(), mem::size_of::<foreign_16::ForeignId>());
+    assert_eq!(
+        field_layout.alignment(),
+        mem::align_of::<foreign_16::ForeignId>()
+    );
+
+    assert_sane_type_layout(foreign_16::Wrapper::LAYOUT);
+    assert_different_type_layout(foreign_16::Wrapper::LAYOUT, foreign_8::Wrapper::LAYOUT);
+    assert_different_type_layout(foreign_8::Wrapper::LAYOUT, foreign_16::Wrapper::LAYOUT);
+}
+
 #[cfg(not(miri))]
 #[test]
 fn same_different_abi_stability() {
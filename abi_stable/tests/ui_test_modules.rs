@@ -5,7 +5,12 @@ mod ui_tests {
     fn ui() {
         let t = trybuild::TestCases::new();
 
-        for dir in ["nonexhaustive_ui_tests", "sabi_trait_ui_tests"] {
+        for dir in [
+            "dyn_trait_ui_tests",
+            "nonexhaustive_ui_tests",
+            "sabi_trait_ui_tests",
+            "stable_abi_ui_tests",
+        ] {
             t.compile_fail(format!("tests/ui_tests/{}/*err.rs", dir));
             t.pass(format!("tests/ui_tests/{}/*ok.rs", dir));
         }
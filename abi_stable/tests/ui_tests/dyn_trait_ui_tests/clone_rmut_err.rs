@@ -0,0 +1,10 @@
+use abi_stable::{erased_types::interfaces::CloneInterface, DynTrait};
+
+fn assert_clone<T: Clone>(_: &T) {}
+
+fn main() {
+    let mut object = DynTrait::from_value(()).interface(CloneInterface);
+    let borrow = object.reborrow_mut();
+    let _ = borrow.clone();
+    assert_clone(&borrow);
+}
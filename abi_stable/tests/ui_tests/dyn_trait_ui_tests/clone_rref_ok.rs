@@ -0,0 +1,8 @@
+use abi_stable::{erased_types::interfaces::CloneInterface, DynTrait};
+
+fn main() {
+    let object = DynTrait::from_value(()).interface(CloneInterface);
+    let borrow = object.reborrow();
+    let _ = borrow.clone();
+    let _ = object.clone();
+}
@@ -0,0 +1,22 @@
+use abi_stable::{
+    sabi_trait::prelude::TD_Opaque,
+    std_types::{RStr, RString},
+};
+
+#[abi_stable::sabi_trait]
+pub trait Named {
+    fn name(&self) -> RStr<'_>;
+}
+
+impl Named for RString {
+    fn name(&self) -> RStr<'_> {
+        self.as_rstr()
+    }
+}
+
+fn main() {
+    let object = Named_TO::from_value(RString::from("hello"), TD_Opaque);
+    let name = object.name();
+    drop(object);
+    assert_eq!(name, "hello");
+}
@@ -0,0 +1,36 @@
+use abi_stable::sabi_trait::prelude::TD_Opaque;
+
+#[abi_stable::sabi_trait]
+pub trait Unsend {
+    fn get(&self) -> u32;
+}
+
+#[abi_stable::sabi_trait]
+pub trait SendOnly: Send {
+    fn get(&self) -> u32;
+}
+
+impl Unsend for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+}
+
+impl SendOnly for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+}
+
+fn assert_send<T: Send>(_: &T) {}
+fn assert_sync<T: Sync>(_: &T) {}
+
+fn main() {
+    let unsend = Unsend_TO::from_value(3u32, TD_Opaque);
+    assert_send(&unsend);
+    assert_sync(&unsend);
+
+    let send_only = SendOnly_TO::from_value(3u32, TD_Opaque);
+    assert_send(&send_only);
+    assert_sync(&send_only);
+}
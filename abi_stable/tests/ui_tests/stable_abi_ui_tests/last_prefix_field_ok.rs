@@ -0,0 +1,28 @@
+use abi_stable::{prefix_type::WithMetadata, StableAbi};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(kind(Prefix(prefix_ref = Module_Ref)))]
+pub struct Module {
+    pub first: u32,
+    #[sabi(last_prefix_field)]
+    pub second: u32,
+    pub third: u32,
+    pub fourth: u32,
+}
+
+const WITH_META: &WithMetadata<Module> = &WithMetadata::new(Module {
+    first: 3,
+    second: 5,
+    third: 8,
+    fourth: 13,
+});
+
+const MODULE: Module_Ref = Module_Ref(WITH_META.static_as_prefix());
+
+fn main() {
+    assert_eq!(MODULE.first(), 3);
+    assert_eq!(MODULE.second(), 5);
+    assert_eq!(MODULE.third(), Some(8));
+    assert_eq!(MODULE.fourth(), Some(13));
+}
@@ -0,0 +1,15 @@
+use abi_stable::StableAbi;
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(kind(Prefix(prefix_ref = Module_Ref)))]
+pub struct Module {
+    pub first: u32,
+    #[sabi(last_prefix_field)]
+    pub second: u32,
+    // A field added in a later version must not be the last prefix field.
+    #[sabi(last_prefix_field)]
+    pub third: u32,
+}
+
+fn main() {}
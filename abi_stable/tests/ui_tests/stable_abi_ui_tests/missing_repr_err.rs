@@ -0,0 +1,11 @@
+use abi_stable::StableAbi;
+
+#[derive(StableAbi)]
+pub struct Rectangle {
+    x: u32,
+    y: u32,
+    w: u16,
+    h: u32,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use abi_stable::StableAbi;
+
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct Rectangle {
+    x: u32,
+    y: u32,
+    w: u16,
+    h: u32,
+}
+
+#[repr(C, packed)]
+#[derive(StableAbi)]
+pub struct PackedRectangle {
+    x: u32,
+    y: u32,
+    w: u16,
+    h: u32,
+}
+
+fn main() {}
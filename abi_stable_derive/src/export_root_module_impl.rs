@@ -8,19 +8,53 @@ use syn::Ident;
 
 use proc_macro2::Span;
 
-use abi_stable_shared::mangled_root_module_loader_name;
+use abi_stable_shared::{
+    is_valid_root_module_name, mangled_named_root_module_loader_name,
+    mangled_root_module_loader_name,
+};
 
 #[doc(hidden)]
-pub fn export_root_module_attr(_attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
-    parse_or_compile_err(item, export_root_module_inner).into()
+pub fn export_root_module_attr(attr: TokenStream1, item: TokenStream1) -> TokenStream1 {
+    parse_or_compile_err(item, move |item| {
+        export_root_module_inner(attr.into(), item)
+    })
+    .into()
 }
 
 #[cfg(test)]
-fn export_root_module_str(item: &str) -> Result<TokenStream2, syn::Error> {
-    syn::parse_str(item).and_then(export_root_module_inner)
+fn export_root_module_str(attr: &str, item: &str) -> Result<TokenStream2, syn::Error> {
+    syn::parse_str(item).and_then(|item| {
+        let attr = syn::parse_str::<TokenStream2>(attr)?;
+        export_root_module_inner(attr, item)
+    })
 }
 
-fn export_root_module_inner(mut input: ItemFn) -> Result<TokenStream2, syn::Error> {
+/// Parses the `name = "..."` argument of the attribute, if there is one.
+fn parse_module_name(attr: TokenStream2) -> Result<Option<String>, syn::Error> {
+    if attr.is_empty() {
+        return Ok(None);
+    }
+
+    let syn::MetaNameValue { path, lit, .. } = syn::parse2::<syn::MetaNameValue>(attr)?;
+    if !path.is_ident("name") {
+        return_spanned_err!(path, "Unrecognized `#[export_root_module]` parameter");
+    }
+
+    match lit {
+        syn::Lit::Str(lit) if is_valid_root_module_name(&lit.value()) => Ok(Some(lit.value())),
+        lit => return_spanned_err!(
+            lit,
+            "Expected a string literal of ASCII alphanumeric characters and underscores",
+        ),
+    }
+}
+
+fn export_root_module_inner(
+    attr: TokenStream2,
+    mut input: ItemFn,
+) -> Result<TokenStream2, syn::Error> {
+    let module_name = parse_module_name(attr)?;
+
     let vis = &input.vis;
 
     let unsafe_no_layout_constant_path =
@@ -50,7 +84,11 @@ fn export_root_module_inner(mut input: ItemFn) -> Result<TokenStream2, syn::Erro
 
     let original_fn_ident = &input.sig.ident;
 
-    let export_name = Ident::new(&mangled_root_module_loader_name(), Span::call_site());
+    let export_name = match &module_name {
+        Some(name) => mangled_named_root_module_loader_name(name),
+        None => mangled_root_module_loader_name(),
+    };
+    let export_name = Ident::new(&export_name, Span::call_site());
 
     Ok(quote!(
         #input
@@ -121,7 +159,7 @@ mod tests {
         ];
 
         for (item, expected_const) in list {
-            let str_out = export_root_module_str(item)
+            let str_out = export_root_module_str("", item)
                 .unwrap()
                 .to_string()
                 .chars()
@@ -130,4 +168,28 @@ mod tests {
             assert!(str_out.contains(expected_const));
         }
     }
+
+    #[test]
+    fn named_root_module() {
+        let item = "pub fn hello()->RString{}";
+
+        let unnamed = export_root_module_str("", item).unwrap().to_string();
+        assert!(unnamed.contains(&mangled_root_module_loader_name()));
+
+        let named = export_root_module_str(r#"name = "foo_bar""#, item)
+            .unwrap()
+            .to_string();
+        assert!(named.contains(&mangled_named_root_module_loader_name("foo_bar")));
+
+        for attr in [
+            r#"name = """#,
+            r#"name = "foo.bar""#,
+            r#"name = "ñandú""#,
+            r#"name = 3"#,
+            r#"nam = "foo""#,
+            r#"foo"#,
+        ] {
+            assert!(export_root_module_str(attr, item).is_err(), "{}", attr);
+        }
+    }
 }
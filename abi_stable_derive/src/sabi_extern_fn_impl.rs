@@ -26,18 +26,24 @@ pub(crate) fn sabi_extern_fn_str(attr: &str, item: &str) -> Result<TokenStream2,
     })
 }
 
-/// Whether the function contains an early return or not.
+/// How the function generated by `#[sabi_extern_fn]` handles early returns and panics.
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub enum WithEarlyReturn {
-    No,
-    Yes,
+pub enum PanicHandling {
+    /// The function can return early, and aborts on panic.
+    Abort,
+    /// The function can't return early, and aborts on panic.
+    NoEarlyReturn,
+    /// The function can return early,
+    /// and passes the panic message to the registered panic logger before aborting.
+    LogPanic,
 }
 
 /// Converts a function into an `extern "C" fn` which aborts on panic.
-pub(crate) fn convert_to_sabi_extern_fn(with_early_return: WithEarlyReturn, item: &mut ItemFn) {
-    let no_early_return = match with_early_return {
-        WithEarlyReturn::No => Some(quote!( no_early_return; )),
-        WithEarlyReturn::Yes => None,
+pub(crate) fn convert_to_sabi_extern_fn(panic_handling: PanicHandling, item: &mut ItemFn) {
+    let macro_mode = match panic_handling {
+        PanicHandling::Abort => None,
+        PanicHandling::NoEarlyReturn => Some(quote!( no_early_return; )),
+        PanicHandling::LogPanic => Some(quote!( log_panic; )),
     };
 
     item.sig.abi = Some(syn::Abi {
@@ -49,7 +55,7 @@ pub(crate) fn convert_to_sabi_extern_fn(with_early_return: WithEarlyReturn, item
 
     let x = quote! {
         ::abi_stable::extern_fn_panic_handling!(
-            #no_early_return
+            #macro_mode
 
             #(#statements)*
         )
@@ -61,13 +67,16 @@ pub(crate) fn convert_to_sabi_extern_fn(with_early_return: WithEarlyReturn, item
 }
 
 fn sabi_extern_fn_inner(attr: TokenStream2, mut item: ItemFn) -> Result<TokenStream2, syn::Error> {
-    let with_early_return = match attr.into_iter().next() {
-        Some(TokenTree::Ident(ref ident)) if ident == "no_early_return" => WithEarlyReturn::No,
+    let panic_handling = match attr.into_iter().next() {
+        Some(TokenTree::Ident(ref ident)) if ident == "no_early_return" => {
+            PanicHandling::NoEarlyReturn
+        }
+        Some(TokenTree::Ident(ref ident)) if ident == "log_panic" => PanicHandling::LogPanic,
         Some(tt) => return_spanned_err!(tt, "Unrecognized `#[sabi_extern_fn]` parameter",),
-        None => WithEarlyReturn::Yes,
+        None => PanicHandling::Abort,
     };
 
-    convert_to_sabi_extern_fn(with_early_return, &mut item);
+    convert_to_sabi_extern_fn(panic_handling, &mut item);
 
     Ok(item.into_token_stream())
 }
@@ -120,6 +129,24 @@ mod tests {
                     }
                 ),
             ),
+            (
+                "log_panic",
+                r##"
+                    pub fn hello(x: u32) -> Option<u32> {
+                        let y = x.checked_mul(2)?;
+                        Some(y + 1)
+                    }
+                "##,
+                quote!(
+                    pub extern "C" fn hello(x: u32) -> Option<u32> {
+                        ::abi_stable::extern_fn_panic_handling!(
+                            log_panic;
+                            let y = x.checked_mul(2)?;
+                            Some(y + 1)
+                        )
+                    }
+                ),
+            ),
         ];
 
         for (attr, item, expected) in list {
@@ -129,4 +156,14 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn unrecognized_parameter() {
+        let err = sabi_extern_fn_str("log_panics", "fn hello() {}").unwrap_err();
+        assert!(
+            err.to_string().contains("Unrecognized"),
+            "{}",
+            err.to_string()
+        );
+    }
 }
@@ -37,7 +37,7 @@ use self::{
     lifetime_unelider::LifetimeUnelider,
     method_where_clause::MethodWhereClause,
     methods_tokenizer::MethodsTokenizer,
-    trait_definition::{TraitDefinition, TraitMethod},
+    trait_definition::{TraitAssocConst, TraitDefinition, TraitMethod},
 };
 
 /// Variables passed to all the `*_items` functions here.
@@ -441,6 +441,8 @@ fn constructor_items(params: TokenizerParams<'_>, mod_: &mut TokenStream2) {
     let assoc_tys_d = assoc_tys_a.clone();
     let assoc_tys_e = assoc_tys_a.clone();
     let assoc_tys_f = assoc_tys_a.clone();
+    let assoc_tys_g = assoc_tys_a.clone();
+    let assoc_tys_h = assoc_tys_a.clone();
 
     let mut make_vtable_args = totrait_def.generics_tokenizer(
         InWhat::ItemUse,
@@ -507,6 +509,25 @@ fn constructor_items(params: TokenizerParams<'_>, mod_: &mut TokenStream2) {
         WhichObject::RObject => quote!(),
     };
 
+    let extra_constraints_small_value = match totrait_def.which_object {
+        WhichObject::DynTrait => quote!(
+            #trait_interface<#trait_interface_use>:
+                ::abi_stable::erased_types::InterfaceType,
+            __sabi_re::DynTraitVTable_Ref<
+                #one_lt
+                __sabi_re::RSmallBox<(),_Inline>,
+                #trait_interface<#trait_interface_use>,
+            >:
+                __sabi_re::MakeDynTraitVTable<
+                    #one_lt
+                    _Self,
+                    __sabi_re::RSmallBox<_Self,_Inline>,
+                    Downcasting
+                >,
+        ),
+        WhichObject::RObject => quote!(),
+    };
+
     let extra_constraints_const = match totrait_def.which_object {
         WhichObject::DynTrait => quote!(
             #trait_interface<#trait_interface_use>:
@@ -550,6 +571,18 @@ fn constructor_items(params: TokenizerParams<'_>, mod_: &mut TokenStream2) {
         &lt_tokens.lt_rbox,
     );
 
+    let gen_params_header_rsmallbox = totrait_def.generics_tokenizer(
+        InWhat::ImplHeader,
+        WithAssocTys::Yes(WhichSelf::NoSelf),
+        &lt_tokens.lt_inline,
+    );
+
+    let gen_params_use_to_rsmallbox = totrait_def.generics_tokenizer(
+        InWhat::ItemUse,
+        WithAssocTys::Yes(WhichSelf::NoSelf),
+        &lt_tokens.lt_rsmallbox,
+    );
+
     let uto_params_use = totrait_def.generics_tokenizer(
         InWhat::ItemUse,
         WithAssocTys::Yes(WhichSelf::NoSelf),
@@ -577,6 +610,7 @@ fn constructor_items(params: TokenizerParams<'_>, mod_: &mut TokenStream2) {
     let mut shared_docs = String::new();
     let mut from_ptr_docs = String::new();
     let mut from_value_docs = String::new();
+    let mut from_small_value_docs = String::new();
     let mut from_const_docs = String::new();
 
     if doc_hidden_attr.is_none() {
@@ -608,6 +642,17 @@ fn constructor_items(params: TokenizerParams<'_>, mod_: &mut TokenStream2) {
             trait_ = trait_ident
         );
 
+        from_small_value_docs = format!(
+            "Constructs this trait from a type that implements `{trait_}`,\n\
+             storing it inline if it fits in `_Inline`.\n\
+             \n\
+             This method is automatically generated,\n\
+             for more documentation you can look at\n\
+             [`abi_stable::docs::sabi_trait_inherent#from_small_value-method`]\n\
+            ",
+            trait_ = trait_ident
+        );
+
         from_const_docs = format!(
             "Constructs this trait from a constant of a type that implements `{trait_}`.\n\
              \n\
@@ -719,6 +764,36 @@ fn constructor_items(params: TokenizerParams<'_>, mod_: &mut TokenStream2) {
             }
         }
 
+        impl<#gen_params_header_rsmallbox> #trait_to<#gen_params_use_to_rsmallbox>
+        where
+            _Inline: __sabi_re::InlineStorage,
+        {
+            #[doc=#from_small_value_docs]
+            #[doc=#shared_docs]
+            #submod_vis fn from_small_value<_Self,Downcasting>(
+                ptr:_Self,
+                can_it_downcast:Downcasting,
+            )->Self
+            where
+                _Self:
+                    #trait_ident<#trait_params #( #assoc_tys_g= #assoc_tys_h, )* >
+                    #plus_lt,
+                #trait_interface<#trait_interface_use>:
+                    __sabi_re::GetRObjectVTable<
+                        Downcasting,
+                        _Self,
+                        __sabi_re::RSmallBox<(),_Inline>,
+                        __sabi_re::RSmallBox<_Self,_Inline>
+                    >,
+                #extra_constraints_small_value
+            {
+                Self::from_ptr::<
+                    __sabi_re::RSmallBox<_Self,_Inline>,
+                    Downcasting
+                >(__sabi_re::RSmallBox::new(ptr),can_it_downcast)
+            }
+        }
+
         impl<#gen_params_header_rref> #trait_to<#gen_params_use_to_rref>{
             #[doc=#from_const_docs]
             #[doc=#shared_docs]
@@ -840,6 +915,8 @@ fn trait_and_impl(
 
     let assoc_tys_a = trait_def.assoc_tys.values().map(|x| &x.assoc_ty);
 
+    let assoc_consts = trait_def.assoc_consts.iter().map(|x| x.item);
+
     let unsafety = trait_def.item.unsafety;
 
     let erased_ptr_bounds = trait_def.erased_ptr_preds();
@@ -855,6 +932,8 @@ fn trait_and_impl(
         {
             #( #assoc_tys_a )*
 
+            #( #assoc_consts )*
+
             #methods_tokenizer_def
         }
     )
@@ -908,6 +987,7 @@ fn methods_impls(param: TokenizerParams, mod_: &mut TokenStream2) -> Result<(),
         trait_to,
         ctokens,
         lt_tokens,
+        submod_vis,
         ..
     } = param;
 
@@ -934,6 +1014,25 @@ fn methods_impls(param: TokenizerParams, mod_: &mut TokenStream2) -> Result<(),
 
     let methods_tokenizer_def = totrait_def.methods_tokenizer(WhichItem::TraitObjectImpl);
 
+    let const_getters = totrait_def.assoc_consts.iter().map(|assoc_const| {
+        let getter = &assoc_const.getter;
+        let ty = &assoc_const.item.ty;
+        let docs = format!(
+            "Gets the value of \
+             [the `{C}` associated constant](./trait.{TN}.html#associatedconstant.{C}) \
+             for the type that this trait object was constructed from.",
+            C = assoc_const.item.ident,
+            TN = totrait_def.name,
+        );
+        quote!(
+            #[doc = #docs]
+            #[inline]
+            #submod_vis fn #getter(&self) -> #ty {
+                (self.sabi_vtable().#getter())()
+            }
+        )
+    });
+
     quote!(
         #[allow(clippy::needless_lifetimes, clippy::new_ret_no_self)]
         impl<#gen_params_header> #trait_to<#gen_params_use_to>
@@ -952,6 +1051,8 @@ fn methods_impls(param: TokenizerParams, mod_: &mut TokenStream2) -> Result<(),
             }
 
             #methods_tokenizer_def
+
+            #( #const_getters )*
         }
     )
     .to_tokens(mod_);
@@ -1100,6 +1201,12 @@ fn vtable_impl(
     let method_names_a = vtable_trait_impl.methods.iter().map(|m| m.name);
     let method_names_b = method_names_a.clone();
 
+    let const_getters_a = vtable_trait_impl.assoc_consts.iter().map(|x| &x.getter);
+    let const_getters_b = const_getters_a.clone();
+    let const_getters_c = const_getters_a.clone();
+    let const_tys = vtable_trait_impl.assoc_consts.iter().map(|x| &x.item.ty);
+    let const_names = vtable_trait_impl.assoc_consts.iter().map(|x| &x.item.ident);
+
     let vtable_generics = vtable_trait_impl.generics_tokenizer(
         InWhat::ItemUse,
         WithAssocTys::Yes(WhichSelf::Underscore),
@@ -1154,6 +1261,9 @@ fn vtable_impl(
                         #(
                             #method_names_a:Self::#method_names_b,
                         )*
+                        #(
+                            #const_getters_a:Self::#const_getters_b,
+                        )*
                     }
                 )
             };
@@ -1164,6 +1274,13 @@ fn vtable_impl(
             };
 
             #methods_tokenizer
+
+            #(
+                #[doc(hidden)]
+                extern "C" fn #const_getters_c() -> #const_tys {
+                    <_Self as #trait_ident<#trait_generics>>::#const_names
+                }
+            )*
         }
     )
     .to_tokens(mod_);
@@ -205,6 +205,8 @@ declare_lifetime_tokens! {
         lt="",
         lt_erasedptr="_ErasedPtr,",
         lt_rbox="__sabi_re::RBox<()>,",
+        lt_inline="_Inline,",
+        lt_rsmallbox="__sabi_re::RSmallBox<(),_Inline>,",
         lt_rref="__sabi_re::RRef<'_sub,()>,",
         lt_rmut="__sabi_re::RMut<'_sub,()>,",
         lt_sub_lt="'_sub,",
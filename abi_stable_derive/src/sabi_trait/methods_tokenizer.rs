@@ -41,7 +41,16 @@ pub struct MethodTokenizer<'a> {
 
 impl<'a> ToTokens for MethodsTokenizer<'a> {
     fn to_tokens(&self, ts: &mut TokenStream2) {
-        for method in &self.trait_def.methods {
+        // The fields for associated constants are interleaved with the ones for methods,
+        // in declaration order,
+        // so that adding methods at the end doesn't change the offset of any field.
+        let mut assoc_consts = self.trait_def.assoc_consts.iter().peekable();
+        for (index, method) in self.trait_def.methods.iter().enumerate() {
+            if self.which_item == WhichItem::VtableDecl {
+                while let Some(assoc_const) = assoc_consts.next_if(|c| c.methods_before <= index) {
+                    self.const_getter_field(assoc_const, ts);
+                }
+            }
             MethodTokenizer {
                 trait_def: self.trait_def,
                 method,
@@ -49,6 +58,21 @@ impl<'a> ToTokens for MethodsTokenizer<'a> {
             }
             .to_tokens(ts);
         }
+        if self.which_item == WhichItem::VtableDecl {
+            for assoc_const in assoc_consts {
+                self.const_getter_field(assoc_const, ts);
+            }
+        }
+    }
+}
+
+impl<'a> MethodsTokenizer<'a> {
+    /// Outputs the vtable field for the getter of an associated constant.
+    fn const_getter_field(&self, assoc_const: &TraitAssocConst<'_>, ts: &mut TokenStream2) {
+        let vis = self.trait_def.submod_vis;
+        let getter = &assoc_const.getter;
+        let ty = &assoc_const.item.ty;
+        ts.append_all(quote!( #vis #getter: extern "C" fn() -> #ty, ));
     }
 }
 
@@ -144,6 +168,9 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
                 let lifetime = WriteLifetime(*lifetime);
                 quote_spanned!(method_span=> _self: __sabi_re::RMut<#lifetime, ()>)
             }
+            (false, SelfParam::ByVal) if method.returns_self => {
+                quote_spanned!(method_span=> _self: __sabi_re::RMut<'_, ()>)
+            }
             (false, SelfParam::ByVal) => {
                 quote_spanned!(method_span=> _self:*mut ())
             }
@@ -163,7 +190,16 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
         let param_names_c = param_names_a.clone();
         let param_names_d = param_names_a.clone();
         let param_names_e = method.params.iter().map(|x| x.pattern);
-        let return_ty = method.output.iter();
+        // By-value methods returning `Self` write the returned value
+        // back into the pointer in the vtable function.
+        let return_self =
+            Some(quote_spanned!(method_span=> Self)).filter(|_| is_method && method.returns_self);
+        let return_ty = method
+            .output
+            .as_ref()
+            .map(|ty| ty.to_token_stream())
+            .or(return_self);
+        let return_ty = return_ty.iter();
 
         let self_is_sized_bound = Some(&ctokens.self_sized)
             .filter(|_| is_method && method.self_param == SelfParam::ByVal);
@@ -283,6 +319,13 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
                             __method(self.obj.sabi_as_rmut(),#(#param_names_c,)*)
                         )
                     }
+                    SelfParam::ByVal if method.returns_self => {
+                        quote_spanned!(method_span=>
+                            let mut __this = self;
+                            __method(__this.obj.sabi_as_rmut(),#(#param_names_c,)*);
+                            __this
+                        )
+                    }
                     SelfParam::ByVal => {
                         quote_spanned!(method_span=>
                             self.obj.sabi_with_value(
@@ -373,6 +416,19 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
                     }
                 }));
             }
+            (WhichItem::VtableImpl, SelfParam::ByVal) if method.returns_self => {
+                // Panics abort inside `extern_fn_panic_handling`,
+                // so the moved-out value is never dropped twice.
+                ts.append_all(quote_spanned!(method_span=>{
+                    unsafe{
+                        let _self = _self.transmute_into_raw::<#self_ty>();
+                        let ret = ::abi_stable::extern_fn_panic_handling!{no_early_return;
+                            __Trait::#method_name(_self.read(),#(#param_names_c,)*)
+                        };
+                        _self.write(ret);
+                    }
+                }));
+            }
             (WhichItem::VtableImpl, SelfParam::ByVal) => {
                 ts.append_all(quote_spanned!(method_span=>{
                     ::abi_stable::extern_fn_panic_handling!{no_early_return; unsafe{
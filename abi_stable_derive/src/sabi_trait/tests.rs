@@ -20,17 +20,49 @@ fn must_not_pass() {
                 fn bar(&self) -> Self;
             }
         ",
-        // Can't return Self,at least for now.
         "
-            trait Baz {
-                fn bar(self) -> Self;
+            trait Bar {
+                const X: usize;
+            }
+        ",
+        "
+            #[sabi(no_trait_impl)]
+            trait Bar {
+                const X: Option<Self>;
+            }
+        ",
+        "
+            #[sabi(no_trait_impl)]
+            trait Bar {
+                const TYPE: usize;
+            }
+        ",
+        "
+            #[sabi(no_trait_impl)]
+            trait Bar {
+                const X: usize;
+                fn x(&self) -> usize;
             }
         ",
         "
+            #[sabi(no_trait_impl)]
             trait Bar {
+                fn x(&self) -> usize;
                 const X: usize;
             }
         ",
+        "
+            #[sabi(no_trait_impl)]
+            trait Bar {
+                const FROM_PTR: usize;
+            }
+        ",
+        "
+            #[sabi(no_trait_impl)]
+            trait Bar {
+                const SABI_REBORROW: usize;
+            }
+        ",
     ];
     for elem in list {
         if derive_sabi_trait(elem).is_ok() {
@@ -47,11 +79,27 @@ fn sabi_trait_test_cases() {
 #[test]
 fn must_pass() {
     let list = vec![
+        "
+            trait Baz {
+                fn bar(self) -> Self;
+                fn qux(self, x: u32) -> Self {
+                    self
+                }
+            }
+        ",
         "
             trait ConstBaz<const N:usize> {
                 fn baz(self);
             }
         ",
+        "
+            #[sabi(no_trait_impl)]
+            trait Bar<T> {
+                const X: usize;
+                const DEFAULTED: Option<T> = None;
+                fn bar(&self);
+            }
+        ",
     ];
 
     for elem in list {
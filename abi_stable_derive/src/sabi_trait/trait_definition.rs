@@ -39,6 +39,18 @@ pub struct AssocTyWithIndex {
     pub assoc_ty: syn::TraitItemType,
 }
 
+/// An associated constant of the trait,
+/// stored in the vtable as a function that returns its value.
+#[derive(Debug, Clone)]
+pub(crate) struct TraitAssocConst<'a> {
+    pub(crate) item: &'a syn::TraitItemConst,
+    /// The name of the vtable field and of the inherent getter method.
+    pub(crate) getter: Ident,
+    /// The amount of methods declared before this constant,
+    /// used to put the vtable field in declaration order.
+    pub(crate) methods_before: usize,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// Represents a trait for use in `#[sabi_trait]`.
@@ -84,6 +96,8 @@ pub(crate) struct TraitDefinition<'a> {
     pub(crate) submod_vis: RelativeVis<'a>,
     // The keys use the proginal identifier for the associated type.
     pub(crate) assoc_tys: HashMap<&'a Ident, AssocTyWithIndex>,
+    /// The associated constants,in declaration order.
+    pub(crate) assoc_consts: Vec<TraitAssocConst<'a>>,
     ///
     pub(crate) methods: Vec<TraitMethod<'a>>,
     /// Whether this has by mutable reference methods.
@@ -118,6 +132,7 @@ impl<'a> TraitDefinition<'a> {
         let vis = VisibilityKind::new(&trait_.vis);
         let submod_vis = vis.submodule_level(1);
         let mut assoc_tys = HashMap::default();
+        let mut assoc_consts = Vec::<TraitAssocConst<'a>>::new();
         let mut methods = Vec::<TraitMethod<'a>>::new();
 
         let mut errors = LinearResult::ok(());
@@ -190,9 +205,12 @@ impl<'a> TraitDefinition<'a> {
         /////////////////////////////////////////////////////
 
         let mut assoc_ty_index = 0;
+        let mut methods_before = 0;
         for item in &trait_.items {
             match item {
-                TraitItem::Method { .. } => {}
+                TraitItem::Method { .. } => {
+                    methods_before += 1;
+                }
                 TraitItem::Type(assoc_ty) => {
                     let with_index = AssocTyWithIndex {
                         index: assoc_ty_index,
@@ -202,6 +220,12 @@ impl<'a> TraitDefinition<'a> {
 
                     assoc_ty_index += 1;
                 }
+                TraitItem::Const(assoc_const) => {
+                    match TraitAssocConst::new(assoc_const, methods_before, &methods) {
+                        Ok(x) => assoc_consts.push(x),
+                        Err(e) => errors.push_err(e),
+                    }
+                }
                 item => errors.push_err(spanned_err!(
                     item,
                     "Associated item not compatible with #[sabi_trait]",
@@ -209,6 +233,16 @@ impl<'a> TraitDefinition<'a> {
             }
         }
 
+        if let (Some(first), false) = (assoc_consts.first(), disable_trait_impl) {
+            errors.push_err(spanned_err!(
+                first.item,
+                "Traits with associated constants require the \
+                 `#[sabi(no_trait_impl)]` attribute,\n\
+                 because the trait object can't implement the trait \
+                 with a constant that varies per object.",
+            ));
+        }
+
         let has_mut_methods = methods.iter().any(|m| {
             matches!(
                 &m.self_param,
@@ -253,6 +287,7 @@ impl<'a> TraitDefinition<'a> {
             vis,
             submod_vis,
             assoc_tys,
+            assoc_consts,
             methods,
             has_mut_methods,
             has_val_methods,
@@ -393,6 +428,90 @@ impl<'a> TraitDefinition<'a> {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+impl<'a> TraitAssocConst<'a> {
+    fn new(
+        item: &'a syn::TraitItemConst,
+        methods_before: usize,
+        methods: &[TraitMethod<'a>],
+    ) -> Result<Self, syn::Error> {
+        if mentions_self(item.ty.to_token_stream()) {
+            return_spanned_err!(
+                item.ty,
+                "The type of an associated constant can't mention `Self` in #[sabi_trait]",
+            );
+        }
+
+        let getter_name = item.ident.to_string().to_lowercase();
+        let getter = match syn::parse_str::<Ident>(&getter_name) {
+            Ok(x) => x,
+            Err(_) => return_spanned_err!(
+                item.ident,
+                "Cannot generate the `{}` getter method for this associated constant,\n\
+                 because it's not a valid identifier.",
+                getter_name,
+            ),
+        };
+
+        if methods.iter().any(|m| *m.name == getter) {
+            return_spanned_err!(
+                item.ident,
+                "The `{}` getter method for this associated constant \
+                 conflicts with the method of the same name.",
+                getter,
+            );
+        }
+
+        if GENERATED_INHERENT_METHODS.contains(&&*getter_name) {
+            return_spanned_err!(
+                item.ident,
+                "The `{}` getter method for this associated constant \
+                 conflicts with an inherent method of the generated trait object.",
+                getter,
+            );
+        }
+
+        Ok(Self {
+            item,
+            getter: Ident::new(&getter_name, item.ident.span()),
+            methods_before,
+        })
+    }
+}
+
+/// The inherent methods of the generated trait object,
+/// which the getters of associated constants can't be named as.
+const GENERATED_INHERENT_METHODS: &[&str] = &[
+    "from_ptr",
+    "from_sabi",
+    "from_value",
+    "from_small_value",
+    "from_const",
+    "sabi_vtable",
+    "sabi_reborrow",
+    "sabi_reborrow_mut",
+];
+
+/// Whether `ts` uses the `Self` identifier anywhere.
+fn mentions_self(ts: TokenStream2) -> bool {
+    ts.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(ident) => ident == "Self",
+        proc_macro2::TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
+
+/// Whether `ty` is the `Self` type.
+fn is_self_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path.qself.is_none() && path.path.is_ident("Self"),
+        syn::Type::Paren(paren) => is_self_type(&paren.elem),
+        syn::Type::Group(group) => is_self_type(&group.elem),
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// Represents a trait method for use in `#[sabi_trait]`.
 #[derive(Debug, Clone)]
 pub(crate) struct TraitMethod<'a> {
@@ -410,7 +529,12 @@ pub(crate) struct TraitMethod<'a> {
     pub(crate) lifetimes: Vec<&'a LifetimeDef>,
     pub(crate) params: Vec<MethodParam<'a>>,
     /// The return type of this method,if None this returns `()`.
+    ///
+    /// This is None when `returns_self` is true.
     pub(crate) output: Option<syn::Type>,
+    /// Whether this is a by-value method that returns `Self`,
+    /// which is implemented by replacing the value behind the pointer.
+    pub(crate) returns_self: bool,
 
     /// Whether the return type borrows from self
     pub(crate) return_borrow_kind: Option<BorrowKind>,
@@ -488,8 +612,14 @@ impl<'a> TraitMethod<'a> {
 
         let mut return_borrow_kind = None::<BorrowKind>;
 
+        let mut returns_self = false;
+
         let output = match &decl.output {
             syn::ReturnType::Default => None,
+            syn::ReturnType::Type(_, ty) if self_param == SelfParam::ByVal && is_self_type(ty) => {
+                returns_self = true;
+                None
+            }
             syn::ReturnType::Type(_, ty) => {
                 let mut ty: syn::Type = (**ty).clone();
                 if let SelfParam::ByRef { lifetime, .. } = &mut self_param {
@@ -559,6 +689,7 @@ impl<'a> TraitMethod<'a> {
             self_param,
             params,
             output,
+            returns_self,
             return_borrow_kind,
             where_clause,
             default,
@@ -405,10 +405,17 @@ pub(crate) fn derive(mut data: DeriveInput) -> Result<TokenStream2, syn::Error>
     let phantom_fields = config
         .phantom_fields
         .iter()
-        .map(|(name, ty)| {
+        .map(|&(name, ty)| (name, ty, None))
+        .chain(
+            config
+                .phantom_lifetimes
+                .iter()
+                .map(|&(name, ty, lt_index)| (name, ty, Some(lt_index))),
+        )
+        .map(|(name, ty, lt_index)| {
             CompTLField::from_expanded_std_field(
                 name,
-                std::iter::empty(),
+                lt_index,
                 shared_vars.push_type(LayoutConstructor::Regular, ty),
                 shared_vars,
             )
@@ -21,6 +21,7 @@ use crate::*;
 use crate::{
     attribute_parsing::contains_doc_hidden,
     impl_interfacetype::{parse_impl_interfacetype, ImplInterfaceType},
+    lifetimes::LifetimeIndex,
     parse_utils::{parse_str_as_ident, ParseBounds, ParsePunctuated},
     utils::{LinearResult, SynResultExt},
 };
@@ -78,6 +79,7 @@ mod kw {
     syn::custom_keyword! {PartialOrd}
     syn::custom_keyword! {phantom_const_param}
     syn::custom_keyword! {phantom_field}
+    syn::custom_keyword! {phantom_lifetime}
     syn::custom_keyword! {phantom_type_param}
     syn::custom_keyword! {prefix_bounds}
     syn::custom_keyword! {prefix_bound}
@@ -88,6 +90,8 @@ mod kw {
     syn::custom_keyword! {pub_getter}
     syn::custom_keyword! {refl}
     syn::custom_keyword! {rename}
+    syn::custom_keyword! {result}
+    syn::custom_keyword! {Rust}
     syn::custom_keyword! {size}
     syn::custom_keyword! {Send}
     syn::custom_keyword! {Serialize}
@@ -140,6 +144,8 @@ pub(crate) struct StableAbiOptions<'a> {
     pub(crate) phantom_fields: Vec<(&'a Ident, &'a Type)>,
     pub(crate) phantom_type_params: Vec<&'a Type>,
     pub(crate) phantom_const_params: Vec<&'a syn::Expr>,
+    /// The name, type, and lifetime of each `#[sabi(phantom_lifetime = 'a)]` virtual field.
+    pub(crate) phantom_lifetimes: Vec<(&'a Ident, &'a Type, LifetimeIndex)>,
 
     pub(crate) const_idents: ConstIdents,
 
@@ -234,7 +240,7 @@ impl<'a> StableAbiOptions<'a> {
     ) -> Result<Self, syn::Error> {
         let mut phantom_fields = Vec::<(&'a Ident, &'a Type)>::new();
 
-        let repr = ReprAttr::new(this.repr)?;
+        let repr = ReprAttr::new(this.repr, ds.name)?;
 
         let mut errors = LinearResult::ok(());
 
@@ -340,6 +346,28 @@ impl<'a> StableAbiOptions<'a> {
             },
         ));
 
+        let mut phantom_lifetimes = Vec::with_capacity(this.phantom_lifetimes.len());
+        for (i, lifetime) in this.phantom_lifetimes.iter().enumerate() {
+            let lt_index = if lifetime.ident == "static" {
+                LifetimeIndex::STATIC
+            } else {
+                match ds
+                    .generics
+                    .lifetimes()
+                    .position(|x| x.lifetime.ident == lifetime.ident)
+                {
+                    Some(index) => LifetimeIndex::Param(index as _),
+                    None => {
+                        errors.push_err(spanned_err!(lifetime, "unknown lifetime"));
+                        continue;
+                    }
+                }
+            };
+            let name = arenas.alloc(parse_str_as_ident(&format!("_phantom_lifetime_{}", i)));
+            let ty = arenas.alloc(syn::parse_quote!(&#lifetime ()));
+            phantom_lifetimes.push((name, ty, lt_index));
+        }
+
         let doc_hidden_attr = if this.is_hidden {
             Some(arenas.alloc(quote!(#[doc(hidden)])))
         } else {
@@ -368,6 +396,7 @@ impl<'a> StableAbiOptions<'a> {
             phantom_fields,
             phantom_type_params: this.phantom_type_params,
             phantom_const_params: this.phantom_const_params,
+            phantom_lifetimes,
             allow_type_macros: this.allow_type_macros,
             with_field_indices: this.with_field_indices,
             const_idents,
@@ -392,6 +421,7 @@ struct StableAbiAttrs<'a> {
     extra_checks: Option<syn::Expr>,
 
     first_suffix_field: FirstSuffixField,
+    last_prefix_field: Option<&'a Field<'a>>,
     default_on_missing_fields: Option<OnMissingField<'a>>,
     prefix_kind_fields: FieldMap<PrefixKindField<'a>>,
 
@@ -413,6 +443,7 @@ struct StableAbiAttrs<'a> {
     extra_phantom_fields: Vec<(&'a Ident, &'a Type)>,
     phantom_type_params: Vec<&'a Type>,
     phantom_const_params: Vec<&'a syn::Expr>,
+    phantom_lifetimes: Vec<&'a syn::Lifetime>,
 
     impl_interfacetype: Option<ImplInterfaceType>,
 
@@ -557,15 +588,23 @@ fn parse_inner<'a, I>(
                         this.repr
                             .set_repr_kind(UncheckedReprKind::Transparent, span)
                     } else if input.check_parse(kw::align)? {
-                        this.repr.set_aligned(parse_int_arg(input)?)
+                        this.repr.set_aligned(parse_int_arg(input)?, span)
                     } else if input.check_parse(kw::packed)? {
                         if input.peek(syn::token::Paren) {
-                            this.repr.set_packed(Some(parse_int_arg(input)?))
+                            this.repr.set_packed(Some(parse_int_arg(input)?), span)
                         } else {
-                            this.repr.set_packed(None)
+                            this.repr.set_packed(None, span)
                         }
                     } else if let Some(dr) = DiscriminantRepr::from_parser(input) {
                         this.repr.set_discriminant_repr(dr, span)
+                    } else if input.check_parse(kw::Rust)? {
+                        Err(syn_err!(
+                            span,
+                            "#[repr(Rust)] types have an unspecified layout, \
+                             which can't be relied on across dynamic libraries.\n\
+                             help: use `#[repr(C)]` instead.{}",
+                            REPR_ERROR_MSG
+                        ))
                     } else {
                         Err(syn_err!(
                             span,
@@ -639,6 +678,10 @@ fn parse_sabi_attr<'a>(
             input.parse::<Token!(=)>()?;
             let ty = arenas.alloc(input.parse_type()?);
             this.phantom_type_params.push(ty);
+        } else if input.check_parse(kw::phantom_lifetime)? {
+            input.parse::<Token!(=)>()?;
+            let lifetime = arenas.alloc(input.parse::<syn::Lifetime>()?);
+            this.phantom_lifetimes.push(lifetime);
         } else if input.check_parse(kw::phantom_const_param)? {
             input.parse::<Token!(=)>()?;
             let constant = arenas.alloc(input.parse_expr()?);
@@ -833,7 +876,18 @@ fn parse_sabi_attr<'a>(
             this.layout_ctor[field] = LayoutConstructor::Opaque;
         } else if input.check_parse(kw::unsafe_sabi_opaque_field)? {
             this.layout_ctor[field] = LayoutConstructor::SabiOpaque;
-        } else if input.check_parse(kw::last_prefix_field)? {
+        } else if let Some(attr_ident) = input.peek_parse(kw::last_prefix_field)? {
+            if let Some(prev_field) = this.last_prefix_field {
+                return_spanned_err!(
+                    attr_ident,
+                    "cannot use the `#[sabi(last_prefix_field)]` attribute on multiple fields, \
+                     it was already used on the `{}` field.\n\
+                     All fields after the last prefix field are conditionally accessible, \
+                     so it must only be used on the last field of the first version of the type.",
+                    prev_field.ident,
+                )
+            }
+            this.last_prefix_field = Some(field);
             let field_pos = field_index + 1;
             this.first_suffix_field = FirstSuffixField { field_pos };
         } else if input.check_parse(kw::rename)? {
@@ -925,6 +979,10 @@ Valid Attributes:
     This returns Some(field_value) if the field exists,None if the field doesn't exist.
     This is the default.
 
+    `#[sabi(missing_field(result))]`
+    This returns ROk(field_value) if the field exists,
+    RErr(MissingFieldError) if the field doesn't exist.
+
     `#[sabi(missing_field(with=\"somefunction\"))]`
     This returns `somefunction()` if the field doesn't exist.
     
@@ -939,6 +997,8 @@ Valid Attributes:
         Ok(OnMissingField::ReturnOption)
     } else if input.check_parse(kw::panic)? {
         Ok(OnMissingField::Panic)
+    } else if input.check_parse(kw::result)? {
+        Ok(OnMissingField::ReturnResult)
     } else if input.check_parse(kw::default)? {
         Ok(OnMissingField::Default_)
     } else if input.check_parse(kw::with)? {
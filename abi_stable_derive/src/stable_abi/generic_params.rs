@@ -31,7 +31,7 @@ impl<'a> GenericParams<'a> {
             let type_param_bounds = config
                 .type_param_bounds
                 .iter()
-                .filter(|(_, bounds)| **bounds == ASTypeParamBound::NoBound)
+                .filter(|(_, bounds)| **bounds != ASTypeParamBound::NoBound)
                 .map(|(type_param, &bounds)| {
                     let type_ = {
                         let x = type_from_ident(type_param.clone());
@@ -46,23 +46,27 @@ impl<'a> GenericParams<'a> {
                 });
 
             let mut iter = type_param_bounds.chain(phantom_type_params);
-            let first = iter.next().unwrap_or(0);
-            let mut last = first;
-            for elem in iter {
-                assert!(
-                    first <= elem,
-                    "BUG:\
-                    The type parameters must all be stored contiguously in the SharedVars.\n\
-                    last={} elem={}\
-                    ",
-                    last,
-                    elem,
-                );
-                last = elem;
-            }
-            StartLen {
-                start: first,
-                len: last - first,
+            match iter.next() {
+                Some(first) => {
+                    let mut last = first;
+                    for elem in iter {
+                        assert!(
+                            first <= elem,
+                            "BUG:\
+                            The type parameters must all be stored contiguously in the SharedVars.\n\
+                            last={} elem={}\
+                            ",
+                            last,
+                            elem,
+                        );
+                        last = elem;
+                    }
+                    StartLen {
+                        start: first,
+                        len: last - first + 1,
+                    }
+                }
+                None => StartLen::EMPTY,
             }
         };
 
@@ -137,6 +137,9 @@ pub(crate) struct FirstSuffixField {
 pub(crate) enum OnMissingField<'a> {
     /// Returns an `Option<FieldType>`,where it returns None if the field is absent.
     ReturnOption,
+    /// Returns an `RResult<FieldType, MissingFieldError>`,
+    /// where it returns `RErr` if the field is absent.
+    ReturnResult,
     /// Panics with a default message.
     Panic,
     /// Evaluates `function()`,and returns the return value of the function.
@@ -202,9 +205,11 @@ impl<'a> PrefixKind<'a> {
             AccessorOrMaybe::Accessor => FieldAccessor::Method { name: None },
             AccessorOrMaybe::Maybe(MaybeAccessor { on_missing, .. }) => match on_missing {
                 OMF::ReturnOption => FieldAccessor::MethodOption,
-                OMF::Panic { .. } | OMF::With { .. } | OMF::Value { .. } | OMF::Default_ => {
-                    FieldAccessor::Method { name: None }
-                }
+                OMF::ReturnResult
+                | OMF::Panic { .. }
+                | OMF::With { .. }
+                | OMF::Value { .. }
+                | OMF::Default_ => FieldAccessor::Method { name: None },
             },
         }
     }
@@ -453,6 +458,14 @@ accessible through [`{prefix_name}`](struct@{prefix_name}), with `.0.prefix()`.
                              `None` if it does not.\
                             ",
                     ),
+                    AOM::Maybe(MaybeAccessor {
+                        on_missing: OnMissingField::ReturnResult,
+                        ..
+                    }) => acc_doc_buffer.push_str(
+                        "Returns `ROk(field_value)` if the field exists,\
+                             `RErr(MissingFieldError)` if it does not.\
+                            ",
+                    ),
                     AOM::Maybe(MaybeAccessor {
                         on_missing: OnMissingField::Panic,
                         ..
@@ -538,18 +551,28 @@ accessible through [`{prefix_name}`](struct@{prefix_name}), with `.0.prefix()`.
                 AccessorOrMaybe::Maybe(maybe_accessor) => {
                     let field_offset = &offset_consts[field_i];
                     let on_missing_field = maybe_accessor.on_missing;
-                    let is_optional = on_missing_field == OnMissingField::ReturnOption;
-
-                    let return_ty = if is_optional {
-                        quote!( Option< #ty > )
-                    } else {
-                        quote!( #ty)
+                    let return_ty = match on_missing_field {
+                        OnMissingField::ReturnOption => quote!( Option< #ty > ),
+                        OnMissingField::ReturnResult => quote!(
+                            __sabi_re::RResult< #ty, __sabi_re::MissingFieldError >
+                        ),
+                        _ => quote!( #ty),
                     };
 
                     let else_ = match on_missing_field {
                         OnMissingField::ReturnOption => quote_spanned! {field_span=>
                             return None
                         },
+                        OnMissingField::ReturnResult => quote_spanned! {field_span=>
+                            return __sabi_re::RErr(
+                                __sabi_re::MissingFieldError::new::<
+                                    #deriving_name #ty_generics
+                                >(
+                                    #field_i,
+                                    self._prefix_type_layout(),
+                                )
+                            )
+                        },
                         OnMissingField::Panic => quote_spanned!(field_span=>
                             __sabi_re::panic_on_missing_field_ty::<
                                 #deriving_name #ty_generics
@@ -571,10 +594,14 @@ accessible through [`{prefix_name}`](struct@{prefix_name}), with `.0.prefix()`.
 
                     let val_var = syn::Ident::new("val", Span::mixed_site());
 
-                    let with_val = if is_optional {
-                        quote_spanned!(field_span=> Some(#val_var) )
-                    } else {
-                        val_var.to_token_stream()
+                    let with_val = match on_missing_field {
+                        OnMissingField::ReturnOption => {
+                            quote_spanned!(field_span=> Some(#val_var) )
+                        }
+                        OnMissingField::ReturnResult => {
+                            quote_spanned!(field_span=> __sabi_re::ROk(#val_var) )
+                        }
+                        _ => val_var.to_token_stream(),
                     };
 
                     conditional_accessors.push(quote_spanned! {field_span=>
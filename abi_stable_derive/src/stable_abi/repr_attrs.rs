@@ -4,9 +4,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::ParseBuffer;
 
-use as_derive_utils::{
-    parse_utils::ParseBufferExt, return_syn_err, syn_err, to_token_fn::ToTokenFnMut,
-};
+use as_derive_utils::{parse_utils::ParseBufferExt, return_syn_err, to_token_fn::ToTokenFnMut};
 
 use crate::{ignored_wrapper::Ignored, literals_constructors::rslice_tokenizer};
 
@@ -90,14 +88,23 @@ pub(crate) static REPR_ERROR_MSG: &str = "\n\
 ";
 
 impl UncheckedReprAttr {
-    pub fn set_aligned(&mut self, alignment: u32) -> Result<(), syn::Error> {
+    pub fn set_aligned(&mut self, alignment: u32, span: Span) -> Result<(), syn::Error> {
         self.is_aligned = Some(alignment);
+        self.set_modifier_span(span);
         Ok(())
     }
-    pub fn set_packed(&mut self, packing: Option<u32>) -> Result<(), syn::Error> {
+    pub fn set_packed(&mut self, packing: Option<u32>, span: Span) -> Result<(), syn::Error> {
         self.is_packed = packing.or(Some(1));
+        self.set_modifier_span(span);
         Ok(())
     }
+    /// Uses the span of a `packed`/`align` modifier for errors,
+    /// so long as no representation kind was specified.
+    fn set_modifier_span(&mut self, span: Span) {
+        if self.repr_kind.is_none() {
+            self.repr_span.value = span;
+        }
+    }
     pub fn set_repr_kind(
         &mut self,
         repr_kind: UncheckedReprKind,
@@ -178,13 +185,36 @@ impl DiscriminantRepr {
 }
 
 impl ReprAttr {
-    pub fn new(unchecked: UncheckedReprAttr) -> Result<Self, syn::Error> {
+    /// Checks the parsed representation attribute,
+    /// `type_name` is the name of the deriving type,
+    /// used as the span of the error when there is no representation attribute.
+    pub fn new(unchecked: UncheckedReprAttr, type_name: &syn::Ident) -> Result<Self, syn::Error> {
         let span = unchecked.repr_span;
         let is_aligned = unchecked.is_aligned;
         let is_packed = unchecked.is_packed;
-        let ura: UncheckedReprKind = unchecked
-            .repr_kind
-            .ok_or_else(|| syn_err!(*span, "{}", REPR_ERROR_MSG))?;
+        let ura: UncheckedReprKind = match unchecked.repr_kind {
+            Some(x) => x,
+            None if is_packed.is_some() || is_aligned.is_some() => return_syn_err!(
+                *span,
+                "#[repr(packed)] and #[repr(align(..))] must be combined with \
+                 another representation attribute.\n\
+                 help: use `#[repr(C, {})]` instead.{}",
+                if is_packed.is_some() {
+                    "packed"
+                } else {
+                    "align(..)"
+                },
+                REPR_ERROR_MSG,
+            ),
+            None => return_syn_err!(
+                type_name.span(),
+                "`{name}` must have a #[repr(..)] attribute to derive StableAbi,\n\
+                 since the default (Rust) representation has an unspecified layout.\n\
+                 help: add `#[repr(C)]` to `{name}`.{msg}",
+                name = type_name,
+                msg = REPR_ERROR_MSG,
+            ),
+        };
         let dr: Option<DiscriminantRepr> = unchecked.discriminant_repr;
         let variant = match (ura, dr) {
             (UncheckedReprKind::C, x) => Repr::C(x),
@@ -81,3 +81,30 @@ where
 pub fn mangled_root_module_loader_name() -> String {
     mangle_ident("lib_header", "root module loader")
 }
+
+/// Gets the name of the static that contains the LibHeader of a root module
+/// exported with `#[export_root_module(name = "...")]`.
+///
+/// This is the name returned by [`mangled_root_module_loader_name`]
+/// followed by the mangled `name`,
+/// and does not have a trailing `'\0'`.
+///
+/// # Panics
+///
+/// Panics if `name` is not a valid root module name,
+/// as determined by [`is_valid_root_module_name`].
+pub fn mangled_named_root_module_loader_name(name: &str) -> String {
+    assert!(
+        is_valid_root_module_name(name),
+        "invalid root module name: {:?}\n\
+         expected a non-empty string of ASCII alphanumeric characters and underscores.",
+        name,
+    );
+    mangle_ident("lib_header", format_args!("root module loader.{}", name))
+}
+
+/// Whether `name` can be used as the name of a root module,
+/// it must be non-empty and only contain ASCII alphanumeric characters and underscores.
+pub fn is_valid_root_module_name(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'_')
+}
@@ -1,6 +1,11 @@
 use std::{
     any::Any,
+    future::Future,
     panic::{catch_unwind, AssertUnwindSafe, Location},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
 };
 
 pub type ThreadError = Box<dyn Any + Send + 'static>;
@@ -23,6 +28,31 @@ where
     }
 }
 
+/// Runs `future` to completion on the current thread,
+/// parking the thread while the future is pending.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match Pin::as_mut(&mut future).poll(&mut cx) {
+            Poll::Ready(x) => return x,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
 #[test]
 fn test_must_panic() {
     assert!(must_panic(|| panic!()).is_ok());
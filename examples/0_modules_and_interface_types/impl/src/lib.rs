@@ -181,9 +181,7 @@ pub fn deserialize_command_borrowing(s: RStr<'_>) -> RResult<TOCommandBox<'_>, R
 /// Defines how a TOReturnValueArc is deserialized from json.
 #[sabi_extern_fn]
 pub fn deserialize_return_value(s: RStr<'_>) -> RResult<TOReturnValueArc, RBoxError> {
-    deserialize_json::<ReturnValue>(s)
-        .map(RArc::new)
-        .map(DynTrait::from_ptr)
+    DynTrait::deserialize_shared_from_str::<ReturnValue>(s)
 }
 
 //////////////////////////////////////////////////////////////////////////////////////
@@ -1,6 +1,6 @@
 use super::*;
 
-use abi_stable::library::RootModule;
+use abi_stable::{library::RootModule, rvec, StableAbi};
 
 use serde_json::value::RawValue;
 
@@ -58,6 +58,27 @@ fn deserializing() {
     assert_eq!(value0, value1);
 }
 
+#[test]
+fn deserializing_return_value() {
+    setup();
+
+    let json = r#"{"Batch":[{"ReverseLines":"foo"},{"GetProcessedBytes":10}]}"#;
+
+    let value0 = deserialize_return_value(json.into()).unwrap();
+    let value1 = serde_json::from_str::<TOReturnValueArc>(json).unwrap();
+
+    assert_eq!(value0, value1);
+    assert_eq!(
+        value0.downcast_as::<ReturnValue>().unwrap(),
+        &ReturnValue::Batch(rvec![
+            ReturnValue::ReverseLines("foo".into()),
+            ReturnValue::GetProcessedBytes(10),
+        ]),
+    );
+
+    assert!(deserialize_return_value(r#"{"Batch":100}"#.into()).is_err());
+}
+
 #[test]
 fn serializing() {
     setup();
@@ -80,3 +101,16 @@ fn serializing() {
 
     assert_eq!(serde_json::to_string(&this).unwrap(), expected_0,);
 }
+
+#[test]
+fn render_remove_words_definition() {
+    let rendered = <RemoveWords<'_, '_> as StableAbi>::LAYOUT.render_definition();
+
+    assert!(
+        rendered.starts_with("#[repr(C)]\nstruct RemoveWords<'a, 'b> {\n"),
+        "{}",
+        rendered
+    );
+    assert!(rendered.contains("    string: RStr<'a>,\n"), "{}", rendered);
+    assert!(rendered.contains("    words: DynTrait<"), "{}", rendered);
+}
@@ -155,7 +155,7 @@ fn main() -> io::Result<()> {
             })?;
         }
         Command::RunTests => {
-            tests::run_dynamic_library_tests(mods);
+            tests::run_dynamic_library_tests(mods, &library_path);
         }
         Command::Json { file } => {
             fn run_command(mods: TextOpsMod_Ref, state: &mut TOStateBox, s: &str) -> RString {
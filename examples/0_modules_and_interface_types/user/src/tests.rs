@@ -1,4 +1,12 @@
-use abi_stable::{std_types::RCow, DynTrait};
+use std::path::Path;
+
+use abi_stable::{
+    library::{lib_header_from_path, RootModule},
+    std_types::RCow,
+    DynTrait,
+};
+
+use abi_stable_shared::test_utils::block_on;
 
 use example_0_interface::CowStrIter;
 
@@ -13,7 +21,9 @@ use super::*;
 ///
 /// There is no way that I am aware to check at compile-time what allocator
 /// the type is using,so this is the best I can do while staying safe.
-pub fn run_dynamic_library_tests(mods: TextOpsMod_Ref) {
+pub fn run_dynamic_library_tests(mods: TextOpsMod_Ref, library_dir: &Path) {
+    test_describe(library_dir);
+    test_load_async(mods, library_dir);
     test_reverse_lines(mods);
     test_remove_words(mods);
 
@@ -23,6 +33,39 @@ pub fn run_dynamic_library_tests(mods: TextOpsMod_Ref) {
     println!("'-------------------------'");
 }
 
+fn test_describe(library_dir: &Path) {
+    let library_path = TextOpsMod_Ref::get_library_path(library_dir);
+    let manifest = lib_header_from_path(&library_path)
+        .unwrap_or_else(|e| panic!("{}", e))
+        .describe();
+
+    assert_eq!(manifest.base_name, TextOpsMod_Ref::BASE_NAME);
+    assert_eq!(manifest.name, TextOpsMod_Ref::NAME);
+    assert_eq!(manifest.version_strings, TextOpsMod_Ref::VERSION_STRINGS);
+    assert_eq!(
+        manifest.field_names.as_deref(),
+        Some(
+            &[
+                "new",
+                "deserializers",
+                "reverse_lines",
+                "remove_words",
+                "get_processed_bytes",
+                "run_command",
+                "set_initial_processed_bytes",
+            ][..]
+        )
+    );
+}
+
+fn test_load_async(mods: TextOpsMod_Ref, library_dir: &Path) {
+    let future = TextOpsMod_Ref::load_from_directory_async(library_dir);
+    let loaded = block_on(future).unwrap_or_else(|e| panic!("{}", e));
+
+    // The module was already loaded, so the same module is returned.
+    assert_eq!(loaded.0.to_raw_ptr(), mods.0.to_raw_ptr());
+}
+
 fn test_reverse_lines(mods: TextOpsMod_Ref) {
     let text_ops = mods;
 
@@ -163,6 +163,30 @@ fn examples_of_constructing_a_command() {
     });
 }
 
+#[test]
+#[cfg(feature = "v1_1")]
+fn examples_of_mapping_a_command() {
+    use abi_stable::nonexhaustive_enum::NonExhaustive;
+
+    let id = ItemId { id: 0 };
+
+    // Converting a known variant of Command into the command that undoes it,
+    // unknown variants (from newer versions of the library) would be returned unchanged.
+    let undo = |cmd| match cmd {
+        Command::AddItem { id, count } => Command::RemoveItem { id, count },
+        Command::RemoveItem { id, count } => Command::AddItem { id, count },
+        cmd => cmd,
+    };
+
+    let add: Command_NE = NonExhaustive::new(Command::AddItem { id, count: 3 });
+    let remove: Command_NE = add.map_known(undo).unwrap();
+    assert_eq!(remove, Command::RemoveItem { id, count: 3 });
+
+    let delete: Command_NE = NonExhaustive::new(Command::DeleteItem { id });
+    let delete: Command_NE = delete.map_known(undo).unwrap();
+    assert_eq!(delete, Command::DeleteItem { id });
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 /// The return value of `Shop::run_command`.
@@ -0,0 +1,45 @@
+//! This is an `implementation crate`,
+//! it exports two root modules, each one under its own name.
+
+use example_3_interface::{MathMod, MathMod_Ref, TextMod, TextMod_Ref};
+
+use abi_stable::{
+    export_root_module,
+    prefix_type::PrefixTypeTrait,
+    sabi_extern_fn,
+    std_types::{RStr, RString},
+};
+
+/// Exports the `MathMod` root module,
+/// the name passed to the attribute must be the same as `example_3_interface::MATH_MODULE_NAME`.
+#[export_root_module(name = "math")]
+pub fn get_math_module() -> MathMod_Ref {
+    MathMod { add, multiply }.leak_into_prefix()
+}
+
+/// Exports the `TextMod` root module,
+/// the name passed to the attribute must be the same as `example_3_interface::TEXT_MODULE_NAME`.
+#[export_root_module(name = "text")]
+pub fn get_text_module() -> TextMod_Ref {
+    TextMod { reverse, uppercase }.leak_into_prefix()
+}
+
+#[sabi_extern_fn]
+fn add(l: u64, r: u64) -> u64 {
+    l.wrapping_add(r)
+}
+
+#[sabi_extern_fn]
+fn multiply(l: u64, r: u64) -> u64 {
+    l.wrapping_mul(r)
+}
+
+#[sabi_extern_fn]
+fn reverse(text: RStr<'_>) -> RString {
+    text.chars().rev().collect()
+}
+
+#[sabi_extern_fn]
+fn uppercase(text: RStr<'_>) -> RString {
+    text.to_uppercase()
+}
@@ -0,0 +1,74 @@
+//! This is an example `interface crate`,
+//! declaring two independent root modules that are exported by the same dynamic library.
+//!
+//! Each root module is exported with `#[export_root_module(name = "...")]`,
+//! and loaded with `RootModule::load_named_from_directory`.
+
+use std::path::Path;
+
+use abi_stable::{
+    library::{LibraryError, RootModule},
+    package_version_strings,
+    sabi_types::VersionStrings,
+    std_types::{RStr, RString},
+    StableAbi,
+};
+
+/// The name of the dynamic library that exports both root modules.
+pub const LIBRARY_BASE_NAME: &str = "named_root_modules";
+
+/// The name that the `MathMod` root module is exported with.
+pub const MATH_MODULE_NAME: &str = "math";
+
+/// The name that the `TextMod` root module is exported with.
+pub const TEXT_MODULE_NAME: &str = "text";
+
+/// A root module of arithmetic functions.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(kind(Prefix(prefix_ref = MathMod_Ref)))]
+#[sabi(missing_field(panic))]
+pub struct MathMod {
+    pub add: extern "C" fn(u64, u64) -> u64,
+
+    #[sabi(last_prefix_field)]
+    pub multiply: extern "C" fn(u64, u64) -> u64,
+}
+
+impl RootModule for MathMod_Ref {
+    abi_stable::declare_root_module_statics! {MathMod_Ref}
+
+    const BASE_NAME: &'static str = LIBRARY_BASE_NAME;
+    const NAME: &'static str = "math module";
+    const VERSION_STRINGS: VersionStrings = package_version_strings!();
+}
+
+/// A root module of text manipulation functions.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(kind(Prefix(prefix_ref = TextMod_Ref)))]
+#[sabi(missing_field(panic))]
+pub struct TextMod {
+    pub reverse: extern "C" fn(RStr<'_>) -> RString,
+
+    #[sabi(last_prefix_field)]
+    pub uppercase: extern "C" fn(RStr<'_>) -> RString,
+}
+
+impl RootModule for TextMod_Ref {
+    abi_stable::declare_root_module_statics! {TextMod_Ref}
+
+    const BASE_NAME: &'static str = LIBRARY_BASE_NAME;
+    const NAME: &'static str = "text module";
+    const VERSION_STRINGS: VersionStrings = package_version_strings!();
+}
+
+/// Loads the `MathMod` root module from the library in the `directory` folder.
+pub fn load_math_module(directory: &Path) -> Result<MathMod_Ref, LibraryError> {
+    MathMod_Ref::load_named_from_directory(directory, MATH_MODULE_NAME)
+}
+
+/// Loads the `TextMod` root module from the library in the `directory` folder.
+pub fn load_text_module(directory: &Path) -> Result<TextMod_Ref, LibraryError> {
+    TextMod_Ref::load_named_from_directory(directory, TEXT_MODULE_NAME)
+}
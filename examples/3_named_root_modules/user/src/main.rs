@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use abi_stable::library::{InvalidRootModuleName, LibraryError, RootModule};
+
+use example_3_interface::{load_math_module, load_text_module, MathMod_Ref, TextMod_Ref};
+
+fn main() {
+    let directory = Path::new("../../../target/debug");
+
+    // Invalid names are reported as errors, without preventing a later load.
+    match MathMod_Ref::load_named_from_directory(directory, "ñandú") {
+        Err(LibraryError::RootModule { err, .. }) => {
+            let invalid = err
+                .error()
+                .and_then(|e| e.downcast_ref::<InvalidRootModuleName>())
+                .unwrap_or_else(|| panic!("{}", err));
+            assert_eq!(invalid.name, "ñandú");
+        }
+        Err(e) => panic!("{}", e),
+        Ok(_) => panic!("loaded a root module with an invalid name"),
+    }
+
+    // Both root modules are loaded from the same dynamic library,
+    // each one by the name it was exported with.
+    let math: MathMod_Ref = load_math_module(directory).unwrap_or_else(|e| panic!("{}", e));
+    let text: TextMod_Ref = load_text_module(directory).unwrap_or_else(|e| panic!("{}", e));
+
+    assert_eq!(math.add()(3, 5), 8);
+    assert_eq!(math.multiply()(3, 5), 15);
+
+    assert_eq!(text.reverse()("hello".into()), "olleh");
+    assert_eq!(text.uppercase()("hello".into()), "HELLO");
+
+    println!("success");
+}
@@ -2,7 +2,11 @@
 
 use testing_interface_1::{get_env_vars, ReturnWhat, TestingMod, TestingMod_Ref};
 
-use abi_stable::{export_root_module, prefix_type::PrefixTypeTrait, std_types::RBoxError};
+use abi_stable::{
+    export_root_module,
+    prefix_type::PrefixTypeTrait,
+    std_types::{RBoxError, RStr},
+};
 
 ///////////////////////////////////////////////////////////////////////////////////
 
@@ -26,3 +30,13 @@ pub fn get_library() -> Result<TestingMod_Ref, RBoxError> {
         }
     }
 }
+
+/// A function exported besides the root module,
+/// which is loaded with `RawLibrary::get`.
+#[no_mangle]
+pub extern "C" fn testing_1_version_banner() -> RStr<'static> {
+    RStr::from_str(concat!(
+        "testing_1_loading_errors ",
+        env!("CARGO_PKG_VERSION")
+    ))
+}
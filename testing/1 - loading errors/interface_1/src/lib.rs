@@ -3,16 +3,30 @@
 //!
 //! These crate test a few of the errors that are returned when loading dynamic libraries
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use abi_stable::{
-    library::RootModule, package_version_strings, sabi_types::VersionStrings, StableAbi,
+    library::{LibraryError, RootModule},
+    package_version_strings,
+    sabi_types::VersionStrings,
+    std_types::RStr,
+    StableAbi,
 };
 
+/// How many times `RootModule::initialization` was called for `TestingMod_Ref`.
+pub static TESTING_MOD_INITIALIZATIONS: AtomicUsize = AtomicUsize::new(0);
+
 impl RootModule for TestingMod_Ref {
     abi_stable::declare_root_module_statics! {TestingMod_Ref}
 
     const BASE_NAME: &'static str = "testing_1_loading_errors";
     const NAME: &'static str = "testing_1_loading_errors";
     const VERSION_STRINGS: VersionStrings = package_version_strings!();
+
+    fn initialization(self) -> Result<Self, LibraryError> {
+        TESTING_MOD_INITIALIZATIONS.fetch_add(1, Ordering::SeqCst);
+        Ok(self)
+    }
 }
 
 #[repr(C)]
@@ -70,6 +84,16 @@ impl RootModule for NonAbiStableLib_Ref {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The name of a function exported by the library besides the root module,
+/// which has the `VersionBannerFn` signature.
+pub const VERSION_BANNER_SYMBOL: &[u8] = b"testing_1_version_banner\0";
+
+/// The type of the function exported by the library under the
+/// `VERSION_BANNER_SYMBOL` name.
+pub type VersionBannerFn = extern "C" fn() -> RStr<'static>;
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// Parameters for the program passed through environment variables.
 ///
 /// The reason that env vars are used instead of command line arguments is because
@@ -1,14 +1,16 @@
 #![allow(clippy::print_literal)]
 
 use abi_stable::library::{
-    development_utils::compute_library_path, LibraryError, RootModule, RootModuleError,
+    development_utils::compute_library_path, LibraryError, RootModule, RootModuleCache,
+    RootModuleError, ROOT_MODULE_LOADER_NAME, ROOT_MODULE_LOADER_NAME_WITH_NUL,
 };
 
 use testing_interface_1::{
-    get_env_vars, NonAbiStableLib_Ref, ReturnWhat, TestingMod_Ref, WithIncompatibleLayout_Ref,
+    get_env_vars, NonAbiStableLib_Ref, ReturnWhat, TestingMod_Ref, VersionBannerFn,
+    WithIncompatibleLayout_Ref, TESTING_MOD_INITIALIZATIONS, VERSION_BANNER_SYMBOL,
 };
 
-use std::fmt;
+use std::{fmt, sync::atomic::Ordering};
 
 fn main() {
     let target: &std::path::Path = "../../../target/".as_ref();
@@ -29,11 +31,14 @@ fn main() {
         let err = NonAbiStableLib_Ref::load_from_directory(&library_path)
             .err()
             .unwrap();
-        assert!(
-            matches!(err, LibraryError::GetSymbolError { .. }),
-            "{:?}",
-            err,
-        );
+        match &err {
+            LibraryError::GetSymbolError { symbol, .. } => {
+                assert_eq!(symbol, ROOT_MODULE_LOADER_NAME_WITH_NUL.as_bytes());
+            }
+            _ => panic!("{:?}", err),
+        }
+        let formatted = err.to_string();
+        assert!(formatted.contains(ROOT_MODULE_LOADER_NAME), "{}", formatted);
     }
 
     {
@@ -53,6 +58,36 @@ fn main() {
         );
     }
 
+    {
+        // The library is only in the second directory
+        let library_path = compute_library_path::<TestingMod_Ref>(target).unwrap();
+        let dirs: &[&std::path::Path] = &["foo/bar/bar".as_ref(), &library_path];
+        let res = TestingMod_Ref::load_from_directories(dirs);
+
+        match envars.return_what {
+            ReturnWhat::Ok => {
+                let module = res.unwrap_or_else(|e| panic!("{}", e));
+                assert_eq!(module.a(), 5);
+            }
+            ReturnWhat::Error | ReturnWhat::Panic => match res.err().unwrap() {
+                LibraryError::Many(errors) => {
+                    assert_eq!(errors.len(), 2, "{:?}", errors);
+                    assert!(
+                        matches!(errors[0], LibraryError::OpenError { .. }),
+                        "{:?}",
+                        errors[0],
+                    );
+                    assert!(
+                        matches!(errors[1], LibraryError::RootModule { .. }),
+                        "{:?}",
+                        errors[1],
+                    );
+                }
+                err => panic!("Expected a LibraryError::Many, found:\n{:#?}", err),
+            },
+        }
+    }
+
     {
         let library_path = compute_library_path::<TestingMod_Ref>(target).unwrap();
         let res = TestingMod_Ref::load_from_directory(&library_path);
@@ -63,6 +98,53 @@ fn main() {
                 assert_eq!(module.a(), 5);
                 assert_eq!(module.b(), 8);
                 assert_eq!(module.c(), 13);
+                assert_eq!(TESTING_MOD_INITIALIZATIONS.load(Ordering::SeqCst), 1);
+
+                // Loading the same library through different spellings of its path
+                let cache = RootModuleCache::<TestingMod_Ref>::new();
+                let file_path = TestingMod_Ref::get_library_path(&library_path);
+                let file_name = file_path.file_name().unwrap();
+                let dir_name = library_path.canonicalize().unwrap();
+                let dir_name = dir_name.file_name().unwrap();
+                let other_spelling = library_path
+                    .join("..")
+                    .join(dir_name)
+                    .join(".")
+                    .join(file_name);
+                assert_ne!(file_path, other_spelling);
+
+                let cached = cache.load_from_file(&file_path).unwrap();
+                let cached_again = cache.load_from_file(&other_spelling).unwrap();
+                let from_dir = cache.load_from_directory(&library_path).unwrap();
+                assert!(std::ptr::eq(
+                    cached.0.to_raw_ptr(),
+                    cached_again.0.to_raw_ptr()
+                ));
+                assert!(std::ptr::eq(cached.0.to_raw_ptr(), from_dir.0.to_raw_ptr()));
+                assert!(std::ptr::eq(cached.0.to_raw_ptr(), module.0.to_raw_ptr()));
+                assert_eq!(cache.paths().len(), 1);
+                assert!(cache.get(&other_spelling).is_some());
+
+                // The cache reuses the root module loaded with `RootModule::load_from*`
+                assert_eq!(TESTING_MOD_INITIALIZATIONS.load(Ordering::SeqCst), 1);
+
+                let raw_library = TestingMod_Ref::get_raw_library().unwrap();
+
+                let banner = unsafe {
+                    raw_library
+                        .get::<VersionBannerFn>(VERSION_BANNER_SYMBOL)
+                        .unwrap_or_else(|e| panic!("{}", e))
+                };
+                assert_eq!(banner(), "testing_1_loading_errors 0.1.0");
+
+                let err = unsafe { raw_library.get::<VersionBannerFn>(b"not_a_symbol\0") }
+                    .err()
+                    .unwrap();
+                assert!(
+                    matches!(err, LibraryError::GetSymbolError { .. }),
+                    "{:?}",
+                    err,
+                );
             }
             ReturnWhat::Error | ReturnWhat::Panic => {
                 let err = res.err().expect("Expected the library to return an error");
@@ -1,9 +1,15 @@
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use abi_stable::{
-    library::lib_header_from_path,
     //abi_stability::check_layout_compatibility,
+    abi_stability::{compare_layout_json, LayoutJson},
+    library::lib_header_from_path,
     reflection::export_module::MRItem,
+    std_types::RErr,
+    type_layout::TypeLayout,
 };
 
 use core_extensions::SelfOps;
@@ -36,6 +42,43 @@ enum Command {
         #[structopt(long = "--compact")]
         compact_json: bool,
     },
+
+    /// Extracts the layout of the root module of an abi_stable library,
+    /// and of all the types it references.
+    #[structopt(name = "layout")]
+    #[structopt(author = "_")]
+    Layout {
+        /// The path to the library.
+        library_path: PathBuf,
+
+        /// Which file to output the layout to.
+        #[structopt(short = "o", long = "out-file")]
+        #[structopt(parse(from_os_str))]
+        output_file: Option<PathBuf>,
+
+        /// Whether to output the layout to stdout.
+        #[structopt(short = "s")]
+        output_stdout: bool,
+
+        /// Whether to outputed json is compact
+        #[structopt(long = "--compact")]
+        compact_json: bool,
+    },
+
+    /// Compares the layouts of two versions of an abi_stable library,
+    /// as outputted by the `layout` subcommand,
+    /// exiting with an error if the new version is incompatible with the old one.
+    #[structopt(name = "compare")]
+    #[structopt(author = "_")]
+    Compare {
+        /// The json file with the layout of the old version of the library.
+        #[structopt(parse(from_os_str))]
+        old_file: PathBuf,
+
+        /// The json file with the layout of the new version of the library.
+        #[structopt(parse(from_os_str))]
+        new_file: PathBuf,
+    },
 }
 
 fn main() {
@@ -76,36 +119,72 @@ version of abi_stable to be loaded successfully.
             output_stdout,
             compact_json,
         } => {
-            let lib_header = lib_header_from_path(library_path.as_ref()).unwrap();
-
-            let layout = lib_header.layout().unwrap_or_else(|| {
-                println!(
-                    "The dynamic library does not support reflection:\n    {}",
-                    library_path.display(),
-                );
-                std::process::exit(1);
-            });
-
+            let layout = library_layout(&library_path);
             let root_mod = MRItem::from_type_layout(layout);
 
-            let json = &if compact_json {
-                serde_json::to_string(&root_mod).unwrap()
-            } else {
-                serde_json::to_string_pretty(&root_mod).unwrap()
+            output_json(&root_mod, output_file, output_stdout, compact_json);
+        }
+        Command::Layout {
+            library_path,
+            output_file,
+            output_stdout,
+            compact_json,
+        } => {
+            let layout = library_layout(&library_path);
+            let layout = LayoutJson::from_type_layout(layout);
+
+            output_json(&layout, output_file, output_stdout, compact_json);
+        }
+        Command::Compare { old_file, new_file } => {
+            let read = |path: &PathBuf| {
+                fs::read_to_string(path).unwrap_or_else(|e| {
+                    panic!("Error reading file:\n{}\nError:\n{}\n", path.display(), e)
+                })
             };
 
-            if let Some(output_file) = &output_file {
-                if let Err(e) = fs::write(output_file, json) {
-                    panic!(
-                        "Error writing to file:\n{}\nError:\n{}\n",
-                        output_file.display(),
-                        e,
-                    );
+            if let RErr(errors) = compare_layout_json(&read(&old_file), &read(&new_file)) {
+                println!("The new version of the library is incompatible with the old one:");
+                for error in &errors {
+                    println!("\n{}", error);
                 }
-            }
-            if output_file.is_none() || output_stdout {
-                println!("{}", json);
+                std::process::exit(1);
             }
         }
     }
 }
+
+fn library_layout(library_path: &Path) -> &'static TypeLayout {
+    let lib_header = lib_header_from_path(library_path).unwrap();
+
+    lib_header.layout().unwrap_or_else(|| {
+        println!(
+            "The dynamic library does not support reflection:\n    {}",
+            library_path.display(),
+        );
+        std::process::exit(1);
+    })
+}
+
+fn output_json<T>(value: &T, output_file: Option<PathBuf>, output_stdout: bool, compact_json: bool)
+where
+    T: serde::Serialize,
+{
+    let json = &if compact_json {
+        serde_json::to_string(value).unwrap()
+    } else {
+        serde_json::to_string_pretty(value).unwrap()
+    };
+
+    if let Some(output_file) = &output_file {
+        if let Err(e) = fs::write(output_file, json) {
+            panic!(
+                "Error writing to file:\n{}\nError:\n{}\n",
+                output_file.display(),
+                e,
+            );
+        }
+    }
+    if output_file.is_none() || output_stdout {
+        println!("{}", json);
+    }
+}